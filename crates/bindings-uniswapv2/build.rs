@@ -0,0 +1,58 @@
+//! Generate a module for every `../abi/<family>/<Contract>.json` whose family feature is
+//! enabled, named after the contract like `abigen!` would (`UniswapV2Pair` -> `uniswap_v2_pair`).
+//! A family whose `CARGO_FEATURE_<FAMILY>` is unset, because its feature is disabled or not
+//! declared in `Cargo.toml`, is skipped with a warning.
+
+use ethers::contract::Abigen;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn sorted_entries(dir: &Path) -> Vec<PathBuf> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("read {} fail: {}", dir.display(), e))
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    entries.sort();
+    entries
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let abi_dir = manifest_dir.join("../abi");
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    println!("cargo:rerun-if-changed={}", abi_dir.display());
+
+    let mut modules = String::new();
+    for family in sorted_entries(&abi_dir)
+        .into_iter()
+        .filter(|path| path.is_dir())
+    {
+        let name = family.file_name().unwrap().to_string_lossy().to_string();
+        let feature = format!("CARGO_FEATURE_{}", name.to_uppercase().replace('-', "_"));
+        if env::var_os(&feature).is_none() {
+            println!(
+                "cargo:warning=abi family `{}` skipped, its feature is disabled or not in Cargo.toml",
+                name
+            );
+            continue;
+        }
+
+        for abi in sorted_entries(&family)
+            .into_iter()
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        {
+            let bindings = Abigen::from_file(&abi)
+                .and_then(|abigen| abigen.generate())
+                .unwrap_or_else(|e| panic!("generate {} fail: {}", abi.display(), e));
+            let module = bindings.module_name();
+            bindings
+                .write_to_file(out_dir.join(format!("{}.rs", module)))
+                .unwrap();
+            modules.push_str(&format!(
+                "pub mod {module} {{\n    include!(concat!(env!(\"OUT_DIR\"), \"/{module}.rs\"));\n}}\n"
+            ));
+        }
+    }
+    fs::write(out_dir.join("bindings.rs"), modules).unwrap();
+}
@@ -1,2325 +0,0 @@
-pub use uniswap_v2_pair::*;
-/// This module was auto-generated with ethers-rs Abigen.
-/// More information at: <https://github.com/gakonst/ethers-rs>
-#[allow(
-    clippy::enum_variant_names,
-    clippy::too_many_arguments,
-    clippy::upper_case_acronyms,
-    clippy::type_complexity,
-    dead_code,
-    non_camel_case_types
-)]
-pub mod uniswap_v2_pair {
-    const _: () = {
-        ::core::include_bytes!("/home/hadt/uniswapv2-mm/crates/abi/pair02_abi.json");
-    };
-    #[allow(deprecated)]
-    fn __abi() -> ::ethers::core::abi::Abi {
-        ::ethers::core::abi::ethabi::Contract {
-            constructor: ::core::option::Option::Some(::ethers::core::abi::ethabi::Constructor {
-                inputs: ::std::vec![],
-            }),
-            functions: ::core::convert::From::from([
-                (
-                    ::std::borrow::ToOwned::to_owned("DOMAIN_SEPARATOR"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("DOMAIN_SEPARATOR"),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("bytes32"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("MINIMUM_LIQUIDITY"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("MINIMUM_LIQUIDITY"),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("PERMIT_TYPEHASH"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("PERMIT_TYPEHASH"),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("bytes32"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("allowance"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("allowance"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::string::String::new(),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::string::String::new(),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("approve"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("approve"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("spender"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("value"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Bool,
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("bool"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(false),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("balanceOf"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("balanceOf"),
-                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("address"),
-                            ),
-                        },],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("burn"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("burn"),
-                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("to"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("address"),
-                            ),
-                        },],
-                        outputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amount0"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amount1"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        constant: ::core::option::Option::Some(false),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("decimals"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("decimals"),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(8usize),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint8"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("factory"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("factory"),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("address"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("getReserves"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("getReserves"),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("_reserve0"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(112usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint112"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("_reserve1"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(112usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint112"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("_blockTimestampLast",),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(32usize),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint32"),
-                                ),
-                            },
-                        ],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("initialize"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("initialize"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("_token0"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("_token1"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![],
-                        constant: ::core::option::Option::Some(false),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("kLast"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("kLast"),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("mint"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("mint"),
-                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("to"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("address"),
-                            ),
-                        },],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("liquidity"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(false),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("name"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("name"),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::String,
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("string"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("nonces"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("nonces"),
-                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("address"),
-                            ),
-                        },],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("permit"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("permit"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("owner"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("spender"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("value"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("v"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(8usize),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint8"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("r"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("bytes32"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("s"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("bytes32"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![],
-                        constant: ::core::option::Option::Some(false),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("price0CumulativeLast"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("price0CumulativeLast",),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("price1CumulativeLast"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("price1CumulativeLast",),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("skim"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("skim"),
-                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("to"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("address"),
-                            ),
-                        },],
-                        outputs: ::std::vec![],
-                        constant: ::core::option::Option::Some(false),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("swap"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("swap"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amount0Out"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amount1Out"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("data"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Bytes,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("bytes"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![],
-                        constant: ::core::option::Option::Some(false),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("symbol"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("symbol"),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::String,
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("string"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("sync"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("sync"),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![],
-                        constant: ::core::option::Option::Some(false),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("token0"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("token0"),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("address"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("token1"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("token1"),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("address"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("totalSupply"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("totalSupply"),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(true),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("transfer"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("transfer"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("value"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Bool,
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("bool"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(false),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("transferFrom"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("transferFrom"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("from"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("value"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Bool,
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("bool"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::Some(false),
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-            ]),
-            events: ::core::convert::From::from([
-                (
-                    ::std::borrow::ToOwned::to_owned("Approval"),
-                    ::std::vec![::ethers::core::abi::ethabi::Event {
-                        name: ::std::borrow::ToOwned::to_owned("Approval"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("owner"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                indexed: true,
-                            },
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("spender"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                indexed: true,
-                            },
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("value"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                indexed: false,
-                            },
-                        ],
-                        anonymous: false,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("Burn"),
-                    ::std::vec![::ethers::core::abi::ethabi::Event {
-                        name: ::std::borrow::ToOwned::to_owned("Burn"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("sender"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                indexed: true,
-                            },
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("amount0"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                indexed: false,
-                            },
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("amount1"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                indexed: false,
-                            },
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                indexed: true,
-                            },
-                        ],
-                        anonymous: false,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("Mint"),
-                    ::std::vec![::ethers::core::abi::ethabi::Event {
-                        name: ::std::borrow::ToOwned::to_owned("Mint"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("sender"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                indexed: true,
-                            },
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("amount0"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                indexed: false,
-                            },
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("amount1"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                indexed: false,
-                            },
-                        ],
-                        anonymous: false,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("Swap"),
-                    ::std::vec![::ethers::core::abi::ethabi::Event {
-                        name: ::std::borrow::ToOwned::to_owned("Swap"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("sender"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                indexed: true,
-                            },
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("amount0In"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                indexed: false,
-                            },
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("amount1In"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                indexed: false,
-                            },
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("amount0Out"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                indexed: false,
-                            },
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("amount1Out"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                indexed: false,
-                            },
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                indexed: true,
-                            },
-                        ],
-                        anonymous: false,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("Sync"),
-                    ::std::vec![::ethers::core::abi::ethabi::Event {
-                        name: ::std::borrow::ToOwned::to_owned("Sync"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("reserve0"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(112usize,),
-                                indexed: false,
-                            },
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("reserve1"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(112usize,),
-                                indexed: false,
-                            },
-                        ],
-                        anonymous: false,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("Transfer"),
-                    ::std::vec![::ethers::core::abi::ethabi::Event {
-                        name: ::std::borrow::ToOwned::to_owned("Transfer"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("from"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                indexed: true,
-                            },
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                indexed: true,
-                            },
-                            ::ethers::core::abi::ethabi::EventParam {
-                                name: ::std::borrow::ToOwned::to_owned("value"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                indexed: false,
-                            },
-                        ],
-                        anonymous: false,
-                    },],
-                ),
-            ]),
-            errors: ::std::collections::BTreeMap::new(),
-            receive: false,
-            fallback: false,
-        }
-    }
-    ///The parsed JSON ABI of the contract.
-    pub static UNISWAPV2PAIR_ABI: ::ethers::contract::Lazy<::ethers::core::abi::Abi> =
-        ::ethers::contract::Lazy::new(__abi);
-    pub struct UniswapV2Pair<M>(::ethers::contract::Contract<M>);
-    impl<M> ::core::clone::Clone for UniswapV2Pair<M> {
-        fn clone(&self) -> Self {
-            Self(::core::clone::Clone::clone(&self.0))
-        }
-    }
-    impl<M> ::core::ops::Deref for UniswapV2Pair<M> {
-        type Target = ::ethers::contract::Contract<M>;
-        fn deref(&self) -> &Self::Target {
-            &self.0
-        }
-    }
-    impl<M> ::core::ops::DerefMut for UniswapV2Pair<M> {
-        fn deref_mut(&mut self) -> &mut Self::Target {
-            &mut self.0
-        }
-    }
-    impl<M> ::core::fmt::Debug for UniswapV2Pair<M> {
-        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            f.debug_tuple(::core::stringify!(UniswapV2Pair))
-                .field(&self.address())
-                .finish()
-        }
-    }
-    impl<M: ::ethers::providers::Middleware> UniswapV2Pair<M> {
-        /// Creates a new contract instance with the specified `ethers` client at
-        /// `address`. The contract derefs to a `ethers::Contract` object.
-        pub fn new<T: Into<::ethers::core::types::Address>>(
-            address: T,
-            client: ::std::sync::Arc<M>,
-        ) -> Self {
-            Self(::ethers::contract::Contract::new(
-                address.into(),
-                UNISWAPV2PAIR_ABI.clone(),
-                client,
-            ))
-        }
-        ///Calls the contract's `DOMAIN_SEPARATOR` (0x3644e515) function
-        pub fn domain_separator(&self) -> ::ethers::contract::builders::ContractCall<M, [u8; 32]> {
-            self.0
-                .method_hash([54, 68, 229, 21], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `MINIMUM_LIQUIDITY` (0xba9a7a56) function
-        pub fn minimum_liquidity(
-            &self,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
-            self.0
-                .method_hash([186, 154, 122, 86], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `PERMIT_TYPEHASH` (0x30adf81f) function
-        pub fn permit_typehash(&self) -> ::ethers::contract::builders::ContractCall<M, [u8; 32]> {
-            self.0
-                .method_hash([48, 173, 248, 31], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `allowance` (0xdd62ed3e) function
-        pub fn allowance(
-            &self,
-            p0: ::ethers::core::types::Address,
-            p1: ::ethers::core::types::Address,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
-            self.0
-                .method_hash([221, 98, 237, 62], (p0, p1))
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `approve` (0x095ea7b3) function
-        pub fn approve(
-            &self,
-            spender: ::ethers::core::types::Address,
-            value: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<M, bool> {
-            self.0
-                .method_hash([9, 94, 167, 179], (spender, value))
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `balanceOf` (0x70a08231) function
-        pub fn balance_of(
-            &self,
-            p0: ::ethers::core::types::Address,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
-            self.0
-                .method_hash([112, 160, 130, 49], p0)
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `burn` (0x89afcb44) function
-        pub fn burn(
-            &self,
-            to: ::ethers::core::types::Address,
-        ) -> ::ethers::contract::builders::ContractCall<
-            M,
-            (::ethers::core::types::U256, ::ethers::core::types::U256),
-        > {
-            self.0
-                .method_hash([137, 175, 203, 68], to)
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `decimals` (0x313ce567) function
-        pub fn decimals(&self) -> ::ethers::contract::builders::ContractCall<M, u8> {
-            self.0
-                .method_hash([49, 60, 229, 103], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `factory` (0xc45a0155) function
-        pub fn factory(
-            &self,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::Address> {
-            self.0
-                .method_hash([196, 90, 1, 85], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `getReserves` (0x0902f1ac) function
-        pub fn get_reserves(
-            &self,
-        ) -> ::ethers::contract::builders::ContractCall<M, (u128, u128, u32)> {
-            self.0
-                .method_hash([9, 2, 241, 172], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `initialize` (0x485cc955) function
-        pub fn initialize(
-            &self,
-            token_0: ::ethers::core::types::Address,
-            token_1: ::ethers::core::types::Address,
-        ) -> ::ethers::contract::builders::ContractCall<M, ()> {
-            self.0
-                .method_hash([72, 92, 201, 85], (token_0, token_1))
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `kLast` (0x7464fc3d) function
-        pub fn k_last(
-            &self,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
-            self.0
-                .method_hash([116, 100, 252, 61], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `mint` (0x6a627842) function
-        pub fn mint(
-            &self,
-            to: ::ethers::core::types::Address,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
-            self.0
-                .method_hash([106, 98, 120, 66], to)
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `name` (0x06fdde03) function
-        pub fn name(&self) -> ::ethers::contract::builders::ContractCall<M, ::std::string::String> {
-            self.0
-                .method_hash([6, 253, 222, 3], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `nonces` (0x7ecebe00) function
-        pub fn nonces(
-            &self,
-            p0: ::ethers::core::types::Address,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
-            self.0
-                .method_hash([126, 206, 190, 0], p0)
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `permit` (0xd505accf) function
-        pub fn permit(
-            &self,
-            owner: ::ethers::core::types::Address,
-            spender: ::ethers::core::types::Address,
-            value: ::ethers::core::types::U256,
-            deadline: ::ethers::core::types::U256,
-            v: u8,
-            r: [u8; 32],
-            s: [u8; 32],
-        ) -> ::ethers::contract::builders::ContractCall<M, ()> {
-            self.0
-                .method_hash(
-                    [213, 5, 172, 207],
-                    (owner, spender, value, deadline, v, r, s),
-                )
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `price0CumulativeLast` (0x5909c0d5) function
-        pub fn price_0_cumulative_last(
-            &self,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
-            self.0
-                .method_hash([89, 9, 192, 213], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `price1CumulativeLast` (0x5a3d5493) function
-        pub fn price_1_cumulative_last(
-            &self,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
-            self.0
-                .method_hash([90, 61, 84, 147], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `skim` (0xbc25cf77) function
-        pub fn skim(
-            &self,
-            to: ::ethers::core::types::Address,
-        ) -> ::ethers::contract::builders::ContractCall<M, ()> {
-            self.0
-                .method_hash([188, 37, 207, 119], to)
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `swap` (0x022c0d9f) function
-        pub fn swap(
-            &self,
-            amount_0_out: ::ethers::core::types::U256,
-            amount_1_out: ::ethers::core::types::U256,
-            to: ::ethers::core::types::Address,
-            data: ::ethers::core::types::Bytes,
-        ) -> ::ethers::contract::builders::ContractCall<M, ()> {
-            self.0
-                .method_hash([2, 44, 13, 159], (amount_0_out, amount_1_out, to, data))
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `symbol` (0x95d89b41) function
-        pub fn symbol(
-            &self,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::std::string::String> {
-            self.0
-                .method_hash([149, 216, 155, 65], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `sync` (0xfff6cae9) function
-        pub fn sync(&self) -> ::ethers::contract::builders::ContractCall<M, ()> {
-            self.0
-                .method_hash([255, 246, 202, 233], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `token0` (0x0dfe1681) function
-        pub fn token_0(
-            &self,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::Address> {
-            self.0
-                .method_hash([13, 254, 22, 129], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `token1` (0xd21220a7) function
-        pub fn token_1(
-            &self,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::Address> {
-            self.0
-                .method_hash([210, 18, 32, 167], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `totalSupply` (0x18160ddd) function
-        pub fn total_supply(
-            &self,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
-            self.0
-                .method_hash([24, 22, 13, 221], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `transfer` (0xa9059cbb) function
-        pub fn transfer(
-            &self,
-            to: ::ethers::core::types::Address,
-            value: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<M, bool> {
-            self.0
-                .method_hash([169, 5, 156, 187], (to, value))
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `transferFrom` (0x23b872dd) function
-        pub fn transfer_from(
-            &self,
-            from: ::ethers::core::types::Address,
-            to: ::ethers::core::types::Address,
-            value: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<M, bool> {
-            self.0
-                .method_hash([35, 184, 114, 221], (from, to, value))
-                .expect("method not found (this should never happen)")
-        }
-        ///Gets the contract's `Approval` event
-        pub fn approval_filter(
-            &self,
-        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, ApprovalFilter> {
-            self.0.event()
-        }
-        ///Gets the contract's `Burn` event
-        pub fn burn_filter(
-            &self,
-        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, BurnFilter> {
-            self.0.event()
-        }
-        ///Gets the contract's `Mint` event
-        pub fn mint_filter(
-            &self,
-        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, MintFilter> {
-            self.0.event()
-        }
-        ///Gets the contract's `Swap` event
-        pub fn swap_filter(
-            &self,
-        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, SwapFilter> {
-            self.0.event()
-        }
-        ///Gets the contract's `Sync` event
-        pub fn sync_filter(
-            &self,
-        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, SyncFilter> {
-            self.0.event()
-        }
-        ///Gets the contract's `Transfer` event
-        pub fn transfer_filter(
-            &self,
-        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, TransferFilter> {
-            self.0.event()
-        }
-        /// Returns an `Event` builder for all the events of this contract.
-        pub fn events(
-            &self,
-        ) -> ::ethers::contract::builders::Event<::std::sync::Arc<M>, M, UniswapV2PairEvents>
-        {
-            self.0
-                .event_with_filter(::core::default::Default::default())
-        }
-    }
-    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>>
-        for UniswapV2Pair<M>
-    {
-        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
-            Self::new(contract.address(), contract.client())
-        }
-    }
-    #[derive(
-        Clone,
-        ::ethers::contract::EthEvent,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethevent(name = "Approval", abi = "Approval(address,address,uint256)")]
-    pub struct ApprovalFilter {
-        #[ethevent(indexed)]
-        pub owner: ::ethers::core::types::Address,
-        #[ethevent(indexed)]
-        pub spender: ::ethers::core::types::Address,
-        pub value: ::ethers::core::types::U256,
-    }
-    #[derive(
-        Clone,
-        ::ethers::contract::EthEvent,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethevent(name = "Burn", abi = "Burn(address,uint256,uint256,address)")]
-    pub struct BurnFilter {
-        #[ethevent(indexed)]
-        pub sender: ::ethers::core::types::Address,
-        pub amount_0: ::ethers::core::types::U256,
-        pub amount_1: ::ethers::core::types::U256,
-        #[ethevent(indexed)]
-        pub to: ::ethers::core::types::Address,
-    }
-    #[derive(
-        Clone,
-        ::ethers::contract::EthEvent,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethevent(name = "Mint", abi = "Mint(address,uint256,uint256)")]
-    pub struct MintFilter {
-        #[ethevent(indexed)]
-        pub sender: ::ethers::core::types::Address,
-        pub amount_0: ::ethers::core::types::U256,
-        pub amount_1: ::ethers::core::types::U256,
-    }
-    #[derive(
-        Clone,
-        ::ethers::contract::EthEvent,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethevent(
-        name = "Swap",
-        abi = "Swap(address,uint256,uint256,uint256,uint256,address)"
-    )]
-    pub struct SwapFilter {
-        #[ethevent(indexed)]
-        pub sender: ::ethers::core::types::Address,
-        pub amount_0_in: ::ethers::core::types::U256,
-        pub amount_1_in: ::ethers::core::types::U256,
-        pub amount_0_out: ::ethers::core::types::U256,
-        pub amount_1_out: ::ethers::core::types::U256,
-        #[ethevent(indexed)]
-        pub to: ::ethers::core::types::Address,
-    }
-    #[derive(
-        Clone,
-        ::ethers::contract::EthEvent,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethevent(name = "Sync", abi = "Sync(uint112,uint112)")]
-    pub struct SyncFilter {
-        pub reserve_0: u128,
-        pub reserve_1: u128,
-    }
-    #[derive(
-        Clone,
-        ::ethers::contract::EthEvent,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethevent(name = "Transfer", abi = "Transfer(address,address,uint256)")]
-    pub struct TransferFilter {
-        #[ethevent(indexed)]
-        pub from: ::ethers::core::types::Address,
-        #[ethevent(indexed)]
-        pub to: ::ethers::core::types::Address,
-        pub value: ::ethers::core::types::U256,
-    }
-    ///Container type for all of the contract's events
-    #[derive(Clone, ::ethers::contract::EthAbiType, Debug, PartialEq, Eq, Hash)]
-    pub enum UniswapV2PairEvents {
-        ApprovalFilter(ApprovalFilter),
-        BurnFilter(BurnFilter),
-        MintFilter(MintFilter),
-        SwapFilter(SwapFilter),
-        SyncFilter(SyncFilter),
-        TransferFilter(TransferFilter),
-    }
-    impl ::ethers::contract::EthLogDecode for UniswapV2PairEvents {
-        fn decode_log(
-            log: &::ethers::core::abi::RawLog,
-        ) -> ::core::result::Result<Self, ::ethers::core::abi::Error> {
-            if let Ok(decoded) = ApprovalFilter::decode_log(log) {
-                return Ok(UniswapV2PairEvents::ApprovalFilter(decoded));
-            }
-            if let Ok(decoded) = BurnFilter::decode_log(log) {
-                return Ok(UniswapV2PairEvents::BurnFilter(decoded));
-            }
-            if let Ok(decoded) = MintFilter::decode_log(log) {
-                return Ok(UniswapV2PairEvents::MintFilter(decoded));
-            }
-            if let Ok(decoded) = SwapFilter::decode_log(log) {
-                return Ok(UniswapV2PairEvents::SwapFilter(decoded));
-            }
-            if let Ok(decoded) = SyncFilter::decode_log(log) {
-                return Ok(UniswapV2PairEvents::SyncFilter(decoded));
-            }
-            if let Ok(decoded) = TransferFilter::decode_log(log) {
-                return Ok(UniswapV2PairEvents::TransferFilter(decoded));
-            }
-            Err(::ethers::core::abi::Error::InvalidData)
-        }
-    }
-    impl ::core::fmt::Display for UniswapV2PairEvents {
-        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            match self {
-                Self::ApprovalFilter(element) => ::core::fmt::Display::fmt(element, f),
-                Self::BurnFilter(element) => ::core::fmt::Display::fmt(element, f),
-                Self::MintFilter(element) => ::core::fmt::Display::fmt(element, f),
-                Self::SwapFilter(element) => ::core::fmt::Display::fmt(element, f),
-                Self::SyncFilter(element) => ::core::fmt::Display::fmt(element, f),
-                Self::TransferFilter(element) => ::core::fmt::Display::fmt(element, f),
-            }
-        }
-    }
-    impl ::core::convert::From<ApprovalFilter> for UniswapV2PairEvents {
-        fn from(value: ApprovalFilter) -> Self {
-            Self::ApprovalFilter(value)
-        }
-    }
-    impl ::core::convert::From<BurnFilter> for UniswapV2PairEvents {
-        fn from(value: BurnFilter) -> Self {
-            Self::BurnFilter(value)
-        }
-    }
-    impl ::core::convert::From<MintFilter> for UniswapV2PairEvents {
-        fn from(value: MintFilter) -> Self {
-            Self::MintFilter(value)
-        }
-    }
-    impl ::core::convert::From<SwapFilter> for UniswapV2PairEvents {
-        fn from(value: SwapFilter) -> Self {
-            Self::SwapFilter(value)
-        }
-    }
-    impl ::core::convert::From<SyncFilter> for UniswapV2PairEvents {
-        fn from(value: SyncFilter) -> Self {
-            Self::SyncFilter(value)
-        }
-    }
-    impl ::core::convert::From<TransferFilter> for UniswapV2PairEvents {
-        fn from(value: TransferFilter) -> Self {
-            Self::TransferFilter(value)
-        }
-    }
-    ///Container type for all input parameters for the `DOMAIN_SEPARATOR` function with signature `DOMAIN_SEPARATOR()` and selector `0x3644e515`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "DOMAIN_SEPARATOR", abi = "DOMAIN_SEPARATOR()")]
-    pub struct DomainSeparatorCall;
-    ///Container type for all input parameters for the `MINIMUM_LIQUIDITY` function with signature `MINIMUM_LIQUIDITY()` and selector `0xba9a7a56`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "MINIMUM_LIQUIDITY", abi = "MINIMUM_LIQUIDITY()")]
-    pub struct MinimumLiquidityCall;
-    ///Container type for all input parameters for the `PERMIT_TYPEHASH` function with signature `PERMIT_TYPEHASH()` and selector `0x30adf81f`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "PERMIT_TYPEHASH", abi = "PERMIT_TYPEHASH()")]
-    pub struct PermitTypehashCall;
-    ///Container type for all input parameters for the `allowance` function with signature `allowance(address,address)` and selector `0xdd62ed3e`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "allowance", abi = "allowance(address,address)")]
-    pub struct AllowanceCall(
-        pub ::ethers::core::types::Address,
-        pub ::ethers::core::types::Address,
-    );
-    ///Container type for all input parameters for the `approve` function with signature `approve(address,uint256)` and selector `0x095ea7b3`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "approve", abi = "approve(address,uint256)")]
-    pub struct ApproveCall {
-        pub spender: ::ethers::core::types::Address,
-        pub value: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `balanceOf` function with signature `balanceOf(address)` and selector `0x70a08231`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "balanceOf", abi = "balanceOf(address)")]
-    pub struct BalanceOfCall(pub ::ethers::core::types::Address);
-    ///Container type for all input parameters for the `burn` function with signature `burn(address)` and selector `0x89afcb44`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "burn", abi = "burn(address)")]
-    pub struct BurnCall {
-        pub to: ::ethers::core::types::Address,
-    }
-    ///Container type for all input parameters for the `decimals` function with signature `decimals()` and selector `0x313ce567`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "decimals", abi = "decimals()")]
-    pub struct DecimalsCall;
-    ///Container type for all input parameters for the `factory` function with signature `factory()` and selector `0xc45a0155`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "factory", abi = "factory()")]
-    pub struct FactoryCall;
-    ///Container type for all input parameters for the `getReserves` function with signature `getReserves()` and selector `0x0902f1ac`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "getReserves", abi = "getReserves()")]
-    pub struct GetReservesCall;
-    ///Container type for all input parameters for the `initialize` function with signature `initialize(address,address)` and selector `0x485cc955`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "initialize", abi = "initialize(address,address)")]
-    pub struct InitializeCall {
-        pub token_0: ::ethers::core::types::Address,
-        pub token_1: ::ethers::core::types::Address,
-    }
-    ///Container type for all input parameters for the `kLast` function with signature `kLast()` and selector `0x7464fc3d`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "kLast", abi = "kLast()")]
-    pub struct KlastCall;
-    ///Container type for all input parameters for the `mint` function with signature `mint(address)` and selector `0x6a627842`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "mint", abi = "mint(address)")]
-    pub struct MintCall {
-        pub to: ::ethers::core::types::Address,
-    }
-    ///Container type for all input parameters for the `name` function with signature `name()` and selector `0x06fdde03`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "name", abi = "name()")]
-    pub struct NameCall;
-    ///Container type for all input parameters for the `nonces` function with signature `nonces(address)` and selector `0x7ecebe00`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "nonces", abi = "nonces(address)")]
-    pub struct NoncesCall(pub ::ethers::core::types::Address);
-    ///Container type for all input parameters for the `permit` function with signature `permit(address,address,uint256,uint256,uint8,bytes32,bytes32)` and selector `0xd505accf`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "permit",
-        abi = "permit(address,address,uint256,uint256,uint8,bytes32,bytes32)"
-    )]
-    pub struct PermitCall {
-        pub owner: ::ethers::core::types::Address,
-        pub spender: ::ethers::core::types::Address,
-        pub value: ::ethers::core::types::U256,
-        pub deadline: ::ethers::core::types::U256,
-        pub v: u8,
-        pub r: [u8; 32],
-        pub s: [u8; 32],
-    }
-    ///Container type for all input parameters for the `price0CumulativeLast` function with signature `price0CumulativeLast()` and selector `0x5909c0d5`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "price0CumulativeLast", abi = "price0CumulativeLast()")]
-    pub struct Price0CumulativeLastCall;
-    ///Container type for all input parameters for the `price1CumulativeLast` function with signature `price1CumulativeLast()` and selector `0x5a3d5493`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "price1CumulativeLast", abi = "price1CumulativeLast()")]
-    pub struct Price1CumulativeLastCall;
-    ///Container type for all input parameters for the `skim` function with signature `skim(address)` and selector `0xbc25cf77`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "skim", abi = "skim(address)")]
-    pub struct SkimCall {
-        pub to: ::ethers::core::types::Address,
-    }
-    ///Container type for all input parameters for the `swap` function with signature `swap(uint256,uint256,address,bytes)` and selector `0x022c0d9f`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "swap", abi = "swap(uint256,uint256,address,bytes)")]
-    pub struct SwapCall {
-        pub amount_0_out: ::ethers::core::types::U256,
-        pub amount_1_out: ::ethers::core::types::U256,
-        pub to: ::ethers::core::types::Address,
-        pub data: ::ethers::core::types::Bytes,
-    }
-    ///Container type for all input parameters for the `symbol` function with signature `symbol()` and selector `0x95d89b41`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "symbol", abi = "symbol()")]
-    pub struct SymbolCall;
-    ///Container type for all input parameters for the `sync` function with signature `sync()` and selector `0xfff6cae9`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "sync", abi = "sync()")]
-    pub struct SyncCall;
-    ///Container type for all input parameters for the `token0` function with signature `token0()` and selector `0x0dfe1681`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "token0", abi = "token0()")]
-    pub struct Token0Call;
-    ///Container type for all input parameters for the `token1` function with signature `token1()` and selector `0xd21220a7`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "token1", abi = "token1()")]
-    pub struct Token1Call;
-    ///Container type for all input parameters for the `totalSupply` function with signature `totalSupply()` and selector `0x18160ddd`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "totalSupply", abi = "totalSupply()")]
-    pub struct TotalSupplyCall;
-    ///Container type for all input parameters for the `transfer` function with signature `transfer(address,uint256)` and selector `0xa9059cbb`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "transfer", abi = "transfer(address,uint256)")]
-    pub struct TransferCall {
-        pub to: ::ethers::core::types::Address,
-        pub value: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `transferFrom` function with signature `transferFrom(address,address,uint256)` and selector `0x23b872dd`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "transferFrom", abi = "transferFrom(address,address,uint256)")]
-    pub struct TransferFromCall {
-        pub from: ::ethers::core::types::Address,
-        pub to: ::ethers::core::types::Address,
-        pub value: ::ethers::core::types::U256,
-    }
-    ///Container type for all of the contract's call
-    #[derive(Clone, ::ethers::contract::EthAbiType, Debug, PartialEq, Eq, Hash)]
-    pub enum UniswapV2PairCalls {
-        DomainSeparator(DomainSeparatorCall),
-        MinimumLiquidity(MinimumLiquidityCall),
-        PermitTypehash(PermitTypehashCall),
-        Allowance(AllowanceCall),
-        Approve(ApproveCall),
-        BalanceOf(BalanceOfCall),
-        Burn(BurnCall),
-        Decimals(DecimalsCall),
-        Factory(FactoryCall),
-        GetReserves(GetReservesCall),
-        Initialize(InitializeCall),
-        Klast(KlastCall),
-        Mint(MintCall),
-        Name(NameCall),
-        Nonces(NoncesCall),
-        Permit(PermitCall),
-        Price0CumulativeLast(Price0CumulativeLastCall),
-        Price1CumulativeLast(Price1CumulativeLastCall),
-        Skim(SkimCall),
-        Swap(SwapCall),
-        Symbol(SymbolCall),
-        Sync(SyncCall),
-        Token0(Token0Call),
-        Token1(Token1Call),
-        TotalSupply(TotalSupplyCall),
-        Transfer(TransferCall),
-        TransferFrom(TransferFromCall),
-    }
-    impl ::ethers::core::abi::AbiDecode for UniswapV2PairCalls {
-        fn decode(
-            data: impl AsRef<[u8]>,
-        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
-            let data = data.as_ref();
-            if let Ok(decoded) =
-                <DomainSeparatorCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::DomainSeparator(decoded));
-            }
-            if let Ok(decoded) =
-                <MinimumLiquidityCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::MinimumLiquidity(decoded));
-            }
-            if let Ok(decoded) =
-                <PermitTypehashCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::PermitTypehash(decoded));
-            }
-            if let Ok(decoded) = <AllowanceCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Allowance(decoded));
-            }
-            if let Ok(decoded) = <ApproveCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Approve(decoded));
-            }
-            if let Ok(decoded) = <BalanceOfCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::BalanceOf(decoded));
-            }
-            if let Ok(decoded) = <BurnCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Burn(decoded));
-            }
-            if let Ok(decoded) = <DecimalsCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Decimals(decoded));
-            }
-            if let Ok(decoded) = <FactoryCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Factory(decoded));
-            }
-            if let Ok(decoded) = <GetReservesCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::GetReserves(decoded));
-            }
-            if let Ok(decoded) = <InitializeCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Initialize(decoded));
-            }
-            if let Ok(decoded) = <KlastCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Klast(decoded));
-            }
-            if let Ok(decoded) = <MintCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Mint(decoded));
-            }
-            if let Ok(decoded) = <NameCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Name(decoded));
-            }
-            if let Ok(decoded) = <NoncesCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Nonces(decoded));
-            }
-            if let Ok(decoded) = <PermitCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Permit(decoded));
-            }
-            if let Ok(decoded) =
-                <Price0CumulativeLastCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::Price0CumulativeLast(decoded));
-            }
-            if let Ok(decoded) =
-                <Price1CumulativeLastCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::Price1CumulativeLast(decoded));
-            }
-            if let Ok(decoded) = <SkimCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Skim(decoded));
-            }
-            if let Ok(decoded) = <SwapCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Swap(decoded));
-            }
-            if let Ok(decoded) = <SymbolCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Symbol(decoded));
-            }
-            if let Ok(decoded) = <SyncCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Sync(decoded));
-            }
-            if let Ok(decoded) = <Token0Call as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Token0(decoded));
-            }
-            if let Ok(decoded) = <Token1Call as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Token1(decoded));
-            }
-            if let Ok(decoded) = <TotalSupplyCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::TotalSupply(decoded));
-            }
-            if let Ok(decoded) = <TransferCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Transfer(decoded));
-            }
-            if let Ok(decoded) = <TransferFromCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::TransferFrom(decoded));
-            }
-            Err(::ethers::core::abi::Error::InvalidData.into())
-        }
-    }
-    impl ::ethers::core::abi::AbiEncode for UniswapV2PairCalls {
-        fn encode(self) -> Vec<u8> {
-            match self {
-                Self::DomainSeparator(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::MinimumLiquidity(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::PermitTypehash(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Allowance(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Approve(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::BalanceOf(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Burn(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Decimals(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Factory(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::GetReserves(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Initialize(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Klast(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Mint(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Name(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Nonces(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Permit(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Price0CumulativeLast(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-                Self::Price1CumulativeLast(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-                Self::Skim(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Swap(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Symbol(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Sync(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Token0(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Token1(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::TotalSupply(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Transfer(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::TransferFrom(element) => ::ethers::core::abi::AbiEncode::encode(element),
-            }
-        }
-    }
-    impl ::core::fmt::Display for UniswapV2PairCalls {
-        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            match self {
-                Self::DomainSeparator(element) => ::core::fmt::Display::fmt(element, f),
-                Self::MinimumLiquidity(element) => ::core::fmt::Display::fmt(element, f),
-                Self::PermitTypehash(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Allowance(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Approve(element) => ::core::fmt::Display::fmt(element, f),
-                Self::BalanceOf(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Burn(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Decimals(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Factory(element) => ::core::fmt::Display::fmt(element, f),
-                Self::GetReserves(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Initialize(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Klast(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Mint(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Name(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Nonces(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Permit(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Price0CumulativeLast(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Price1CumulativeLast(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Skim(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Swap(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Symbol(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Sync(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Token0(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Token1(element) => ::core::fmt::Display::fmt(element, f),
-                Self::TotalSupply(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Transfer(element) => ::core::fmt::Display::fmt(element, f),
-                Self::TransferFrom(element) => ::core::fmt::Display::fmt(element, f),
-            }
-        }
-    }
-    impl ::core::convert::From<DomainSeparatorCall> for UniswapV2PairCalls {
-        fn from(value: DomainSeparatorCall) -> Self {
-            Self::DomainSeparator(value)
-        }
-    }
-    impl ::core::convert::From<MinimumLiquidityCall> for UniswapV2PairCalls {
-        fn from(value: MinimumLiquidityCall) -> Self {
-            Self::MinimumLiquidity(value)
-        }
-    }
-    impl ::core::convert::From<PermitTypehashCall> for UniswapV2PairCalls {
-        fn from(value: PermitTypehashCall) -> Self {
-            Self::PermitTypehash(value)
-        }
-    }
-    impl ::core::convert::From<AllowanceCall> for UniswapV2PairCalls {
-        fn from(value: AllowanceCall) -> Self {
-            Self::Allowance(value)
-        }
-    }
-    impl ::core::convert::From<ApproveCall> for UniswapV2PairCalls {
-        fn from(value: ApproveCall) -> Self {
-            Self::Approve(value)
-        }
-    }
-    impl ::core::convert::From<BalanceOfCall> for UniswapV2PairCalls {
-        fn from(value: BalanceOfCall) -> Self {
-            Self::BalanceOf(value)
-        }
-    }
-    impl ::core::convert::From<BurnCall> for UniswapV2PairCalls {
-        fn from(value: BurnCall) -> Self {
-            Self::Burn(value)
-        }
-    }
-    impl ::core::convert::From<DecimalsCall> for UniswapV2PairCalls {
-        fn from(value: DecimalsCall) -> Self {
-            Self::Decimals(value)
-        }
-    }
-    impl ::core::convert::From<FactoryCall> for UniswapV2PairCalls {
-        fn from(value: FactoryCall) -> Self {
-            Self::Factory(value)
-        }
-    }
-    impl ::core::convert::From<GetReservesCall> for UniswapV2PairCalls {
-        fn from(value: GetReservesCall) -> Self {
-            Self::GetReserves(value)
-        }
-    }
-    impl ::core::convert::From<InitializeCall> for UniswapV2PairCalls {
-        fn from(value: InitializeCall) -> Self {
-            Self::Initialize(value)
-        }
-    }
-    impl ::core::convert::From<KlastCall> for UniswapV2PairCalls {
-        fn from(value: KlastCall) -> Self {
-            Self::Klast(value)
-        }
-    }
-    impl ::core::convert::From<MintCall> for UniswapV2PairCalls {
-        fn from(value: MintCall) -> Self {
-            Self::Mint(value)
-        }
-    }
-    impl ::core::convert::From<NameCall> for UniswapV2PairCalls {
-        fn from(value: NameCall) -> Self {
-            Self::Name(value)
-        }
-    }
-    impl ::core::convert::From<NoncesCall> for UniswapV2PairCalls {
-        fn from(value: NoncesCall) -> Self {
-            Self::Nonces(value)
-        }
-    }
-    impl ::core::convert::From<PermitCall> for UniswapV2PairCalls {
-        fn from(value: PermitCall) -> Self {
-            Self::Permit(value)
-        }
-    }
-    impl ::core::convert::From<Price0CumulativeLastCall> for UniswapV2PairCalls {
-        fn from(value: Price0CumulativeLastCall) -> Self {
-            Self::Price0CumulativeLast(value)
-        }
-    }
-    impl ::core::convert::From<Price1CumulativeLastCall> for UniswapV2PairCalls {
-        fn from(value: Price1CumulativeLastCall) -> Self {
-            Self::Price1CumulativeLast(value)
-        }
-    }
-    impl ::core::convert::From<SkimCall> for UniswapV2PairCalls {
-        fn from(value: SkimCall) -> Self {
-            Self::Skim(value)
-        }
-    }
-    impl ::core::convert::From<SwapCall> for UniswapV2PairCalls {
-        fn from(value: SwapCall) -> Self {
-            Self::Swap(value)
-        }
-    }
-    impl ::core::convert::From<SymbolCall> for UniswapV2PairCalls {
-        fn from(value: SymbolCall) -> Self {
-            Self::Symbol(value)
-        }
-    }
-    impl ::core::convert::From<SyncCall> for UniswapV2PairCalls {
-        fn from(value: SyncCall) -> Self {
-            Self::Sync(value)
-        }
-    }
-    impl ::core::convert::From<Token0Call> for UniswapV2PairCalls {
-        fn from(value: Token0Call) -> Self {
-            Self::Token0(value)
-        }
-    }
-    impl ::core::convert::From<Token1Call> for UniswapV2PairCalls {
-        fn from(value: Token1Call) -> Self {
-            Self::Token1(value)
-        }
-    }
-    impl ::core::convert::From<TotalSupplyCall> for UniswapV2PairCalls {
-        fn from(value: TotalSupplyCall) -> Self {
-            Self::TotalSupply(value)
-        }
-    }
-    impl ::core::convert::From<TransferCall> for UniswapV2PairCalls {
-        fn from(value: TransferCall) -> Self {
-            Self::Transfer(value)
-        }
-    }
-    impl ::core::convert::From<TransferFromCall> for UniswapV2PairCalls {
-        fn from(value: TransferFromCall) -> Self {
-            Self::TransferFrom(value)
-        }
-    }
-    ///Container type for all return fields from the `DOMAIN_SEPARATOR` function with signature `DOMAIN_SEPARATOR()` and selector `0x3644e515`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct DomainSeparatorReturn(pub [u8; 32]);
-    ///Container type for all return fields from the `MINIMUM_LIQUIDITY` function with signature `MINIMUM_LIQUIDITY()` and selector `0xba9a7a56`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct MinimumLiquidityReturn(pub ::ethers::core::types::U256);
-    ///Container type for all return fields from the `PERMIT_TYPEHASH` function with signature `PERMIT_TYPEHASH()` and selector `0x30adf81f`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct PermitTypehashReturn(pub [u8; 32]);
-    ///Container type for all return fields from the `allowance` function with signature `allowance(address,address)` and selector `0xdd62ed3e`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct AllowanceReturn(pub ::ethers::core::types::U256);
-    ///Container type for all return fields from the `approve` function with signature `approve(address,uint256)` and selector `0x095ea7b3`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct ApproveReturn(pub bool);
-    ///Container type for all return fields from the `balanceOf` function with signature `balanceOf(address)` and selector `0x70a08231`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct BalanceOfReturn(pub ::ethers::core::types::U256);
-    ///Container type for all return fields from the `burn` function with signature `burn(address)` and selector `0x89afcb44`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct BurnReturn {
-        pub amount_0: ::ethers::core::types::U256,
-        pub amount_1: ::ethers::core::types::U256,
-    }
-    ///Container type for all return fields from the `decimals` function with signature `decimals()` and selector `0x313ce567`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct DecimalsReturn(pub u8);
-    ///Container type for all return fields from the `factory` function with signature `factory()` and selector `0xc45a0155`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct FactoryReturn(pub ::ethers::core::types::Address);
-    ///Container type for all return fields from the `getReserves` function with signature `getReserves()` and selector `0x0902f1ac`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct GetReservesReturn {
-        pub reserve_0: u128,
-        pub reserve_1: u128,
-        pub block_timestamp_last: u32,
-    }
-    ///Container type for all return fields from the `kLast` function with signature `kLast()` and selector `0x7464fc3d`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct KlastReturn(pub ::ethers::core::types::U256);
-    ///Container type for all return fields from the `mint` function with signature `mint(address)` and selector `0x6a627842`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct MintReturn {
-        pub liquidity: ::ethers::core::types::U256,
-    }
-    ///Container type for all return fields from the `name` function with signature `name()` and selector `0x06fdde03`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct NameReturn(pub ::std::string::String);
-    ///Container type for all return fields from the `nonces` function with signature `nonces(address)` and selector `0x7ecebe00`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct NoncesReturn(pub ::ethers::core::types::U256);
-    ///Container type for all return fields from the `price0CumulativeLast` function with signature `price0CumulativeLast()` and selector `0x5909c0d5`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct Price0CumulativeLastReturn(pub ::ethers::core::types::U256);
-    ///Container type for all return fields from the `price1CumulativeLast` function with signature `price1CumulativeLast()` and selector `0x5a3d5493`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct Price1CumulativeLastReturn(pub ::ethers::core::types::U256);
-    ///Container type for all return fields from the `symbol` function with signature `symbol()` and selector `0x95d89b41`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct SymbolReturn(pub ::std::string::String);
-    ///Container type for all return fields from the `token0` function with signature `token0()` and selector `0x0dfe1681`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct Token0Return(pub ::ethers::core::types::Address);
-    ///Container type for all return fields from the `token1` function with signature `token1()` and selector `0xd21220a7`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct Token1Return(pub ::ethers::core::types::Address);
-    ///Container type for all return fields from the `totalSupply` function with signature `totalSupply()` and selector `0x18160ddd`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct TotalSupplyReturn(pub ::ethers::core::types::U256);
-    ///Container type for all return fields from the `transfer` function with signature `transfer(address,uint256)` and selector `0xa9059cbb`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct TransferReturn(pub bool);
-    ///Container type for all return fields from the `transferFrom` function with signature `transferFrom(address,address,uint256)` and selector `0x23b872dd`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct TransferFromReturn(pub bool);
-}
@@ -1,3151 +0,0 @@
-pub use uniswap_v2_router_02::*;
-/// This module was auto-generated with ethers-rs Abigen.
-/// More information at: <https://github.com/gakonst/ethers-rs>
-#[allow(
-    clippy::enum_variant_names,
-    clippy::too_many_arguments,
-    clippy::upper_case_acronyms,
-    clippy::type_complexity,
-    dead_code,
-    non_camel_case_types
-)]
-pub mod uniswap_v2_router_02 {
-    const _: () = {
-        ::core::include_bytes!("/home/hadt/uniswapv2-mm/crates/abi/router02_abi.json");
-    };
-    #[allow(deprecated)]
-    fn __abi() -> ::ethers::core::abi::Abi {
-        ::ethers::core::abi::ethabi::Contract {
-            constructor: ::core::option::Option::Some(::ethers::core::abi::ethabi::Constructor {
-                inputs: ::std::vec![
-                    ::ethers::core::abi::ethabi::Param {
-                        name: ::std::borrow::ToOwned::to_owned("_factory"),
-                        kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                        internal_type: ::core::option::Option::Some(
-                            ::std::borrow::ToOwned::to_owned("address"),
-                        ),
-                    },
-                    ::ethers::core::abi::ethabi::Param {
-                        name: ::std::borrow::ToOwned::to_owned("_WETH"),
-                        kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                        internal_type: ::core::option::Option::Some(
-                            ::std::borrow::ToOwned::to_owned("address"),
-                        ),
-                    },
-                ],
-            }),
-            functions: ::core::convert::From::from([
-                (
-                    ::std::borrow::ToOwned::to_owned("WETH"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("WETH"),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("address"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("addLiquidity"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("addLiquidity"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("tokenA"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("tokenB"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountADesired"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountBDesired"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountAMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountBMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountA"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountB"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("liquidity"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("addLiquidityETH"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("addLiquidityETH"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("token"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountTokenDesired",),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountTokenMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountETHMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountToken"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountETH"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("liquidity"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::Payable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("factory"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("factory"),
-                        inputs: ::std::vec![],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::string::String::new(),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("address"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("getAmountIn"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("getAmountIn"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountOut"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("reserveIn"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("reserveOut"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("amountIn"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::Pure,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("getAmountOut"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("getAmountOut"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountIn"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("reserveIn"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("reserveOut"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("amountOut"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::Pure,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("getAmountsIn"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("getAmountsIn"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountOut"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("path"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                    ::std::boxed::Box::new(
-                                        ::ethers::core::abi::ethabi::ParamType::Address,
-                                    ),
-                                ),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address[]"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("amounts"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                ::std::boxed::Box::new(
-                                    ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
-                                ),
-                            ),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256[]"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("getAmountsOut"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("getAmountsOut"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountIn"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("path"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                    ::std::boxed::Box::new(
-                                        ::ethers::core::abi::ethabi::ParamType::Address,
-                                    ),
-                                ),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address[]"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("amounts"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                ::std::boxed::Box::new(
-                                    ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
-                                ),
-                            ),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256[]"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("quote"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("quote"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountA"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("reserveA"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("reserveB"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("amountB"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::Pure,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("removeLiquidity"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("removeLiquidity"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("tokenA"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("tokenB"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("liquidity"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountAMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountBMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountA"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountB"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("removeLiquidityETH"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("removeLiquidityETH"),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("token"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("liquidity"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountTokenMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountETHMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountToken"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountETH"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned(
-                        "removeLiquidityETHSupportingFeeOnTransferTokens",
-                    ),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned(
-                            "removeLiquidityETHSupportingFeeOnTransferTokens",
-                        ),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("token"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("liquidity"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountTokenMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountETHMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("amountETH"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("removeLiquidityETHWithPermit"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("removeLiquidityETHWithPermit",),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("token"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("liquidity"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountTokenMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountETHMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("approveMax"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Bool,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("bool"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("v"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(8usize),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint8"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("r"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("bytes32"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("s"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("bytes32"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountToken"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountETH"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned(
-                        "removeLiquidityETHWithPermitSupportingFeeOnTransferTokens",
-                    ),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned(
-                            "removeLiquidityETHWithPermitSupportingFeeOnTransferTokens",
-                        ),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("token"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("liquidity"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountTokenMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountETHMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("approveMax"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Bool,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("bool"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("v"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(8usize),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint8"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("r"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("bytes32"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("s"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("bytes32"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("amountETH"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("removeLiquidityWithPermit"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("removeLiquidityWithPermit",),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("tokenA"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("tokenB"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("liquidity"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountAMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountBMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("approveMax"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Bool,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("bool"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("v"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(8usize),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint8"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("r"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("bytes32"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("s"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("bytes32"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountA"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountB"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("swapETHForExactTokens"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("swapETHForExactTokens",),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountOut"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("path"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                    ::std::boxed::Box::new(
-                                        ::ethers::core::abi::ethabi::ParamType::Address,
-                                    ),
-                                ),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address[]"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("amounts"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                ::std::boxed::Box::new(
-                                    ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
-                                ),
-                            ),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256[]"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::Payable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("swapExactETHForTokens"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("swapExactETHForTokens",),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountOutMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("path"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                    ::std::boxed::Box::new(
-                                        ::ethers::core::abi::ethabi::ParamType::Address,
-                                    ),
-                                ),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address[]"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("amounts"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                ::std::boxed::Box::new(
-                                    ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
-                                ),
-                            ),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256[]"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::Payable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned(
-                        "swapExactETHForTokensSupportingFeeOnTransferTokens",
-                    ),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned(
-                            "swapExactETHForTokensSupportingFeeOnTransferTokens",
-                        ),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountOutMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("path"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                    ::std::boxed::Box::new(
-                                        ::ethers::core::abi::ethabi::ParamType::Address,
-                                    ),
-                                ),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address[]"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::Payable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("swapExactTokensForETH"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("swapExactTokensForETH",),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountIn"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountOutMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("path"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                    ::std::boxed::Box::new(
-                                        ::ethers::core::abi::ethabi::ParamType::Address,
-                                    ),
-                                ),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address[]"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("amounts"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                ::std::boxed::Box::new(
-                                    ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
-                                ),
-                            ),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256[]"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned(
-                        "swapExactTokensForETHSupportingFeeOnTransferTokens",
-                    ),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned(
-                            "swapExactTokensForETHSupportingFeeOnTransferTokens",
-                        ),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountIn"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountOutMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("path"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                    ::std::boxed::Box::new(
-                                        ::ethers::core::abi::ethabi::ParamType::Address,
-                                    ),
-                                ),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address[]"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("swapExactTokensForTokens"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("swapExactTokensForTokens",),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountIn"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountOutMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("path"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                    ::std::boxed::Box::new(
-                                        ::ethers::core::abi::ethabi::ParamType::Address,
-                                    ),
-                                ),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address[]"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("amounts"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                ::std::boxed::Box::new(
-                                    ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
-                                ),
-                            ),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256[]"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned(
-                        "swapExactTokensForTokensSupportingFeeOnTransferTokens",
-                    ),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned(
-                            "swapExactTokensForTokensSupportingFeeOnTransferTokens",
-                        ),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountIn"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountOutMin"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("path"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                    ::std::boxed::Box::new(
-                                        ::ethers::core::abi::ethabi::ParamType::Address,
-                                    ),
-                                ),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address[]"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("swapTokensForExactETH"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("swapTokensForExactETH",),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountOut"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountInMax"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("path"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                    ::std::boxed::Box::new(
-                                        ::ethers::core::abi::ethabi::ParamType::Address,
-                                    ),
-                                ),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address[]"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("amounts"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                ::std::boxed::Box::new(
-                                    ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
-                                ),
-                            ),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256[]"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-                (
-                    ::std::borrow::ToOwned::to_owned("swapTokensForExactTokens"),
-                    ::std::vec![::ethers::core::abi::ethabi::Function {
-                        name: ::std::borrow::ToOwned::to_owned("swapTokensForExactTokens",),
-                        inputs: ::std::vec![
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountOut"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("amountInMax"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("path"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                    ::std::boxed::Box::new(
-                                        ::ethers::core::abi::ethabi::ParamType::Address,
-                                    ),
-                                ),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address[]"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("to"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("address"),
-                                ),
-                            },
-                            ::ethers::core::abi::ethabi::Param {
-                                name: ::std::borrow::ToOwned::to_owned("deadline"),
-                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
-                                internal_type: ::core::option::Option::Some(
-                                    ::std::borrow::ToOwned::to_owned("uint256"),
-                                ),
-                            },
-                        ],
-                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
-                            name: ::std::borrow::ToOwned::to_owned("amounts"),
-                            kind: ::ethers::core::abi::ethabi::ParamType::Array(
-                                ::std::boxed::Box::new(
-                                    ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
-                                ),
-                            ),
-                            internal_type: ::core::option::Option::Some(
-                                ::std::borrow::ToOwned::to_owned("uint256[]"),
-                            ),
-                        },],
-                        constant: ::core::option::Option::None,
-                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
-                    },],
-                ),
-            ]),
-            events: ::std::collections::BTreeMap::new(),
-            errors: ::std::collections::BTreeMap::new(),
-            receive: true,
-            fallback: false,
-        }
-    }
-    ///The parsed JSON ABI of the contract.
-    pub static UNISWAPV2ROUTER02_ABI: ::ethers::contract::Lazy<::ethers::core::abi::Abi> =
-        ::ethers::contract::Lazy::new(__abi);
-    pub struct UniswapV2Router02<M>(::ethers::contract::Contract<M>);
-    impl<M> ::core::clone::Clone for UniswapV2Router02<M> {
-        fn clone(&self) -> Self {
-            Self(::core::clone::Clone::clone(&self.0))
-        }
-    }
-    impl<M> ::core::ops::Deref for UniswapV2Router02<M> {
-        type Target = ::ethers::contract::Contract<M>;
-        fn deref(&self) -> &Self::Target {
-            &self.0
-        }
-    }
-    impl<M> ::core::ops::DerefMut for UniswapV2Router02<M> {
-        fn deref_mut(&mut self) -> &mut Self::Target {
-            &mut self.0
-        }
-    }
-    impl<M> ::core::fmt::Debug for UniswapV2Router02<M> {
-        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            f.debug_tuple(::core::stringify!(UniswapV2Router02))
-                .field(&self.address())
-                .finish()
-        }
-    }
-    impl<M: ::ethers::providers::Middleware> UniswapV2Router02<M> {
-        /// Creates a new contract instance with the specified `ethers` client at
-        /// `address`. The contract derefs to a `ethers::Contract` object.
-        pub fn new<T: Into<::ethers::core::types::Address>>(
-            address: T,
-            client: ::std::sync::Arc<M>,
-        ) -> Self {
-            Self(::ethers::contract::Contract::new(
-                address.into(),
-                UNISWAPV2ROUTER02_ABI.clone(),
-                client,
-            ))
-        }
-        ///Calls the contract's `WETH` (0xad5c4648) function
-        pub fn weth(
-            &self,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::Address> {
-            self.0
-                .method_hash([173, 92, 70, 72], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `addLiquidity` (0xe8e33700) function
-        pub fn add_liquidity(
-            &self,
-            token_a: ::ethers::core::types::Address,
-            token_b: ::ethers::core::types::Address,
-            amount_a_desired: ::ethers::core::types::U256,
-            amount_b_desired: ::ethers::core::types::U256,
-            amount_a_min: ::ethers::core::types::U256,
-            amount_b_min: ::ethers::core::types::U256,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<
-            M,
-            (
-                ::ethers::core::types::U256,
-                ::ethers::core::types::U256,
-                ::ethers::core::types::U256,
-            ),
-        > {
-            self.0
-                .method_hash(
-                    [232, 227, 55, 0],
-                    (
-                        token_a,
-                        token_b,
-                        amount_a_desired,
-                        amount_b_desired,
-                        amount_a_min,
-                        amount_b_min,
-                        to,
-                        deadline,
-                    ),
-                )
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `addLiquidityETH` (0xf305d719) function
-        pub fn add_liquidity_eth(
-            &self,
-            token: ::ethers::core::types::Address,
-            amount_token_desired: ::ethers::core::types::U256,
-            amount_token_min: ::ethers::core::types::U256,
-            amount_eth_min: ::ethers::core::types::U256,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<
-            M,
-            (
-                ::ethers::core::types::U256,
-                ::ethers::core::types::U256,
-                ::ethers::core::types::U256,
-            ),
-        > {
-            self.0
-                .method_hash(
-                    [243, 5, 215, 25],
-                    (
-                        token,
-                        amount_token_desired,
-                        amount_token_min,
-                        amount_eth_min,
-                        to,
-                        deadline,
-                    ),
-                )
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `factory` (0xc45a0155) function
-        pub fn factory(
-            &self,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::Address> {
-            self.0
-                .method_hash([196, 90, 1, 85], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `getAmountIn` (0x85f8c259) function
-        pub fn get_amount_in(
-            &self,
-            amount_out: ::ethers::core::types::U256,
-            reserve_in: ::ethers::core::types::U256,
-            reserve_out: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
-            self.0
-                .method_hash([133, 248, 194, 89], (amount_out, reserve_in, reserve_out))
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `getAmountOut` (0x054d50d4) function
-        pub fn get_amount_out(
-            &self,
-            amount_in: ::ethers::core::types::U256,
-            reserve_in: ::ethers::core::types::U256,
-            reserve_out: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
-            self.0
-                .method_hash([5, 77, 80, 212], (amount_in, reserve_in, reserve_out))
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `getAmountsIn` (0x1f00ca74) function
-        pub fn get_amounts_in(
-            &self,
-            amount_out: ::ethers::core::types::U256,
-            path: ::std::vec::Vec<::ethers::core::types::Address>,
-        ) -> ::ethers::contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::ethers::core::types::U256>,
-        > {
-            self.0
-                .method_hash([31, 0, 202, 116], (amount_out, path))
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `getAmountsOut` (0xd06ca61f) function
-        pub fn get_amounts_out(
-            &self,
-            amount_in: ::ethers::core::types::U256,
-            path: ::std::vec::Vec<::ethers::core::types::Address>,
-        ) -> ::ethers::contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::ethers::core::types::U256>,
-        > {
-            self.0
-                .method_hash([208, 108, 166, 31], (amount_in, path))
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `quote` (0xad615dec) function
-        pub fn quote(
-            &self,
-            amount_a: ::ethers::core::types::U256,
-            reserve_a: ::ethers::core::types::U256,
-            reserve_b: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
-            self.0
-                .method_hash([173, 97, 93, 236], (amount_a, reserve_a, reserve_b))
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `removeLiquidity` (0xbaa2abde) function
-        pub fn remove_liquidity(
-            &self,
-            token_a: ::ethers::core::types::Address,
-            token_b: ::ethers::core::types::Address,
-            liquidity: ::ethers::core::types::U256,
-            amount_a_min: ::ethers::core::types::U256,
-            amount_b_min: ::ethers::core::types::U256,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<
-            M,
-            (::ethers::core::types::U256, ::ethers::core::types::U256),
-        > {
-            self.0
-                .method_hash(
-                    [186, 162, 171, 222],
-                    (
-                        token_a,
-                        token_b,
-                        liquidity,
-                        amount_a_min,
-                        amount_b_min,
-                        to,
-                        deadline,
-                    ),
-                )
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `removeLiquidityETH` (0x02751cec) function
-        pub fn remove_liquidity_eth(
-            &self,
-            token: ::ethers::core::types::Address,
-            liquidity: ::ethers::core::types::U256,
-            amount_token_min: ::ethers::core::types::U256,
-            amount_eth_min: ::ethers::core::types::U256,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<
-            M,
-            (::ethers::core::types::U256, ::ethers::core::types::U256),
-        > {
-            self.0
-                .method_hash(
-                    [2, 117, 28, 236],
-                    (
-                        token,
-                        liquidity,
-                        amount_token_min,
-                        amount_eth_min,
-                        to,
-                        deadline,
-                    ),
-                )
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `removeLiquidityETHSupportingFeeOnTransferTokens` (0xaf2979eb) function
-        pub fn remove_liquidity_eth_supporting_fee_on_transfer_tokens(
-            &self,
-            token: ::ethers::core::types::Address,
-            liquidity: ::ethers::core::types::U256,
-            amount_token_min: ::ethers::core::types::U256,
-            amount_eth_min: ::ethers::core::types::U256,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
-            self.0
-                .method_hash(
-                    [175, 41, 121, 235],
-                    (
-                        token,
-                        liquidity,
-                        amount_token_min,
-                        amount_eth_min,
-                        to,
-                        deadline,
-                    ),
-                )
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `removeLiquidityETHWithPermit` (0xded9382a) function
-        pub fn remove_liquidity_eth_with_permit(
-            &self,
-            token: ::ethers::core::types::Address,
-            liquidity: ::ethers::core::types::U256,
-            amount_token_min: ::ethers::core::types::U256,
-            amount_eth_min: ::ethers::core::types::U256,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-            approve_max: bool,
-            v: u8,
-            r: [u8; 32],
-            s: [u8; 32],
-        ) -> ::ethers::contract::builders::ContractCall<
-            M,
-            (::ethers::core::types::U256, ::ethers::core::types::U256),
-        > {
-            self.0
-                .method_hash(
-                    [222, 217, 56, 42],
-                    (
-                        token,
-                        liquidity,
-                        amount_token_min,
-                        amount_eth_min,
-                        to,
-                        deadline,
-                        approve_max,
-                        v,
-                        r,
-                        s,
-                    ),
-                )
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `removeLiquidityETHWithPermitSupportingFeeOnTransferTokens` (0x5b0d5984) function
-        pub fn remove_liquidity_eth_with_permit_supporting_fee_on_transfer_tokens(
-            &self,
-            token: ::ethers::core::types::Address,
-            liquidity: ::ethers::core::types::U256,
-            amount_token_min: ::ethers::core::types::U256,
-            amount_eth_min: ::ethers::core::types::U256,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-            approve_max: bool,
-            v: u8,
-            r: [u8; 32],
-            s: [u8; 32],
-        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
-            self.0
-                .method_hash(
-                    [91, 13, 89, 132],
-                    (
-                        token,
-                        liquidity,
-                        amount_token_min,
-                        amount_eth_min,
-                        to,
-                        deadline,
-                        approve_max,
-                        v,
-                        r,
-                        s,
-                    ),
-                )
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `removeLiquidityWithPermit` (0x2195995c) function
-        pub fn remove_liquidity_with_permit(
-            &self,
-            token_a: ::ethers::core::types::Address,
-            token_b: ::ethers::core::types::Address,
-            liquidity: ::ethers::core::types::U256,
-            amount_a_min: ::ethers::core::types::U256,
-            amount_b_min: ::ethers::core::types::U256,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-            approve_max: bool,
-            v: u8,
-            r: [u8; 32],
-            s: [u8; 32],
-        ) -> ::ethers::contract::builders::ContractCall<
-            M,
-            (::ethers::core::types::U256, ::ethers::core::types::U256),
-        > {
-            self.0
-                .method_hash(
-                    [33, 149, 153, 92],
-                    (
-                        token_a,
-                        token_b,
-                        liquidity,
-                        amount_a_min,
-                        amount_b_min,
-                        to,
-                        deadline,
-                        approve_max,
-                        v,
-                        r,
-                        s,
-                    ),
-                )
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `swapETHForExactTokens` (0xfb3bdb41) function
-        pub fn swap_eth_for_exact_tokens(
-            &self,
-            amount_out: ::ethers::core::types::U256,
-            path: ::std::vec::Vec<::ethers::core::types::Address>,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::ethers::core::types::U256>,
-        > {
-            self.0
-                .method_hash([251, 59, 219, 65], (amount_out, path, to, deadline))
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `swapExactETHForTokens` (0x7ff36ab5) function
-        pub fn swap_exact_eth_for_tokens(
-            &self,
-            amount_out_min: ::ethers::core::types::U256,
-            path: ::std::vec::Vec<::ethers::core::types::Address>,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::ethers::core::types::U256>,
-        > {
-            self.0
-                .method_hash([127, 243, 106, 181], (amount_out_min, path, to, deadline))
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `swapExactETHForTokensSupportingFeeOnTransferTokens` (0xb6f9de95) function
-        pub fn swap_exact_eth_for_tokens_supporting_fee_on_transfer_tokens(
-            &self,
-            amount_out_min: ::ethers::core::types::U256,
-            path: ::std::vec::Vec<::ethers::core::types::Address>,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<M, ()> {
-            self.0
-                .method_hash([182, 249, 222, 149], (amount_out_min, path, to, deadline))
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `swapExactTokensForETH` (0x18cbafe5) function
-        pub fn swap_exact_tokens_for_eth(
-            &self,
-            amount_in: ::ethers::core::types::U256,
-            amount_out_min: ::ethers::core::types::U256,
-            path: ::std::vec::Vec<::ethers::core::types::Address>,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::ethers::core::types::U256>,
-        > {
-            self.0
-                .method_hash(
-                    [24, 203, 175, 229],
-                    (amount_in, amount_out_min, path, to, deadline),
-                )
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `swapExactTokensForETHSupportingFeeOnTransferTokens` (0x791ac947) function
-        pub fn swap_exact_tokens_for_eth_supporting_fee_on_transfer_tokens(
-            &self,
-            amount_in: ::ethers::core::types::U256,
-            amount_out_min: ::ethers::core::types::U256,
-            path: ::std::vec::Vec<::ethers::core::types::Address>,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<M, ()> {
-            self.0
-                .method_hash(
-                    [121, 26, 201, 71],
-                    (amount_in, amount_out_min, path, to, deadline),
-                )
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `swapExactTokensForTokens` (0x38ed1739) function
-        pub fn swap_exact_tokens_for_tokens(
-            &self,
-            amount_in: ::ethers::core::types::U256,
-            amount_out_min: ::ethers::core::types::U256,
-            path: ::std::vec::Vec<::ethers::core::types::Address>,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::ethers::core::types::U256>,
-        > {
-            self.0
-                .method_hash(
-                    [56, 237, 23, 57],
-                    (amount_in, amount_out_min, path, to, deadline),
-                )
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `swapExactTokensForTokensSupportingFeeOnTransferTokens` (0x5c11d795) function
-        pub fn swap_exact_tokens_for_tokens_supporting_fee_on_transfer_tokens(
-            &self,
-            amount_in: ::ethers::core::types::U256,
-            amount_out_min: ::ethers::core::types::U256,
-            path: ::std::vec::Vec<::ethers::core::types::Address>,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<M, ()> {
-            self.0
-                .method_hash(
-                    [92, 17, 215, 149],
-                    (amount_in, amount_out_min, path, to, deadline),
-                )
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `swapTokensForExactETH` (0x4a25d94a) function
-        pub fn swap_tokens_for_exact_eth(
-            &self,
-            amount_out: ::ethers::core::types::U256,
-            amount_in_max: ::ethers::core::types::U256,
-            path: ::std::vec::Vec<::ethers::core::types::Address>,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::ethers::core::types::U256>,
-        > {
-            self.0
-                .method_hash(
-                    [74, 37, 217, 74],
-                    (amount_out, amount_in_max, path, to, deadline),
-                )
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `swapTokensForExactTokens` (0x8803dbee) function
-        pub fn swap_tokens_for_exact_tokens(
-            &self,
-            amount_out: ::ethers::core::types::U256,
-            amount_in_max: ::ethers::core::types::U256,
-            path: ::std::vec::Vec<::ethers::core::types::Address>,
-            to: ::ethers::core::types::Address,
-            deadline: ::ethers::core::types::U256,
-        ) -> ::ethers::contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::ethers::core::types::U256>,
-        > {
-            self.0
-                .method_hash(
-                    [136, 3, 219, 238],
-                    (amount_out, amount_in_max, path, to, deadline),
-                )
-                .expect("method not found (this should never happen)")
-        }
-    }
-    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>>
-        for UniswapV2Router02<M>
-    {
-        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
-            Self::new(contract.address(), contract.client())
-        }
-    }
-    ///Container type for all input parameters for the `WETH` function with signature `WETH()` and selector `0xad5c4648`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "WETH", abi = "WETH()")]
-    pub struct WethCall;
-    ///Container type for all input parameters for the `addLiquidity` function with signature `addLiquidity(address,address,uint256,uint256,uint256,uint256,address,uint256)` and selector `0xe8e33700`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "addLiquidity",
-        abi = "addLiquidity(address,address,uint256,uint256,uint256,uint256,address,uint256)"
-    )]
-    pub struct AddLiquidityCall {
-        pub token_a: ::ethers::core::types::Address,
-        pub token_b: ::ethers::core::types::Address,
-        pub amount_a_desired: ::ethers::core::types::U256,
-        pub amount_b_desired: ::ethers::core::types::U256,
-        pub amount_a_min: ::ethers::core::types::U256,
-        pub amount_b_min: ::ethers::core::types::U256,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `addLiquidityETH` function with signature `addLiquidityETH(address,uint256,uint256,uint256,address,uint256)` and selector `0xf305d719`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "addLiquidityETH",
-        abi = "addLiquidityETH(address,uint256,uint256,uint256,address,uint256)"
-    )]
-    pub struct AddLiquidityETHCall {
-        pub token: ::ethers::core::types::Address,
-        pub amount_token_desired: ::ethers::core::types::U256,
-        pub amount_token_min: ::ethers::core::types::U256,
-        pub amount_eth_min: ::ethers::core::types::U256,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `factory` function with signature `factory()` and selector `0xc45a0155`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "factory", abi = "factory()")]
-    pub struct FactoryCall;
-    ///Container type for all input parameters for the `getAmountIn` function with signature `getAmountIn(uint256,uint256,uint256)` and selector `0x85f8c259`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "getAmountIn", abi = "getAmountIn(uint256,uint256,uint256)")]
-    pub struct GetAmountInCall {
-        pub amount_out: ::ethers::core::types::U256,
-        pub reserve_in: ::ethers::core::types::U256,
-        pub reserve_out: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `getAmountOut` function with signature `getAmountOut(uint256,uint256,uint256)` and selector `0x054d50d4`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "getAmountOut", abi = "getAmountOut(uint256,uint256,uint256)")]
-    pub struct GetAmountOutCall {
-        pub amount_in: ::ethers::core::types::U256,
-        pub reserve_in: ::ethers::core::types::U256,
-        pub reserve_out: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `getAmountsIn` function with signature `getAmountsIn(uint256,address[])` and selector `0x1f00ca74`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "getAmountsIn", abi = "getAmountsIn(uint256,address[])")]
-    pub struct GetAmountsInCall {
-        pub amount_out: ::ethers::core::types::U256,
-        pub path: ::std::vec::Vec<::ethers::core::types::Address>,
-    }
-    ///Container type for all input parameters for the `getAmountsOut` function with signature `getAmountsOut(uint256,address[])` and selector `0xd06ca61f`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "getAmountsOut", abi = "getAmountsOut(uint256,address[])")]
-    pub struct GetAmountsOutCall {
-        pub amount_in: ::ethers::core::types::U256,
-        pub path: ::std::vec::Vec<::ethers::core::types::Address>,
-    }
-    ///Container type for all input parameters for the `quote` function with signature `quote(uint256,uint256,uint256)` and selector `0xad615dec`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(name = "quote", abi = "quote(uint256,uint256,uint256)")]
-    pub struct QuoteCall {
-        pub amount_a: ::ethers::core::types::U256,
-        pub reserve_a: ::ethers::core::types::U256,
-        pub reserve_b: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `removeLiquidity` function with signature `removeLiquidity(address,address,uint256,uint256,uint256,address,uint256)` and selector `0xbaa2abde`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "removeLiquidity",
-        abi = "removeLiquidity(address,address,uint256,uint256,uint256,address,uint256)"
-    )]
-    pub struct RemoveLiquidityCall {
-        pub token_a: ::ethers::core::types::Address,
-        pub token_b: ::ethers::core::types::Address,
-        pub liquidity: ::ethers::core::types::U256,
-        pub amount_a_min: ::ethers::core::types::U256,
-        pub amount_b_min: ::ethers::core::types::U256,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `removeLiquidityETH` function with signature `removeLiquidityETH(address,uint256,uint256,uint256,address,uint256)` and selector `0x02751cec`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "removeLiquidityETH",
-        abi = "removeLiquidityETH(address,uint256,uint256,uint256,address,uint256)"
-    )]
-    pub struct RemoveLiquidityETHCall {
-        pub token: ::ethers::core::types::Address,
-        pub liquidity: ::ethers::core::types::U256,
-        pub amount_token_min: ::ethers::core::types::U256,
-        pub amount_eth_min: ::ethers::core::types::U256,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `removeLiquidityETHSupportingFeeOnTransferTokens` function with signature `removeLiquidityETHSupportingFeeOnTransferTokens(address,uint256,uint256,uint256,address,uint256)` and selector `0xaf2979eb`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "removeLiquidityETHSupportingFeeOnTransferTokens",
-        abi = "removeLiquidityETHSupportingFeeOnTransferTokens(address,uint256,uint256,uint256,address,uint256)"
-    )]
-    pub struct RemoveLiquidityETHSupportingFeeOnTransferTokensCall {
-        pub token: ::ethers::core::types::Address,
-        pub liquidity: ::ethers::core::types::U256,
-        pub amount_token_min: ::ethers::core::types::U256,
-        pub amount_eth_min: ::ethers::core::types::U256,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `removeLiquidityETHWithPermit` function with signature `removeLiquidityETHWithPermit(address,uint256,uint256,uint256,address,uint256,bool,uint8,bytes32,bytes32)` and selector `0xded9382a`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "removeLiquidityETHWithPermit",
-        abi = "removeLiquidityETHWithPermit(address,uint256,uint256,uint256,address,uint256,bool,uint8,bytes32,bytes32)"
-    )]
-    pub struct RemoveLiquidityETHWithPermitCall {
-        pub token: ::ethers::core::types::Address,
-        pub liquidity: ::ethers::core::types::U256,
-        pub amount_token_min: ::ethers::core::types::U256,
-        pub amount_eth_min: ::ethers::core::types::U256,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-        pub approve_max: bool,
-        pub v: u8,
-        pub r: [u8; 32],
-        pub s: [u8; 32],
-    }
-    ///Container type for all input parameters for the `removeLiquidityETHWithPermitSupportingFeeOnTransferTokens` function with signature `removeLiquidityETHWithPermitSupportingFeeOnTransferTokens(address,uint256,uint256,uint256,address,uint256,bool,uint8,bytes32,bytes32)` and selector `0x5b0d5984`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "removeLiquidityETHWithPermitSupportingFeeOnTransferTokens",
-        abi = "removeLiquidityETHWithPermitSupportingFeeOnTransferTokens(address,uint256,uint256,uint256,address,uint256,bool,uint8,bytes32,bytes32)"
-    )]
-    pub struct RemoveLiquidityETHWithPermitSupportingFeeOnTransferTokensCall {
-        pub token: ::ethers::core::types::Address,
-        pub liquidity: ::ethers::core::types::U256,
-        pub amount_token_min: ::ethers::core::types::U256,
-        pub amount_eth_min: ::ethers::core::types::U256,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-        pub approve_max: bool,
-        pub v: u8,
-        pub r: [u8; 32],
-        pub s: [u8; 32],
-    }
-    ///Container type for all input parameters for the `removeLiquidityWithPermit` function with signature `removeLiquidityWithPermit(address,address,uint256,uint256,uint256,address,uint256,bool,uint8,bytes32,bytes32)` and selector `0x2195995c`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "removeLiquidityWithPermit",
-        abi = "removeLiquidityWithPermit(address,address,uint256,uint256,uint256,address,uint256,bool,uint8,bytes32,bytes32)"
-    )]
-    pub struct RemoveLiquidityWithPermitCall {
-        pub token_a: ::ethers::core::types::Address,
-        pub token_b: ::ethers::core::types::Address,
-        pub liquidity: ::ethers::core::types::U256,
-        pub amount_a_min: ::ethers::core::types::U256,
-        pub amount_b_min: ::ethers::core::types::U256,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-        pub approve_max: bool,
-        pub v: u8,
-        pub r: [u8; 32],
-        pub s: [u8; 32],
-    }
-    ///Container type for all input parameters for the `swapETHForExactTokens` function with signature `swapETHForExactTokens(uint256,address[],address,uint256)` and selector `0xfb3bdb41`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "swapETHForExactTokens",
-        abi = "swapETHForExactTokens(uint256,address[],address,uint256)"
-    )]
-    pub struct SwapETHForExactTokensCall {
-        pub amount_out: ::ethers::core::types::U256,
-        pub path: ::std::vec::Vec<::ethers::core::types::Address>,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `swapExactETHForTokens` function with signature `swapExactETHForTokens(uint256,address[],address,uint256)` and selector `0x7ff36ab5`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "swapExactETHForTokens",
-        abi = "swapExactETHForTokens(uint256,address[],address,uint256)"
-    )]
-    pub struct SwapExactETHForTokensCall {
-        pub amount_out_min: ::ethers::core::types::U256,
-        pub path: ::std::vec::Vec<::ethers::core::types::Address>,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `swapExactETHForTokensSupportingFeeOnTransferTokens` function with signature `swapExactETHForTokensSupportingFeeOnTransferTokens(uint256,address[],address,uint256)` and selector `0xb6f9de95`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "swapExactETHForTokensSupportingFeeOnTransferTokens",
-        abi = "swapExactETHForTokensSupportingFeeOnTransferTokens(uint256,address[],address,uint256)"
-    )]
-    pub struct SwapExactETHForTokensSupportingFeeOnTransferTokensCall {
-        pub amount_out_min: ::ethers::core::types::U256,
-        pub path: ::std::vec::Vec<::ethers::core::types::Address>,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `swapExactTokensForETH` function with signature `swapExactTokensForETH(uint256,uint256,address[],address,uint256)` and selector `0x18cbafe5`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "swapExactTokensForETH",
-        abi = "swapExactTokensForETH(uint256,uint256,address[],address,uint256)"
-    )]
-    pub struct SwapExactTokensForETHCall {
-        pub amount_in: ::ethers::core::types::U256,
-        pub amount_out_min: ::ethers::core::types::U256,
-        pub path: ::std::vec::Vec<::ethers::core::types::Address>,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `swapExactTokensForETHSupportingFeeOnTransferTokens` function with signature `swapExactTokensForETHSupportingFeeOnTransferTokens(uint256,uint256,address[],address,uint256)` and selector `0x791ac947`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "swapExactTokensForETHSupportingFeeOnTransferTokens",
-        abi = "swapExactTokensForETHSupportingFeeOnTransferTokens(uint256,uint256,address[],address,uint256)"
-    )]
-    pub struct SwapExactTokensForETHSupportingFeeOnTransferTokensCall {
-        pub amount_in: ::ethers::core::types::U256,
-        pub amount_out_min: ::ethers::core::types::U256,
-        pub path: ::std::vec::Vec<::ethers::core::types::Address>,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `swapExactTokensForTokens` function with signature `swapExactTokensForTokens(uint256,uint256,address[],address,uint256)` and selector `0x38ed1739`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "swapExactTokensForTokens",
-        abi = "swapExactTokensForTokens(uint256,uint256,address[],address,uint256)"
-    )]
-    pub struct SwapExactTokensForTokensCall {
-        pub amount_in: ::ethers::core::types::U256,
-        pub amount_out_min: ::ethers::core::types::U256,
-        pub path: ::std::vec::Vec<::ethers::core::types::Address>,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `swapExactTokensForTokensSupportingFeeOnTransferTokens` function with signature `swapExactTokensForTokensSupportingFeeOnTransferTokens(uint256,uint256,address[],address,uint256)` and selector `0x5c11d795`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "swapExactTokensForTokensSupportingFeeOnTransferTokens",
-        abi = "swapExactTokensForTokensSupportingFeeOnTransferTokens(uint256,uint256,address[],address,uint256)"
-    )]
-    pub struct SwapExactTokensForTokensSupportingFeeOnTransferTokensCall {
-        pub amount_in: ::ethers::core::types::U256,
-        pub amount_out_min: ::ethers::core::types::U256,
-        pub path: ::std::vec::Vec<::ethers::core::types::Address>,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `swapTokensForExactETH` function with signature `swapTokensForExactETH(uint256,uint256,address[],address,uint256)` and selector `0x4a25d94a`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "swapTokensForExactETH",
-        abi = "swapTokensForExactETH(uint256,uint256,address[],address,uint256)"
-    )]
-    pub struct SwapTokensForExactETHCall {
-        pub amount_out: ::ethers::core::types::U256,
-        pub amount_in_max: ::ethers::core::types::U256,
-        pub path: ::std::vec::Vec<::ethers::core::types::Address>,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-    }
-    ///Container type for all input parameters for the `swapTokensForExactTokens` function with signature `swapTokensForExactTokens(uint256,uint256,address[],address,uint256)` and selector `0x8803dbee`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthCall,
-        ::ethers::contract::EthDisplay,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    #[ethcall(
-        name = "swapTokensForExactTokens",
-        abi = "swapTokensForExactTokens(uint256,uint256,address[],address,uint256)"
-    )]
-    pub struct SwapTokensForExactTokensCall {
-        pub amount_out: ::ethers::core::types::U256,
-        pub amount_in_max: ::ethers::core::types::U256,
-        pub path: ::std::vec::Vec<::ethers::core::types::Address>,
-        pub to: ::ethers::core::types::Address,
-        pub deadline: ::ethers::core::types::U256,
-    }
-    ///Container type for all of the contract's call
-    #[derive(Clone, ::ethers::contract::EthAbiType, Debug, PartialEq, Eq, Hash)]
-    pub enum UniswapV2Router02Calls {
-        Weth(WethCall),
-        AddLiquidity(AddLiquidityCall),
-        AddLiquidityETH(AddLiquidityETHCall),
-        Factory(FactoryCall),
-        GetAmountIn(GetAmountInCall),
-        GetAmountOut(GetAmountOutCall),
-        GetAmountsIn(GetAmountsInCall),
-        GetAmountsOut(GetAmountsOutCall),
-        Quote(QuoteCall),
-        RemoveLiquidity(RemoveLiquidityCall),
-        RemoveLiquidityETH(RemoveLiquidityETHCall),
-        RemoveLiquidityETHSupportingFeeOnTransferTokens(
-            RemoveLiquidityETHSupportingFeeOnTransferTokensCall,
-        ),
-        RemoveLiquidityETHWithPermit(RemoveLiquidityETHWithPermitCall),
-        RemoveLiquidityETHWithPermitSupportingFeeOnTransferTokens(
-            RemoveLiquidityETHWithPermitSupportingFeeOnTransferTokensCall,
-        ),
-        RemoveLiquidityWithPermit(RemoveLiquidityWithPermitCall),
-        SwapETHForExactTokens(SwapETHForExactTokensCall),
-        SwapExactETHForTokens(SwapExactETHForTokensCall),
-        SwapExactETHForTokensSupportingFeeOnTransferTokens(
-            SwapExactETHForTokensSupportingFeeOnTransferTokensCall,
-        ),
-        SwapExactTokensForETH(SwapExactTokensForETHCall),
-        SwapExactTokensForETHSupportingFeeOnTransferTokens(
-            SwapExactTokensForETHSupportingFeeOnTransferTokensCall,
-        ),
-        SwapExactTokensForTokens(SwapExactTokensForTokensCall),
-        SwapExactTokensForTokensSupportingFeeOnTransferTokens(
-            SwapExactTokensForTokensSupportingFeeOnTransferTokensCall,
-        ),
-        SwapTokensForExactETH(SwapTokensForExactETHCall),
-        SwapTokensForExactTokens(SwapTokensForExactTokensCall),
-    }
-    impl ::ethers::core::abi::AbiDecode for UniswapV2Router02Calls {
-        fn decode(
-            data: impl AsRef<[u8]>,
-        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
-            let data = data.as_ref();
-            if let Ok(decoded) = <WethCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Weth(decoded));
-            }
-            if let Ok(decoded) = <AddLiquidityCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::AddLiquidity(decoded));
-            }
-            if let Ok(decoded) =
-                <AddLiquidityETHCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::AddLiquidityETH(decoded));
-            }
-            if let Ok(decoded) = <FactoryCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Factory(decoded));
-            }
-            if let Ok(decoded) = <GetAmountInCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::GetAmountIn(decoded));
-            }
-            if let Ok(decoded) = <GetAmountOutCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::GetAmountOut(decoded));
-            }
-            if let Ok(decoded) = <GetAmountsInCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::GetAmountsIn(decoded));
-            }
-            if let Ok(decoded) = <GetAmountsOutCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::GetAmountsOut(decoded));
-            }
-            if let Ok(decoded) = <QuoteCall as ::ethers::core::abi::AbiDecode>::decode(data) {
-                return Ok(Self::Quote(decoded));
-            }
-            if let Ok(decoded) =
-                <RemoveLiquidityCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::RemoveLiquidity(decoded));
-            }
-            if let Ok(decoded) =
-                <RemoveLiquidityETHCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::RemoveLiquidityETH(decoded));
-            }
-            if let Ok(decoded) = <RemoveLiquidityETHSupportingFeeOnTransferTokensCall as ::ethers::core::abi::AbiDecode>::decode(
-                data,
-            ) {
-                return Ok(
-                    Self::RemoveLiquidityETHSupportingFeeOnTransferTokens(decoded),
-                );
-            }
-            if let Ok(decoded) =
-                <RemoveLiquidityETHWithPermitCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::RemoveLiquidityETHWithPermit(decoded));
-            }
-            if let Ok(decoded) = <RemoveLiquidityETHWithPermitSupportingFeeOnTransferTokensCall as ::ethers::core::abi::AbiDecode>::decode(
-                data,
-            ) {
-                return Ok(
-                    Self::RemoveLiquidityETHWithPermitSupportingFeeOnTransferTokens(
-                        decoded,
-                    ),
-                );
-            }
-            if let Ok(decoded) =
-                <RemoveLiquidityWithPermitCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::RemoveLiquidityWithPermit(decoded));
-            }
-            if let Ok(decoded) =
-                <SwapETHForExactTokensCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::SwapETHForExactTokens(decoded));
-            }
-            if let Ok(decoded) =
-                <SwapExactETHForTokensCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::SwapExactETHForTokens(decoded));
-            }
-            if let Ok(decoded) = <SwapExactETHForTokensSupportingFeeOnTransferTokensCall as ::ethers::core::abi::AbiDecode>::decode(
-                data,
-            ) {
-                return Ok(
-                    Self::SwapExactETHForTokensSupportingFeeOnTransferTokens(decoded),
-                );
-            }
-            if let Ok(decoded) =
-                <SwapExactTokensForETHCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::SwapExactTokensForETH(decoded));
-            }
-            if let Ok(decoded) = <SwapExactTokensForETHSupportingFeeOnTransferTokensCall as ::ethers::core::abi::AbiDecode>::decode(
-                data,
-            ) {
-                return Ok(
-                    Self::SwapExactTokensForETHSupportingFeeOnTransferTokens(decoded),
-                );
-            }
-            if let Ok(decoded) =
-                <SwapExactTokensForTokensCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::SwapExactTokensForTokens(decoded));
-            }
-            if let Ok(decoded) = <SwapExactTokensForTokensSupportingFeeOnTransferTokensCall as ::ethers::core::abi::AbiDecode>::decode(
-                data,
-            ) {
-                return Ok(
-                    Self::SwapExactTokensForTokensSupportingFeeOnTransferTokens(decoded),
-                );
-            }
-            if let Ok(decoded) =
-                <SwapTokensForExactETHCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::SwapTokensForExactETH(decoded));
-            }
-            if let Ok(decoded) =
-                <SwapTokensForExactTokensCall as ::ethers::core::abi::AbiDecode>::decode(data)
-            {
-                return Ok(Self::SwapTokensForExactTokens(decoded));
-            }
-            Err(::ethers::core::abi::Error::InvalidData.into())
-        }
-    }
-    impl ::ethers::core::abi::AbiEncode for UniswapV2Router02Calls {
-        fn encode(self) -> Vec<u8> {
-            match self {
-                Self::Weth(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::AddLiquidity(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::AddLiquidityETH(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Factory(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::GetAmountIn(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::GetAmountOut(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::GetAmountsIn(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::GetAmountsOut(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::Quote(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::RemoveLiquidity(element) => ::ethers::core::abi::AbiEncode::encode(element),
-                Self::RemoveLiquidityETH(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-                Self::RemoveLiquidityETHSupportingFeeOnTransferTokens(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-                Self::RemoveLiquidityETHWithPermit(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-                Self::RemoveLiquidityETHWithPermitSupportingFeeOnTransferTokens(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-                Self::RemoveLiquidityWithPermit(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-                Self::SwapETHForExactTokens(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-                Self::SwapExactETHForTokens(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-                Self::SwapExactETHForTokensSupportingFeeOnTransferTokens(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-                Self::SwapExactTokensForETH(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-                Self::SwapExactTokensForETHSupportingFeeOnTransferTokens(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-                Self::SwapExactTokensForTokens(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-                Self::SwapExactTokensForTokensSupportingFeeOnTransferTokens(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-                Self::SwapTokensForExactETH(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-                Self::SwapTokensForExactTokens(element) => {
-                    ::ethers::core::abi::AbiEncode::encode(element)
-                }
-            }
-        }
-    }
-    impl ::core::fmt::Display for UniswapV2Router02Calls {
-        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            match self {
-                Self::Weth(element) => ::core::fmt::Display::fmt(element, f),
-                Self::AddLiquidity(element) => ::core::fmt::Display::fmt(element, f),
-                Self::AddLiquidityETH(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Factory(element) => ::core::fmt::Display::fmt(element, f),
-                Self::GetAmountIn(element) => ::core::fmt::Display::fmt(element, f),
-                Self::GetAmountOut(element) => ::core::fmt::Display::fmt(element, f),
-                Self::GetAmountsIn(element) => ::core::fmt::Display::fmt(element, f),
-                Self::GetAmountsOut(element) => ::core::fmt::Display::fmt(element, f),
-                Self::Quote(element) => ::core::fmt::Display::fmt(element, f),
-                Self::RemoveLiquidity(element) => ::core::fmt::Display::fmt(element, f),
-                Self::RemoveLiquidityETH(element) => ::core::fmt::Display::fmt(element, f),
-                Self::RemoveLiquidityETHSupportingFeeOnTransferTokens(element) => {
-                    ::core::fmt::Display::fmt(element, f)
-                }
-                Self::RemoveLiquidityETHWithPermit(element) => {
-                    ::core::fmt::Display::fmt(element, f)
-                }
-                Self::RemoveLiquidityETHWithPermitSupportingFeeOnTransferTokens(element) => {
-                    ::core::fmt::Display::fmt(element, f)
-                }
-                Self::RemoveLiquidityWithPermit(element) => ::core::fmt::Display::fmt(element, f),
-                Self::SwapETHForExactTokens(element) => ::core::fmt::Display::fmt(element, f),
-                Self::SwapExactETHForTokens(element) => ::core::fmt::Display::fmt(element, f),
-                Self::SwapExactETHForTokensSupportingFeeOnTransferTokens(element) => {
-                    ::core::fmt::Display::fmt(element, f)
-                }
-                Self::SwapExactTokensForETH(element) => ::core::fmt::Display::fmt(element, f),
-                Self::SwapExactTokensForETHSupportingFeeOnTransferTokens(element) => {
-                    ::core::fmt::Display::fmt(element, f)
-                }
-                Self::SwapExactTokensForTokens(element) => ::core::fmt::Display::fmt(element, f),
-                Self::SwapExactTokensForTokensSupportingFeeOnTransferTokens(element) => {
-                    ::core::fmt::Display::fmt(element, f)
-                }
-                Self::SwapTokensForExactETH(element) => ::core::fmt::Display::fmt(element, f),
-                Self::SwapTokensForExactTokens(element) => ::core::fmt::Display::fmt(element, f),
-            }
-        }
-    }
-    impl ::core::convert::From<WethCall> for UniswapV2Router02Calls {
-        fn from(value: WethCall) -> Self {
-            Self::Weth(value)
-        }
-    }
-    impl ::core::convert::From<AddLiquidityCall> for UniswapV2Router02Calls {
-        fn from(value: AddLiquidityCall) -> Self {
-            Self::AddLiquidity(value)
-        }
-    }
-    impl ::core::convert::From<AddLiquidityETHCall> for UniswapV2Router02Calls {
-        fn from(value: AddLiquidityETHCall) -> Self {
-            Self::AddLiquidityETH(value)
-        }
-    }
-    impl ::core::convert::From<FactoryCall> for UniswapV2Router02Calls {
-        fn from(value: FactoryCall) -> Self {
-            Self::Factory(value)
-        }
-    }
-    impl ::core::convert::From<GetAmountInCall> for UniswapV2Router02Calls {
-        fn from(value: GetAmountInCall) -> Self {
-            Self::GetAmountIn(value)
-        }
-    }
-    impl ::core::convert::From<GetAmountOutCall> for UniswapV2Router02Calls {
-        fn from(value: GetAmountOutCall) -> Self {
-            Self::GetAmountOut(value)
-        }
-    }
-    impl ::core::convert::From<GetAmountsInCall> for UniswapV2Router02Calls {
-        fn from(value: GetAmountsInCall) -> Self {
-            Self::GetAmountsIn(value)
-        }
-    }
-    impl ::core::convert::From<GetAmountsOutCall> for UniswapV2Router02Calls {
-        fn from(value: GetAmountsOutCall) -> Self {
-            Self::GetAmountsOut(value)
-        }
-    }
-    impl ::core::convert::From<QuoteCall> for UniswapV2Router02Calls {
-        fn from(value: QuoteCall) -> Self {
-            Self::Quote(value)
-        }
-    }
-    impl ::core::convert::From<RemoveLiquidityCall> for UniswapV2Router02Calls {
-        fn from(value: RemoveLiquidityCall) -> Self {
-            Self::RemoveLiquidity(value)
-        }
-    }
-    impl ::core::convert::From<RemoveLiquidityETHCall> for UniswapV2Router02Calls {
-        fn from(value: RemoveLiquidityETHCall) -> Self {
-            Self::RemoveLiquidityETH(value)
-        }
-    }
-    impl ::core::convert::From<RemoveLiquidityETHSupportingFeeOnTransferTokensCall>
-        for UniswapV2Router02Calls
-    {
-        fn from(value: RemoveLiquidityETHSupportingFeeOnTransferTokensCall) -> Self {
-            Self::RemoveLiquidityETHSupportingFeeOnTransferTokens(value)
-        }
-    }
-    impl ::core::convert::From<RemoveLiquidityETHWithPermitCall> for UniswapV2Router02Calls {
-        fn from(value: RemoveLiquidityETHWithPermitCall) -> Self {
-            Self::RemoveLiquidityETHWithPermit(value)
-        }
-    }
-    impl ::core::convert::From<RemoveLiquidityETHWithPermitSupportingFeeOnTransferTokensCall>
-        for UniswapV2Router02Calls
-    {
-        fn from(value: RemoveLiquidityETHWithPermitSupportingFeeOnTransferTokensCall) -> Self {
-            Self::RemoveLiquidityETHWithPermitSupportingFeeOnTransferTokens(value)
-        }
-    }
-    impl ::core::convert::From<RemoveLiquidityWithPermitCall> for UniswapV2Router02Calls {
-        fn from(value: RemoveLiquidityWithPermitCall) -> Self {
-            Self::RemoveLiquidityWithPermit(value)
-        }
-    }
-    impl ::core::convert::From<SwapETHForExactTokensCall> for UniswapV2Router02Calls {
-        fn from(value: SwapETHForExactTokensCall) -> Self {
-            Self::SwapETHForExactTokens(value)
-        }
-    }
-    impl ::core::convert::From<SwapExactETHForTokensCall> for UniswapV2Router02Calls {
-        fn from(value: SwapExactETHForTokensCall) -> Self {
-            Self::SwapExactETHForTokens(value)
-        }
-    }
-    impl ::core::convert::From<SwapExactETHForTokensSupportingFeeOnTransferTokensCall>
-        for UniswapV2Router02Calls
-    {
-        fn from(value: SwapExactETHForTokensSupportingFeeOnTransferTokensCall) -> Self {
-            Self::SwapExactETHForTokensSupportingFeeOnTransferTokens(value)
-        }
-    }
-    impl ::core::convert::From<SwapExactTokensForETHCall> for UniswapV2Router02Calls {
-        fn from(value: SwapExactTokensForETHCall) -> Self {
-            Self::SwapExactTokensForETH(value)
-        }
-    }
-    impl ::core::convert::From<SwapExactTokensForETHSupportingFeeOnTransferTokensCall>
-        for UniswapV2Router02Calls
-    {
-        fn from(value: SwapExactTokensForETHSupportingFeeOnTransferTokensCall) -> Self {
-            Self::SwapExactTokensForETHSupportingFeeOnTransferTokens(value)
-        }
-    }
-    impl ::core::convert::From<SwapExactTokensForTokensCall> for UniswapV2Router02Calls {
-        fn from(value: SwapExactTokensForTokensCall) -> Self {
-            Self::SwapExactTokensForTokens(value)
-        }
-    }
-    impl ::core::convert::From<SwapExactTokensForTokensSupportingFeeOnTransferTokensCall>
-        for UniswapV2Router02Calls
-    {
-        fn from(value: SwapExactTokensForTokensSupportingFeeOnTransferTokensCall) -> Self {
-            Self::SwapExactTokensForTokensSupportingFeeOnTransferTokens(value)
-        }
-    }
-    impl ::core::convert::From<SwapTokensForExactETHCall> for UniswapV2Router02Calls {
-        fn from(value: SwapTokensForExactETHCall) -> Self {
-            Self::SwapTokensForExactETH(value)
-        }
-    }
-    impl ::core::convert::From<SwapTokensForExactTokensCall> for UniswapV2Router02Calls {
-        fn from(value: SwapTokensForExactTokensCall) -> Self {
-            Self::SwapTokensForExactTokens(value)
-        }
-    }
-    ///Container type for all return fields from the `WETH` function with signature `WETH()` and selector `0xad5c4648`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct WethReturn(pub ::ethers::core::types::Address);
-    ///Container type for all return fields from the `addLiquidity` function with signature `addLiquidity(address,address,uint256,uint256,uint256,uint256,address,uint256)` and selector `0xe8e33700`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct AddLiquidityReturn {
-        pub amount_a: ::ethers::core::types::U256,
-        pub amount_b: ::ethers::core::types::U256,
-        pub liquidity: ::ethers::core::types::U256,
-    }
-    ///Container type for all return fields from the `addLiquidityETH` function with signature `addLiquidityETH(address,uint256,uint256,uint256,address,uint256)` and selector `0xf305d719`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct AddLiquidityETHReturn {
-        pub amount_token: ::ethers::core::types::U256,
-        pub amount_eth: ::ethers::core::types::U256,
-        pub liquidity: ::ethers::core::types::U256,
-    }
-    ///Container type for all return fields from the `factory` function with signature `factory()` and selector `0xc45a0155`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct FactoryReturn(pub ::ethers::core::types::Address);
-    ///Container type for all return fields from the `getAmountIn` function with signature `getAmountIn(uint256,uint256,uint256)` and selector `0x85f8c259`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct GetAmountInReturn {
-        pub amount_in: ::ethers::core::types::U256,
-    }
-    ///Container type for all return fields from the `getAmountOut` function with signature `getAmountOut(uint256,uint256,uint256)` and selector `0x054d50d4`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct GetAmountOutReturn {
-        pub amount_out: ::ethers::core::types::U256,
-    }
-    ///Container type for all return fields from the `getAmountsIn` function with signature `getAmountsIn(uint256,address[])` and selector `0x1f00ca74`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct GetAmountsInReturn {
-        pub amounts: ::std::vec::Vec<::ethers::core::types::U256>,
-    }
-    ///Container type for all return fields from the `getAmountsOut` function with signature `getAmountsOut(uint256,address[])` and selector `0xd06ca61f`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct GetAmountsOutReturn {
-        pub amounts: ::std::vec::Vec<::ethers::core::types::U256>,
-    }
-    ///Container type for all return fields from the `quote` function with signature `quote(uint256,uint256,uint256)` and selector `0xad615dec`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct QuoteReturn {
-        pub amount_b: ::ethers::core::types::U256,
-    }
-    ///Container type for all return fields from the `removeLiquidity` function with signature `removeLiquidity(address,address,uint256,uint256,uint256,address,uint256)` and selector `0xbaa2abde`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct RemoveLiquidityReturn {
-        pub amount_a: ::ethers::core::types::U256,
-        pub amount_b: ::ethers::core::types::U256,
-    }
-    ///Container type for all return fields from the `removeLiquidityETH` function with signature `removeLiquidityETH(address,uint256,uint256,uint256,address,uint256)` and selector `0x02751cec`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct RemoveLiquidityETHReturn {
-        pub amount_token: ::ethers::core::types::U256,
-        pub amount_eth: ::ethers::core::types::U256,
-    }
-    ///Container type for all return fields from the `removeLiquidityETHSupportingFeeOnTransferTokens` function with signature `removeLiquidityETHSupportingFeeOnTransferTokens(address,uint256,uint256,uint256,address,uint256)` and selector `0xaf2979eb`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct RemoveLiquidityETHSupportingFeeOnTransferTokensReturn {
-        pub amount_eth: ::ethers::core::types::U256,
-    }
-    ///Container type for all return fields from the `removeLiquidityETHWithPermit` function with signature `removeLiquidityETHWithPermit(address,uint256,uint256,uint256,address,uint256,bool,uint8,bytes32,bytes32)` and selector `0xded9382a`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct RemoveLiquidityETHWithPermitReturn {
-        pub amount_token: ::ethers::core::types::U256,
-        pub amount_eth: ::ethers::core::types::U256,
-    }
-    ///Container type for all return fields from the `removeLiquidityETHWithPermitSupportingFeeOnTransferTokens` function with signature `removeLiquidityETHWithPermitSupportingFeeOnTransferTokens(address,uint256,uint256,uint256,address,uint256,bool,uint8,bytes32,bytes32)` and selector `0x5b0d5984`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct RemoveLiquidityETHWithPermitSupportingFeeOnTransferTokensReturn {
-        pub amount_eth: ::ethers::core::types::U256,
-    }
-    ///Container type for all return fields from the `removeLiquidityWithPermit` function with signature `removeLiquidityWithPermit(address,address,uint256,uint256,uint256,address,uint256,bool,uint8,bytes32,bytes32)` and selector `0x2195995c`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct RemoveLiquidityWithPermitReturn {
-        pub amount_a: ::ethers::core::types::U256,
-        pub amount_b: ::ethers::core::types::U256,
-    }
-    ///Container type for all return fields from the `swapETHForExactTokens` function with signature `swapETHForExactTokens(uint256,address[],address,uint256)` and selector `0xfb3bdb41`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct SwapETHForExactTokensReturn {
-        pub amounts: ::std::vec::Vec<::ethers::core::types::U256>,
-    }
-    ///Container type for all return fields from the `swapExactETHForTokens` function with signature `swapExactETHForTokens(uint256,address[],address,uint256)` and selector `0x7ff36ab5`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct SwapExactETHForTokensReturn {
-        pub amounts: ::std::vec::Vec<::ethers::core::types::U256>,
-    }
-    ///Container type for all return fields from the `swapExactTokensForETH` function with signature `swapExactTokensForETH(uint256,uint256,address[],address,uint256)` and selector `0x18cbafe5`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct SwapExactTokensForETHReturn {
-        pub amounts: ::std::vec::Vec<::ethers::core::types::U256>,
-    }
-    ///Container type for all return fields from the `swapExactTokensForTokens` function with signature `swapExactTokensForTokens(uint256,uint256,address[],address,uint256)` and selector `0x38ed1739`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct SwapExactTokensForTokensReturn {
-        pub amounts: ::std::vec::Vec<::ethers::core::types::U256>,
-    }
-    ///Container type for all return fields from the `swapTokensForExactETH` function with signature `swapTokensForExactETH(uint256,uint256,address[],address,uint256)` and selector `0x4a25d94a`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct SwapTokensForExactETHReturn {
-        pub amounts: ::std::vec::Vec<::ethers::core::types::U256>,
-    }
-    ///Container type for all return fields from the `swapTokensForExactTokens` function with signature `swapTokensForExactTokens(uint256,uint256,address[],address,uint256)` and selector `0x8803dbee`
-    #[derive(
-        Clone,
-        ::ethers::contract::EthAbiType,
-        ::ethers::contract::EthAbiCodec,
-        Default,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-    )]
-    pub struct SwapTokensForExactTokensReturn {
-        pub amounts: ::std::vec::Vec<::ethers::core::types::U256>,
-    }
-}
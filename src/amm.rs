@@ -0,0 +1,74 @@
+use ethers::types::U256;
+
+/// Uniswap V2 swap fee numerator over `FEE_DENOMINATOR` (0.3%).
+const FEE_NUMERATOR: u64 = 997u64;
+const FEE_DENOMINATOR: u64 = 1000u64;
+
+/// Output amount of an exact-in swap, mirrors `UniswapV2Library.getAmountOut`.
+pub fn get_amount_out(amount_in: U256, reserve_in: U256, reserve_out: U256) -> U256 {
+    if amount_in.is_zero() || reserve_in.is_zero() || reserve_out.is_zero() {
+        return U256::zero();
+    }
+    let amount_in_with_fee = amount_in * U256::from(FEE_NUMERATOR);
+    let numerator = amount_in_with_fee * reserve_out;
+    let denominator = reserve_in * U256::from(FEE_DENOMINATOR) + amount_in_with_fee;
+    numerator / denominator
+}
+
+/// Input that maximizes `out - in` when swapping through two pools back to the input token.
+///
+/// Chaining two constant-product swaps gives `out = K x / (L + M x)` with
+/// `K = g^2 r_out_1 r_out_2`, `L = r_in_1 r_in_2` and `M = g r_in_2 + g^2 r_out_1` for the fee
+/// factor `g`, so the profit peaks at `x = (sqrt(K L) - L) / M`. Zero when the pools leave
+/// no profit.
+pub fn optimal_round_trip(
+    reserve_in_1: U256,
+    reserve_out_1: U256,
+    reserve_in_2: U256,
+    reserve_out_2: U256,
+) -> U256 {
+    let raw = |value: U256| value.to_string().parse::<f64>().unwrap_or(0f64);
+    let g = FEE_NUMERATOR as f64 / FEE_DENOMINATOR as f64;
+    let (in_1, out_1, in_2, out_2) = (
+        raw(reserve_in_1),
+        raw(reserve_out_1),
+        raw(reserve_in_2),
+        raw(reserve_out_2),
+    );
+
+    let k = g * g * out_1 * out_2;
+    let l = in_1 * in_2;
+    let m = g * in_2 + g * g * out_1;
+    if k <= l || m == 0f64 {
+        return U256::zero();
+    }
+    let x = ((k * l).sqrt() - l) / m;
+    U256::from_dec_str(&format!("{:.0}", x.floor())).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(x: U256, pools: [U256; 4]) -> U256 {
+        let mid = get_amount_out(x, pools[0], pools[1]);
+        get_amount_out(mid, pools[2], pools[3])
+    }
+
+    #[test]
+    fn optimal_round_trip_maximizes_profit() {
+        let e18 = U256::exp10(18);
+        // token A costs 1 B on the first pool and 1.1 B on the second one
+        let pools = [e18 * 1000, e18 * 1000, e18 * 1000, e18 * 1100];
+        let x = optimal_round_trip(pools[0], pools[1], pools[2], pools[3]);
+        assert!(!x.is_zero());
+
+        let profit = |x: U256| round_trip(x, pools).saturating_sub(x);
+        assert!(profit(x) > U256::zero());
+        assert!(profit(x) >= profit(x * 99 / 100));
+        assert!(profit(x) >= profit(x * 101 / 100));
+
+        // the other way around loses
+        assert!(optimal_round_trip(pools[3], pools[2], pools[1], pools[0]).is_zero());
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{
+    amm::{get_amount_out, optimal_round_trip},
+    config::Config,
+    dex::RouterClient,
+    erc20, pair,
+    tswap::{check_allowance, Tswap},
+    utils::{gas_price, to_float},
+};
+use anyhow::{anyhow, Result};
+use ethers::{
+    providers::Middleware,
+    types::{Address, U256},
+};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Buy token A with token B on one venue and sell it back for token B on another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Opportunity {
+    pub buy_venue: usize,
+    pub sell_venue: usize,
+    /// Token B spent on `buy_venue`.
+    pub amount_in: U256,
+    /// Token A received on `buy_venue` and sold on `sell_venue`.
+    pub amount_a: U256,
+    /// Token B received on `sell_venue`.
+    pub amount_out: U256,
+}
+
+impl Opportunity {
+    /// Token B gained before gas.
+    pub fn profit(&self) -> U256 {
+        self.amount_out.saturating_sub(self.amount_in)
+    }
+}
+
+/// Outcome of spending `amount_in` token B on `buy` then selling the token A on `sell`.
+pub fn evaluate(
+    reserves: &[(U256, U256)],
+    buy_venue: usize,
+    sell_venue: usize,
+    amount_in: U256,
+) -> Opportunity {
+    let (buy_a, buy_b) = reserves[buy_venue];
+    let (sell_a, sell_b) = reserves[sell_venue];
+    let amount_a = get_amount_out(amount_in, buy_b, buy_a);
+    Opportunity {
+        buy_venue,
+        sell_venue,
+        amount_in,
+        amount_a,
+        amount_out: get_amount_out(amount_a, sell_a, sell_b),
+    }
+}
+
+/// Most profitable optimally sized round trip across all venue pairs, given (token A,
+/// token B) reserves per venue.
+pub fn find(reserves: &[(U256, U256)]) -> Option<Opportunity> {
+    let mut best: Option<Opportunity> = None;
+    for buy_venue in 0..reserves.len() {
+        for sell_venue in 0..reserves.len() {
+            if buy_venue == sell_venue {
+                continue;
+            }
+            let (buy_a, buy_b) = reserves[buy_venue];
+            let (sell_a, sell_b) = reserves[sell_venue];
+            let amount_in = optimal_round_trip(buy_b, buy_a, sell_a, sell_b);
+            if amount_in.is_zero() {
+                continue;
+            }
+            let opportunity = evaluate(reserves, buy_venue, sell_venue, amount_in);
+            if opportunity.profit() > best.map(|b| b.profit()).unwrap_or_default() {
+                best = Some(opportunity);
+            }
+        }
+    }
+    best
+}
+
+/// Scan the configured venues every `interval` seconds until Ctrl+C, executing profitable
+/// opportunities when `execute` is set.
+pub async fn scan<M: Middleware + 'static>(
+    client: Arc<M>,
+    config: &Config,
+    owner: Address,
+) -> Result<()> {
+    let arb_config = config
+        .arb
+        .clone()
+        .ok_or_else(|| anyhow!("arb is not set in config"))?;
+    if arb_config.venues.len() < 2 {
+        return Err(anyhow!("arb needs at least 2 venues"));
+    }
+    let token_a_address = Address::from_str(&config.pool.token_a_address)?;
+    let token_b_address = Address::from_str(&config.pool.token_b_address)?;
+    let decimal_b = config.pool.token_b_decimal;
+
+    let mut layouts = vec![];
+    let mut tswaps = vec![];
+    for venue in arb_config.venues.iter() {
+        let mut pool = config.pool.clone();
+        pool.router_address = venue.router_address.clone();
+        pool.factory_address = venue.factory_address.clone();
+        pool.init_code_hash = venue.init_code_hash.clone();
+        pool.pair_address = venue.pair_address.clone();
+        layouts.push(pair::layout(client.clone(), &pool).await?);
+
+        let router_address = Address::from_str(&venue.router_address)?;
+        let dex = RouterClient::new(client.clone(), router_address, owner);
+        tswaps.push((router_address, Tswap::new(Arc::new(dex), config.clone())));
+    }
+    println!(
+        "scanning {} venues, press Ctrl+C to stop",
+        arb_config.venues.len()
+    );
+
+    loop {
+        let mut reserves = vec![];
+        for layout in layouts.iter() {
+            let (reserve_0, reserve_1) =
+                pair::get_reserves(client.clone(), layout.pair_address).await;
+            reserves.push(layout.split(reserve_0, reserve_1));
+        }
+
+        let gas_price = gas_price(client.as_ref(), config).await;
+        let gas_cost =
+            to_float(gas_price * U256::from(arb_config.swap_gas * 2), 18) * arb_config.eth_price;
+
+        if let Some(found) = find(&reserves) {
+            let balance_b = erc20::balance_of(client.clone(), token_b_address, owner).await;
+            let opportunity = if found.amount_in > balance_b && arb_config.execute {
+                evaluate(&reserves, found.buy_venue, found.sell_venue, balance_b)
+            } else {
+                found
+            };
+            let profit = to_float(opportunity.profit(), decimal_b) - gas_cost;
+
+            if profit >= arb_config.min_profit {
+                println!(
+                    "buy on {} with {} ({}), sell {} ({}) on {} for {} ({}), profit {} after {} gas",
+                    arb_config.venues[opportunity.buy_venue].name,
+                    to_float(opportunity.amount_in, decimal_b),
+                    config.pool.token_b_symbol,
+                    to_float(opportunity.amount_a, config.pool.token_a_decimal),
+                    config.pool.token_a_symbol,
+                    arb_config.venues[opportunity.sell_venue].name,
+                    to_float(opportunity.amount_out, decimal_b),
+                    config.pool.token_b_symbol,
+                    profit,
+                    gas_cost
+                );
+
+                if arb_config.execute {
+                    let (buy_router, buy) = &tswaps[opportunity.buy_venue];
+                    let (sell_router, sell) = &tswaps[opportunity.sell_venue];
+
+                    check_allowance(
+                        client.clone(),
+                        buy,
+                        token_b_address,
+                        owner,
+                        *buy_router,
+                        opportunity.amount_in,
+                    )
+                    .await;
+
+                    // the sell leg waits for the buy to be mined and sells what it received
+                    let balance_a = erc20::balance_of(client.clone(), token_a_address, owner).await;
+                    let received = match buy
+                        .swap(token_b_address, token_a_address, opportunity.amount_in)
+                        .await
+                    {
+                        Ok(sent) if buy.confirm_spent(token_b_address, balance_b, sent).await => {
+                            Ok(erc20::balance_of(client.clone(), token_a_address, owner)
+                                .await
+                                .saturating_sub(balance_a))
+                        }
+                        Ok(_) => Err(anyhow!("not mined before the deadline")),
+                        Err(e) => Err(e),
+                    };
+                    match received {
+                        Ok(amount_a) if !amount_a.is_zero() => {
+                            check_allowance(
+                                client.clone(),
+                                sell,
+                                token_a_address,
+                                owner,
+                                *sell_router,
+                                amount_a,
+                            )
+                            .await;
+                            match sell.swap(token_a_address, token_b_address, amount_a).await {
+                                Ok(_) => println!("arbitrage sent, sold {} token A", amount_a),
+                                Err(e) => println!("sell leg fail: {}", e),
+                            }
+                        }
+                        Ok(_) => println!("buy leg received no token A"),
+                        Err(e) => println!("buy leg fail: {}", e),
+                    }
+                }
+            }
+        }
+
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            _ = tokio::time::sleep(Duration::from_secs(arb_config.interval)) => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_cheapest_buy_and_richest_sell() {
+        let e18 = U256::exp10(18);
+        // token A priced 1.0, 1.05 and 0.95 B
+        let reserves = vec![
+            (e18 * 1000, e18 * 1000),
+            (e18 * 1000, e18 * 1050),
+            (e18 * 1000, e18 * 950),
+        ];
+        let opportunity = find(&reserves).unwrap();
+        assert_eq!(opportunity.buy_venue, 2);
+        assert_eq!(opportunity.sell_venue, 1);
+        assert!(opportunity.profit() > U256::zero());
+
+        // a smaller trade on the same route makes less
+        let smaller = evaluate(&reserves, 2, 1, opportunity.amount_in / 2);
+        assert!(smaller.profit() < opportunity.profit());
+    }
+
+    #[test]
+    fn no_opportunity_within_fees() {
+        let e18 = U256::exp10(18);
+        let reserves = vec![(e18 * 1000, e18 * 1000), (e18 * 1000, e18 * 1004)];
+        assert_eq!(find(&reserves), None);
+    }
+}
@@ -0,0 +1,412 @@
+use crate::{
+    amm::get_amount_out,
+    history::BlockRecord,
+    tswap::{step_amount, SWAP_DEADLINE},
+    utils::{price, to_float},
+    BASIS_POINT,
+};
+use anyhow::{anyhow, Result};
+use ethers::types::U256;
+
+/// Schedule parameters under test, same meaning as in `TradeConfig`.
+#[derive(Debug, Clone, Copy)]
+pub struct Schedule {
+    pub tswap_sell: u64,
+    pub tswap_buy: u64,
+    pub tswap_step: u64,
+}
+
+/// Candidate values for each schedule parameter.
+#[derive(Debug, Clone)]
+pub struct Grid {
+    pub tswap_sells: Vec<u64>,
+    pub tswap_buys: Vec<u64>,
+    pub tswap_steps: Vec<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BacktestReport {
+    pub schedule: Schedule,
+    /// False when the history ran out before the buy stage finished.
+    pub completed: bool,
+    pub failed_steps: u64,
+    pub sold_a: f64,
+    pub received_b: f64,
+    pub spent_b: f64,
+    pub bought_a: f64,
+    /// Average sell price below the arrival mid price.
+    pub sell_slippage_bps: f64,
+    /// Average buy price above the mid price when the buy stage started.
+    pub buy_slippage_bps: f64,
+    /// Shortfall of the sell stage against selling everything at the arrival mid price.
+    pub shortfall_bps: f64,
+    /// Round-trip result in token A, unspent token B valued at the final mid price.
+    pub pnl_a: f64,
+    pub pnl_bps: f64,
+}
+
+/// Recorded reserves shifted by the token A we pushed into or pulled out of the pool.
+///
+/// Keeping the recorded `k` and only carrying our own displacement lets external swaps
+/// and liquidity changes come straight from the history while our impact persists.
+struct SimPool {
+    reserve_a: U256,
+    reserve_b: U256,
+    added_a: U256,
+    removed_a: U256,
+}
+
+impl SimPool {
+    fn update(&mut self, record: &BlockRecord) {
+        let k = record.reserve_a * record.reserve_b;
+        let reserve_a = (record.reserve_a + self.added_a).saturating_sub(self.removed_a);
+        self.reserve_a = reserve_a.max(U256::one());
+        self.reserve_b = k / self.reserve_a;
+    }
+
+    fn amount_out(&self, sell_a: bool, amount_in: U256) -> U256 {
+        if sell_a {
+            get_amount_out(amount_in, self.reserve_a, self.reserve_b)
+        } else {
+            get_amount_out(amount_in, self.reserve_b, self.reserve_a)
+        }
+    }
+
+    fn mid_price(&self, token_a_decimal: u32, token_b_decimal: u32) -> f64 {
+        price(
+            to_float(self.reserve_a, token_a_decimal),
+            to_float(self.reserve_b, token_b_decimal),
+        )
+    }
+}
+
+/// A step quoted at one block and mined in the next one.
+struct PendingStep {
+    sell_a: bool,
+    amount_in: U256,
+    amount_out_min: U256,
+}
+
+#[derive(PartialEq)]
+enum Stage {
+    Sell,
+    Buy,
+    Done,
+}
+
+/// Replay `records` and inject a sell-then-buy cycle of `amount` token A following `schedule`.
+pub fn run(
+    records: &[BlockRecord],
+    amount: U256,
+    schedule: Schedule,
+    slippage: u64,
+    token_a_decimal: u32,
+    token_b_decimal: u32,
+) -> Result<BacktestReport> {
+    let first = records.first().ok_or_else(|| anyhow!("history is empty"))?;
+    if schedule.tswap_sell == 0 || schedule.tswap_buy == 0 {
+        return Err(anyhow!("number of steps must be greater than 0"));
+    }
+
+    let mut pool = SimPool {
+        reserve_a: U256::zero(),
+        reserve_b: U256::zero(),
+        added_a: U256::zero(),
+        removed_a: U256::zero(),
+    };
+    pool.update(first);
+    let arrival_price = pool.mid_price(token_a_decimal, token_b_decimal);
+    let mut buy_arrival_price = arrival_price;
+
+    let (mut sold_a, mut received_b, mut spent_b, mut bought_a) =
+        (U256::zero(), U256::zero(), U256::zero(), U256::zero());
+    let mut failed_steps = 0u64;
+
+    let mut stage = Stage::Sell;
+    let mut stage_amount = amount;
+    let mut max_step = schedule.tswap_sell;
+    let mut step_count = 0u64;
+    let mut next_step_time = first.timestamp;
+    let mut pending: Vec<PendingStep> = vec![];
+
+    for record in records {
+        pool.update(record);
+
+        for step in pending.drain(..) {
+            let amount_out = pool.amount_out(step.sell_a, step.amount_in);
+            if amount_out < step.amount_out_min {
+                failed_steps += 1;
+                continue;
+            }
+            if step.sell_a {
+                pool.added_a += step.amount_in;
+                sold_a += step.amount_in;
+                received_b += amount_out;
+            } else {
+                pool.removed_a += amount_out;
+                spent_b += step.amount_in;
+                bought_a += amount_out;
+            }
+            // the next step in this block trades against the moved pool
+            pool.update(record);
+        }
+
+        if step_count == max_step {
+            match stage {
+                Stage::Sell => {
+                    stage = Stage::Buy;
+                    stage_amount = received_b;
+                    max_step = schedule.tswap_buy;
+                    step_count = 0;
+                    next_step_time = record.timestamp + SWAP_DEADLINE as u64 / 1000;
+                    buy_arrival_price = pool.mid_price(token_a_decimal, token_b_decimal);
+                }
+                Stage::Buy => stage = Stage::Done,
+                Stage::Done => {}
+            }
+        }
+        if stage == Stage::Done {
+            break;
+        }
+
+        while step_count < max_step && record.timestamp >= next_step_time {
+            step_count += 1;
+            next_step_time += schedule.tswap_step;
+
+            let sell_a = stage == Stage::Sell;
+            let amount_in = step_amount(stage_amount, max_step, step_count);
+            let amount_out_min = pool
+                .amount_out(sell_a, amount_in)
+                .checked_mul(U256::from(slippage))
+                .unwrap()
+                .checked_div(U256::from(BASIS_POINT))
+                .unwrap();
+            pending.push(PendingStep {
+                sell_a,
+                amount_in,
+                amount_out_min,
+            });
+        }
+    }
+
+    let final_price = pool.mid_price(token_a_decimal, token_b_decimal);
+    let amount = to_float(amount, token_a_decimal);
+    let sold_a = to_float(sold_a, token_a_decimal);
+    let received_b = to_float(received_b, token_b_decimal);
+    let spent_b = to_float(spent_b, token_b_decimal);
+    let bought_a = to_float(bought_a, token_a_decimal);
+
+    let sell_price = price(sold_a, received_b);
+    let buy_price = price(bought_a, spent_b);
+    let bps = |value: f64, reference: f64| {
+        if reference == 0f64 {
+            0f64
+        } else {
+            value / reference * BASIS_POINT as f64
+        }
+    };
+
+    let paper_value = amount * arrival_price;
+    let realized_value = received_b + (amount - sold_a) * final_price;
+    let unspent_b = received_b - spent_b;
+    let pnl_a = bought_a - sold_a
+        + if final_price == 0f64 {
+            0f64
+        } else {
+            unspent_b / final_price
+        };
+
+    Ok(BacktestReport {
+        schedule,
+        completed: stage == Stage::Done,
+        failed_steps,
+        sold_a,
+        received_b,
+        spent_b,
+        bought_a,
+        sell_slippage_bps: if sold_a == 0f64 {
+            0f64
+        } else {
+            bps(arrival_price - sell_price, arrival_price)
+        },
+        buy_slippage_bps: if bought_a == 0f64 {
+            0f64
+        } else {
+            bps(buy_price - buy_arrival_price, buy_arrival_price)
+        },
+        shortfall_bps: bps(paper_value - realized_value, paper_value),
+        pnl_a,
+        pnl_bps: bps(pnl_a, amount),
+    })
+}
+
+/// Run every combination of schedule parameters, best round-trip PnL first.
+pub fn grid_search(
+    records: &[BlockRecord],
+    amount: U256,
+    grid: &Grid,
+    slippage: u64,
+    token_a_decimal: u32,
+    token_b_decimal: u32,
+) -> Result<Vec<BacktestReport>> {
+    let mut reports = vec![];
+    for &tswap_sell in &grid.tswap_sells {
+        for &tswap_buy in &grid.tswap_buys {
+            for &tswap_step in &grid.tswap_steps {
+                let schedule = Schedule {
+                    tswap_sell,
+                    tswap_buy,
+                    tswap_step,
+                };
+                reports.push(run(
+                    records,
+                    amount,
+                    schedule,
+                    slippage,
+                    token_a_decimal,
+                    token_b_decimal,
+                )?);
+            }
+        }
+    }
+    reports.sort_by(|a, b| b.pnl_bps.total_cmp(&a.pnl_bps));
+    Ok(reports)
+}
+
+pub fn print_reports(reports: &[BacktestReport], token_a_symbol: &str) {
+    println!(
+        " sell   buy   step | failed   sell_bps    buy_bps  short_bps | {:>14}   pnl_bps | completed",
+        format!("pnl ({})", token_a_symbol)
+    );
+    for report in reports {
+        println!(
+            "{:>5} {:>5} {:>6} | {:>6} {:>10.2} {:>10.2} {:>10.2} | {:>14.6} {:>9.2} | {}",
+            report.schedule.tswap_sell,
+            report.schedule.tswap_buy,
+            report.schedule.tswap_step,
+            report.failed_steps,
+            report.sell_slippage_bps,
+            report.buy_slippage_bps,
+            report.shortfall_bps,
+            report.pnl_a,
+            report.pnl_bps,
+            report.completed
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(block_number: u64) -> BlockRecord {
+        BlockRecord {
+            reserve_a: U256::exp10(21),
+            reserve_b: U256::exp10(21),
+            ..pool_record(block_number, 1, 1)
+        }
+    }
+
+    /// Block every 12 seconds with the given reserves, in raw units.
+    fn pool_record(block_number: u64, reserve_a: u64, reserve_b: u64) -> BlockRecord {
+        BlockRecord {
+            block_number,
+            timestamp: block_number * 12,
+            reserve_a: U256::from(reserve_a),
+            reserve_b: U256::from(reserve_b),
+            price: reserve_b as f64 / reserve_a as f64,
+            ..Default::default()
+        }
+    }
+
+    fn one_step() -> Schedule {
+        Schedule {
+            tswap_sell: 1,
+            tswap_buy: 1,
+            tswap_step: 0,
+        }
+    }
+
+    fn assert_close(value: f64, expected: f64) {
+        assert!((value - expected).abs() < 0.01, "{} != {}", value, expected);
+    }
+
+    #[test]
+    fn round_trip_on_a_quiet_pool_loses_the_fees() {
+        // the buy stage starts 120 seconds (10 blocks) after the sell stage is mined
+        let records: Vec<BlockRecord> = (0..13)
+            .map(|block| pool_record(block, 1_000_000, 1_000_000))
+            .collect();
+        let report = run(&records, U256::from(1000u64), one_step(), 9500, 0, 0).unwrap();
+
+        assert!(report.completed);
+        assert_eq!(report.failed_steps, 0);
+        // 1000 * 997 * 1e6 / (1e9 + 997000)
+        assert_eq!(report.sold_a, 1000f64);
+        assert_eq!(report.received_b, 996f64);
+        // 996 B against the (1001000, 999000) pool the sale left
+        assert_eq!(report.spent_b, 996f64);
+        assert_eq!(report.bought_a, 994f64);
+        assert_close(report.sell_slippage_bps, 40f64);
+        assert_close(report.shortfall_bps, 40f64);
+        // 996 / 994 against the 999000 / 1001000 mid price
+        assert_close(report.buy_slippage_bps, 40.18f64);
+        assert_close(report.pnl_a, -6f64);
+        assert_close(report.pnl_bps, -60f64);
+    }
+
+    #[test]
+    fn recorded_external_swaps_move_the_pool_under_us() {
+        // others sell 100000 A (and add B back to the same reserve) before the buy stage
+        let records: Vec<BlockRecord> = (0..13)
+            .map(|block| match block {
+                0..=4 => pool_record(block, 1_000_000, 1_000_000),
+                _ => pool_record(block, 1_100_000, 1_000_000),
+            })
+            .collect();
+        let report = run(&records, U256::from(1000u64), one_step(), 9500, 0, 0).unwrap();
+
+        assert!(report.completed);
+        assert_eq!(report.received_b, 996f64);
+        // our 1000 A stay on top of the recorded 1100000
+        assert_eq!(report.bought_a, 1093f64);
+        assert_close(report.pnl_a, 93f64);
+    }
+
+    #[test]
+    fn step_under_its_minimum_fails_and_short_history_does_not_complete() {
+        // someone sells A between our quote and our block, with no slippage allowed
+        let mut records = vec![
+            pool_record(0, 1_000_000, 1_000_000),
+            pool_record(1, 1_010_000, 990_099),
+        ];
+        records.extend((2..5).map(|block| pool_record(block, 1_000_000, 1_000_000)));
+        let report = run(&records, U256::from(1000u64), one_step(), 10000, 0, 0).unwrap();
+
+        assert!(!report.completed);
+        assert_eq!(report.failed_steps, 1);
+        assert_eq!(report.sold_a, 0f64);
+        assert_eq!(report.bought_a, 0f64);
+        assert_eq!(report.pnl_a, 0f64);
+        // nothing sold, valued at the unchanged price
+        assert_close(report.shortfall_bps, 0f64);
+    }
+
+    #[test]
+    fn steps_in_one_block_see_each_others_impact() {
+        let records: Vec<BlockRecord> = (0..4).map(record).collect();
+        let sell = |tswap_sell| {
+            let schedule = Schedule {
+                tswap_sell,
+                tswap_buy: 1,
+                tswap_step: 0,
+            };
+            run(&records, U256::exp10(20), schedule, 0, 18, 18)
+                .unwrap()
+                .received_b
+        };
+        // ten steps mined together get about what one swap of the whole amount gets
+        let (whole, split) = (sell(1), sell(10));
+        assert!((split - whole).abs() < whole * 0.001);
+    }
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use ethers::{
+    contract::abigen,
+    providers::Middleware,
+    types::{Address, I256},
+};
+
+use crate::utils::to_float;
+
+abigen!(
+    AggregatorV3,
+    r#"[
+        function decimals() external view returns (uint8)
+        function latestRoundData() external view returns (uint80 roundId, int256 answer, uint256 startedAt, uint256 updatedAt, uint80 answeredInRound)
+    ]"#
+);
+
+/// Latest answer of a Chainlink-style aggregator, scaled by its decimals.
+pub async fn latest_answer<M: Middleware + 'static>(
+    client: Arc<M>,
+    aggregator_address: Address,
+) -> Result<f64> {
+    let aggregator = AggregatorV3::new(aggregator_address, client);
+    let decimals = aggregator.decimals().await?;
+    let (_, answer, _, _, _) = aggregator.latest_round_data().await?;
+    if answer <= I256::zero() {
+        return Err(anyhow!(
+            "aggregator {:?} answered {}",
+            aggregator_address,
+            answer
+        ));
+    }
+    Ok(to_float(answer.into_raw(), decimals as u32))
+}
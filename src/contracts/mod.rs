@@ -1,2 +1,5 @@
+pub mod aggregator;
 pub mod erc20;
+pub mod pair;
+pub mod router;
 pub mod weth;
@@ -0,0 +1,339 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use bindings_uniswapv2::{
+    uniswap_v2_factory::UniswapV2Factory, uniswap_v2_pair::UniswapV2Pair,
+    uniswap_v2_router_02::UniswapV2Router02,
+};
+use ethers::{
+    providers::Middleware,
+    types::{Address, BlockId, BlockNumber, H256, U256},
+    utils::{get_create2_address_from_hash, keccak256},
+};
+
+use crate::config::PoolConfig;
+
+/// How the configured token A / token B map onto the pair's token0 / token1.
+#[derive(Debug, Clone, Copy)]
+pub struct PairLayout {
+    pub pair_address: Address,
+    pub token_a_is_0: bool,
+    pub token_a_decimal: u32,
+    pub token_b_decimal: u32,
+}
+
+impl PairLayout {
+    /// Reorder a (token0, token1) value pair into (token A, token B).
+    pub fn split<T>(&self, value_0: T, value_1: T) -> (T, T) {
+        if self.token_a_is_0 {
+            (value_0, value_1)
+        } else {
+            (value_1, value_0)
+        }
+    }
+}
+
+/// Init code hash of the pair contract of known V2 deployments, keyed by factory address.
+pub const KNOWN_FACTORIES: &[(&str, &str)] = &[
+    // Uniswap V2, Ethereum
+    (
+        "0x5C69bEe701ef814a2B6a3EDD4B1652CB9cc5aA6f",
+        "0x96e8ac4277198ff8b6f785478aa9a39f403cb768dd02cbee326c3e7da348845f",
+    ),
+];
+
+/// Tokens in pair order, mirrors `UniswapV2Library.sortTokens`.
+pub fn sort_tokens(token_a: Address, token_b: Address) -> (Address, Address) {
+    if token_a < token_b {
+        (token_a, token_b)
+    } else {
+        (token_b, token_a)
+    }
+}
+
+/// CREATE2 address of the token A / token B pair, mirrors `UniswapV2Library.pairFor`.
+pub fn pair_for(
+    factory_address: Address,
+    token_a: Address,
+    token_b: Address,
+    init_code_hash: H256,
+) -> Address {
+    let (token_0, token_1) = sort_tokens(token_a, token_b);
+    let salt = keccak256([token_0.as_bytes(), token_1.as_bytes()].concat());
+    get_create2_address_from_hash(factory_address, salt, init_code_hash)
+}
+
+/// Init code hash of a factory listed in `KNOWN_FACTORIES`.
+pub fn known_init_code_hash(factory_address: Address) -> Option<H256> {
+    KNOWN_FACTORIES
+        .iter()
+        .find(|(factory, _)| Address::from_str(factory).ok() == Some(factory_address))
+        .and_then(|(_, hash)| H256::from_str(hash).ok())
+}
+
+/// Factory behind the configured router, `pool.factory_address` when set.
+pub async fn factory<M: Middleware + 'static>(
+    client: Arc<M>,
+    pool: &PoolConfig,
+) -> Result<Address> {
+    match &pool.factory_address {
+        Some(factory_address) => Ok(Address::from_str(factory_address)?),
+        None => {
+            let router = UniswapV2Router02::new(Address::from_str(&pool.router_address)?, client);
+            Ok(router.factory().await?)
+        }
+    }
+}
+
+/// CREATE2 address of the pool's pair when the factory's init code hash is known
+/// (`pool.init_code_hash` or `KNOWN_FACTORIES`), computed offline without checking that a
+/// pair was deployed there.
+pub fn derive(factory_address: Address, pool: &PoolConfig) -> Result<Option<Address>> {
+    let init_code_hash = match &pool.init_code_hash {
+        Some(hash) => Some(H256::from_str(hash)?),
+        None => known_init_code_hash(factory_address),
+    };
+    let token_a_address = Address::from_str(&pool.token_a_address)?;
+    let token_b_address = Address::from_str(&pool.token_b_address)?;
+    Ok(
+        init_code_hash
+            .map(|hash| pair_for(factory_address, token_a_address, token_b_address, hash)),
+    )
+}
+
+/// Address of the token A / token B pair contract.
+///
+/// The `derive`d address is used once its `token0()` answers the pair's first token, which
+/// a wrong init code hash or a pair never deployed cannot do; `factory.getPair` is asked
+/// otherwise. A configured `pair_address` must match it.
+pub async fn resolve<M: Middleware + 'static>(
+    client: Arc<M>,
+    pool: &PoolConfig,
+) -> Result<Address> {
+    let token_a_address = Address::from_str(&pool.token_a_address)?;
+    let token_b_address = Address::from_str(&pool.token_b_address)?;
+    let factory_address = factory(client.clone(), pool).await?;
+
+    let token_0 = sort_tokens(token_a_address, token_b_address).0;
+    let derived = match derive(factory_address, pool)? {
+        Some(derived) => {
+            let pair = UniswapV2Pair::new(derived, client.clone());
+            match pair.token_0().await {
+                Ok(token) if token == token_0 => Some(derived),
+                _ => {
+                    println!(
+                        "no {} / {} pair at CREATE2 address {:?}, asking the factory",
+                        pool.token_a_symbol, pool.token_b_symbol, derived
+                    );
+                    None
+                }
+            }
+        }
+        None => None,
+    };
+    let pair_address = match derived {
+        Some(derived) => derived,
+        None => {
+            let factory = UniswapV2Factory::new(factory_address, client);
+            factory.get_pair(token_a_address, token_b_address).await?
+        }
+    };
+    if pair_address.is_zero() {
+        return Err(anyhow!(
+            "factory {:?} has no {} / {} pair",
+            factory_address,
+            pool.token_a_symbol,
+            pool.token_b_symbol
+        ));
+    }
+
+    if let Some(configured) = &pool.pair_address {
+        let configured = Address::from_str(configured)?;
+        if configured != pair_address {
+            return Err(anyhow!(
+                "pair_address {:?} is not the {} / {} pair {:?} of factory {:?}",
+                configured,
+                pool.token_a_symbol,
+                pool.token_b_symbol,
+                pair_address,
+                factory_address
+            ));
+        }
+    }
+    Ok(pair_address)
+}
+
+/// Layout of the pool's pair, resolved from the router's factory.
+pub async fn layout<M: Middleware + 'static>(
+    client: Arc<M>,
+    pool: &PoolConfig,
+) -> Result<PairLayout> {
+    let pair_address = resolve(client, pool).await?;
+    let token_a_address = Address::from_str(&pool.token_a_address)?;
+    let token_b_address = Address::from_str(&pool.token_b_address)?;
+
+    Ok(PairLayout {
+        pair_address,
+        token_a_is_0: sort_tokens(token_a_address, token_b_address).0 == token_a_address,
+        token_a_decimal: pool.token_a_decimal,
+        token_b_decimal: pool.token_b_decimal,
+    })
+}
+
+/// Layout of an arbitrary pair holding the pool's tokens, checked against its token0 / token1.
+pub async fn layout_at<M: Middleware + 'static>(
+    client: Arc<M>,
+    pair_address: Address,
+    pool: &PoolConfig,
+) -> Result<PairLayout> {
+    let token_a_address = Address::from_str(&pool.token_a_address)?;
+    let token_b_address = Address::from_str(&pool.token_b_address)?;
+
+    let pair = UniswapV2Pair::new(pair_address, client);
+    let token_0 = pair.token_0().await?;
+    let token_1 = pair.token_1().await?;
+
+    let token_a_is_0 = if token_0 == token_a_address && token_1 == token_b_address {
+        true
+    } else if token_0 == token_b_address && token_1 == token_a_address {
+        false
+    } else {
+        return Err(anyhow!(
+            "pair {:?} does not hold {} / {}",
+            pair_address,
+            pool.token_a_symbol,
+            pool.token_b_symbol
+        ));
+    };
+
+    Ok(PairLayout {
+        pair_address,
+        token_a_is_0,
+        token_a_decimal: pool.token_a_decimal,
+        token_b_decimal: pool.token_b_decimal,
+    })
+}
+
+/// Cumulative prices of the pair and the reserves they were last accumulated with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CumulativePrices {
+    /// `price0CumulativeLast`, token1 per token0 as UQ112x112 times seconds.
+    pub price_0: U256,
+    /// `price1CumulativeLast`, token0 per token1 as UQ112x112 times seconds.
+    pub price_1: U256,
+    pub reserve_0: U256,
+    pub reserve_1: U256,
+    /// `blockTimestampLast`, block timestamp mod 2**32 of the last update.
+    pub timestamp: u32,
+}
+
+/// Cumulative prices as of the end of `block`, latest when None.
+pub async fn cumulative_prices<M: Middleware + 'static>(
+    client: Arc<M>,
+    pair_address: Address,
+    block: Option<u64>,
+) -> Result<CumulativePrices> {
+    let pair = UniswapV2Pair::new(pair_address, client);
+    let block = BlockId::from(block.map(BlockNumber::from).unwrap_or(BlockNumber::Latest));
+    let price_0 = pair.price_0_cumulative_last().block(block).await?;
+    let price_1 = pair.price_1_cumulative_last().block(block).await?;
+    let (reserve_0, reserve_1, timestamp) = pair.get_reserves().block(block).await?;
+    Ok(CumulativePrices {
+        price_0,
+        price_1,
+        reserve_0: U256::from(reserve_0),
+        reserve_1: U256::from(reserve_1),
+        timestamp,
+    })
+}
+
+/// Reserves ordered as (token0, token1).
+pub async fn get_reserves<M: Middleware + 'static>(
+    client: Arc<M>,
+    pair_address: Address,
+) -> (U256, U256) {
+    let pair = UniswapV2Pair::new(pair_address, client);
+    let (reserve_0, reserve_1, _) = pair.get_reserves().await.unwrap_or((0, 0, 0));
+    (U256::from(reserve_0), U256::from(reserve_1))
+}
+
+pub async fn balance_of<M: Middleware + 'static>(
+    client: Arc<M>,
+    pair_address: Address,
+    owner: Address,
+) -> U256 {
+    let pair = UniswapV2Pair::new(pair_address, client);
+    pair.balance_of(owner).await.unwrap_or(U256::zero())
+}
+
+pub async fn total_supply<M: Middleware + 'static>(client: Arc<M>, pair_address: Address) -> U256 {
+    let pair = UniswapV2Pair::new(pair_address, client);
+    pair.total_supply().await.unwrap_or(U256::zero())
+}
+
+/// `reserve0 * reserve1` as of the last liquidity event, 0 while the protocol fee is off.
+pub async fn k_last<M: Middleware + 'static>(client: Arc<M>, pair_address: Address) -> U256 {
+    let pair = UniswapV2Pair::new(pair_address, client);
+    pair.k_last().await.unwrap_or(U256::zero())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(value: &str) -> Address {
+        Address::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn computes_known_pair_addresses() {
+        let usdc = address("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        let weth = address("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+
+        let (factory, hash) = KNOWN_FACTORIES[0];
+        let factory = address(factory);
+        assert_eq!(known_init_code_hash(factory), H256::from_str(hash).ok());
+        let pair = address("0xB4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc");
+        assert_eq!(
+            pair_for(factory, weth, usdc, H256::from_str(hash).unwrap()),
+            pair
+        );
+        // token order does not matter
+        assert_eq!(
+            pair_for(factory, usdc, weth, H256::from_str(hash).unwrap()),
+            pair
+        );
+
+        assert_eq!(known_init_code_hash(Address::zero()), None);
+    }
+
+    #[test]
+    fn derive_prefers_configured_hash() {
+        let (factory, hash) = KNOWN_FACTORIES[0];
+        let factory = address(factory);
+        let mut pool = PoolConfig {
+            router_address: String::new(),
+            pair_address: None,
+            factory_address: None,
+            init_code_hash: None,
+            weth_address: String::new(),
+            token_a_address: "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".to_string(),
+            token_a_symbol: "WETH".to_string(),
+            token_a_decimal: 18,
+            token_b_address: "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".to_string(),
+            token_b_symbol: "USDC".to_string(),
+            token_b_decimal: 6,
+        };
+        assert_eq!(
+            derive(factory, &pool).unwrap(),
+            Some(address("0xB4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc"))
+        );
+        assert_eq!(derive(Address::zero(), &pool).unwrap(), None);
+
+        pool.init_code_hash = Some(hash.to_string());
+        assert!(derive(Address::zero(), &pool).unwrap().is_some());
+        pool.init_code_hash = Some("not a hash".to_string());
+        assert!(derive(factory, &pool).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use bindings_uniswapv2::uniswap_v2_router_02::UniswapV2Router02;
+use chrono::Utc;
+use ethers::{
+    providers::Middleware,
+    types::{Address, TransactionReceipt, U256},
+};
+
+use crate::{
+    config::Config,
+    tswap::SWAP_DEADLINE,
+    utils::{gas_limit, gas_price, succeeded},
+};
+
+const DEFAULT_ADD_LIQUIDITY_CALL_GAS: u64 = 250000u64;
+const DEFAULT_REMOVE_LIQUIDITY_CALL_GAS: u64 = 200000u64;
+
+/// Minimum amounts accepted by `add_liquidity` / `remove_liquidity`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiquidityAmounts {
+    pub amount_a: U256,
+    pub amount_b: U256,
+}
+
+fn deadline() -> U256 {
+    U256::from(Utc::now().timestamp() + SWAP_DEADLINE / 1000)
+}
+
+/// `router.quote`: amount of token B matching `amount_a` at the current reserves.
+pub async fn quote<M: Middleware + 'static>(
+    client: Arc<M>,
+    router_address: Address,
+    amount_a: U256,
+    reserve_a: U256,
+    reserve_b: U256,
+) -> Result<U256> {
+    let router = UniswapV2Router02::new(router_address, client);
+    Ok(router.quote(amount_a, reserve_a, reserve_b).await?)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn add_liquidity<M: Middleware + 'static>(
+    client: Arc<M>,
+    router_address: Address,
+    token_a_address: Address,
+    token_b_address: Address,
+    desired: LiquidityAmounts,
+    min: LiquidityAmounts,
+    owner: Address,
+    config: &Config,
+) -> Result<TransactionReceipt> {
+    let router = UniswapV2Router02::new(router_address, client.clone());
+    let add_call = router.add_liquidity(
+        token_a_address,
+        token_b_address,
+        desired.amount_a,
+        desired.amount_b,
+        min.amount_a,
+        min.amount_b,
+        owner,
+        deadline(),
+    );
+
+    let gas = add_call
+        .estimate_gas()
+        .await
+        .unwrap_or(U256::from(DEFAULT_ADD_LIQUIDITY_CALL_GAS));
+
+    let receipt = add_call
+        .gas(gas_limit(gas))
+        .gas_price(gas_price(client.as_ref(), config).await)
+        .send()
+        .await?
+        .await?;
+
+    succeeded(receipt, "add liquidity")
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn remove_liquidity<M: Middleware + 'static>(
+    client: Arc<M>,
+    router_address: Address,
+    token_a_address: Address,
+    token_b_address: Address,
+    liquidity: U256,
+    min: LiquidityAmounts,
+    owner: Address,
+    config: &Config,
+) -> Result<TransactionReceipt> {
+    let router = UniswapV2Router02::new(router_address, client.clone());
+    let remove_call = router.remove_liquidity(
+        token_a_address,
+        token_b_address,
+        liquidity,
+        min.amount_a,
+        min.amount_b,
+        owner,
+        deadline(),
+    );
+
+    let gas = remove_call
+        .estimate_gas()
+        .await
+        .unwrap_or(U256::from(DEFAULT_REMOVE_LIQUIDITY_CALL_GAS));
+
+    let receipt = remove_call
+        .gas(gas_limit(gas))
+        .gas_price(gas_price(client.as_ref(), config).await)
+        .send()
+        .await?
+        .await?;
+
+    succeeded(receipt, "remove liquidity")
+}
@@ -0,0 +1,524 @@
+use crate::{
+    config::{Config, CycleConfig},
+    dex::DexClient,
+    erc20,
+    tswap::{check_allowance, RoundTripMark, StageReport, Tswap, SWAP_DEADLINE},
+    utils::{price, to_float},
+    vwap,
+};
+use anyhow::{anyhow, Result};
+use ethers::{
+    providers::Middleware,
+    types::{Address, U256},
+    utils::format_units,
+};
+use rand::Rng;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Token sold or bought back in a cycle.
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub address: Address,
+    pub symbol: String,
+    pub decimal: u32,
+}
+
+/// Amounts moved by one sell-then-buy cycle, in token units.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CycleResult {
+    pub sold_a: f64,
+    pub received_b: f64,
+    pub spent_b: f64,
+    pub bought_a: f64,
+    /// ETH spent on gas over the cycle.
+    pub gas_spent: f64,
+    pub sell: StageReport,
+    /// Default when the buying stage was skipped.
+    pub buy: StageReport,
+}
+
+impl CycleResult {
+    /// Token A gained over the cycle, unspent token B valued at the buying stage's price.
+    ///
+    /// `sold_a` is what left the wallet, token A the selling stage left unsold never counts.
+    pub fn pnl(&self) -> f64 {
+        let rate = if self.spent_b > 0f64 {
+            price(self.spent_b, self.bought_a)
+        } else {
+            price(self.received_b, self.sold_a)
+        };
+        self.bought_a - self.sold_a + (self.received_b - self.spent_b) * rate
+    }
+}
+
+/// Running totals over all cycles of a run.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CycleStats {
+    pub cycles: u64,
+    /// Cumulative PnL in token A.
+    pub pnl: f64,
+    pub gas_spent: f64,
+    /// Stage reports summed over the cycles.
+    pub sell: StageReport,
+    pub buy: StageReport,
+}
+
+impl CycleStats {
+    pub fn record(&mut self, result: &CycleResult) {
+        self.cycles += 1;
+        self.pnl += result.pnl();
+        self.gas_spent += result.gas_spent;
+        self.sell.add(&result.sell);
+        self.buy.add(&result.buy);
+    }
+
+    /// Why the run should end after the cycles recorded so far, if it should.
+    pub fn stop_reason(&self, config: &CycleConfig) -> Option<String> {
+        if config.max_cycles > 0 && self.cycles >= config.max_cycles {
+            return Some(format!("{} cycles done", self.cycles));
+        }
+        if let Some(max_loss) = config.max_loss {
+            if -self.pnl >= max_loss {
+                return Some(format!("cumulative loss {} reached max_loss", -self.pnl));
+            }
+        }
+        if let Some(max_gas) = config.max_gas {
+            if self.gas_spent >= max_gas {
+                return Some(format!("gas spent {} reached max_gas", self.gas_spent));
+            }
+        }
+        None
+    }
+}
+
+/// Seconds to wait before the next cycle, uniform in `[min_pause, max_pause]`.
+pub fn pause<R: Rng>(config: &CycleConfig, rng: &mut R) -> u64 {
+    if config.max_pause <= config.min_pause {
+        config.min_pause
+    } else {
+        rng.gen_range(config.min_pause..=config.max_pause)
+    }
+}
+
+fn signed(before: U256, after: U256, decimal: u32) -> String {
+    if after >= before {
+        format!("+{}", format_units(after - before, decimal).unwrap())
+    } else {
+        format!("-{}", format_units(before - after, decimal).unwrap())
+    }
+}
+
+/// Run one stage with `vwap` when configured, in `max_step` steps otherwise.
+#[allow(clippy::too_many_arguments)]
+async fn stage<M: Middleware + 'static, D: DexClient>(
+    client: Arc<M>,
+    tswap: &Tswap<D>,
+    config: &Config,
+    owner: Address,
+    token_in: Address,
+    token_out: Address,
+    amount: U256,
+    max_step: u64,
+) -> Result<StageReport> {
+    if config.trade.vwap.is_some() {
+        vwap::vwap(client, tswap, config, owner, token_in, token_out, amount).await
+    } else {
+        Ok(tswap.tswap(token_in, token_out, amount, max_step).await)
+    }
+}
+
+/// Sell `amount` token A in `tswap_sell` steps, then buy it back with everything received
+/// in `tswap_buy` steps, with why the loss kill switch stopped it if it did.
+///
+/// The kill switch only holds for the round trip, it is lifted on return whether the round
+/// trip failed or not.
+#[allow(clippy::too_many_arguments)]
+pub async fn round_trip<M: Middleware + 'static, D: DexClient>(
+    client: Arc<M>,
+    tswap: &Tswap<D>,
+    config: &Config,
+    owner: Address,
+    router_address: Address,
+    token_a: &Token,
+    token_b: &Token,
+    amount: U256,
+) -> Result<(CycleResult, Option<String>)> {
+    let result = trade_round_trip(
+        client,
+        tswap,
+        config,
+        owner,
+        router_address,
+        token_a,
+        token_b,
+        amount,
+    )
+    .await;
+    let halted = tswap.end_round_trip();
+    result.map(|result| (result, halted))
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn trade_round_trip<M: Middleware + 'static, D: DexClient>(
+    client: Arc<M>,
+    tswap: &Tswap<D>,
+    config: &Config,
+    owner: Address,
+    router_address: Address,
+    token_a: &Token,
+    token_b: &Token,
+    amount: U256,
+) -> Result<CycleResult> {
+    let eth_balance = client
+        .get_balance(owner, None)
+        .await
+        .unwrap_or(U256::zero());
+
+    // approve router contract to use token for trading
+    check_allowance(
+        client.clone(),
+        tswap,
+        token_a.address,
+        owner,
+        router_address,
+        amount,
+    )
+    .await;
+
+    let before_selling_a_balance = erc20::balance_of(client.clone(), token_a.address, owner).await;
+    let before_selling_b_balance = erc20::balance_of(client.clone(), token_b.address, owner).await;
+    tswap.start_round_trip(RoundTripMark {
+        token_a: token_a.address,
+        token_b: token_b.address,
+        start_a: before_selling_a_balance,
+        start_b: before_selling_b_balance,
+        amount,
+    });
+
+    println!(
+        "------------ selling stage ({} steps) ------------",
+        config.trade.tswap_sell
+    );
+    println!(
+        "sell {} ({}) to buy {}",
+        format_units(amount, token_a.decimal).unwrap(),
+        token_a.symbol,
+        token_b.symbol
+    );
+
+    let sell = stage(
+        client.clone(),
+        tswap,
+        config,
+        owner,
+        token_a.address,
+        token_b.address,
+        amount,
+        config.trade.tswap_sell,
+    )
+    .await?;
+    if !sell.unsold.is_zero() {
+        println!(
+            "{} ({}) left unsold",
+            format_units(sell.unsold, token_a.decimal).unwrap(),
+            token_a.symbol
+        );
+    }
+
+    // wait for last swap transaction finalized
+    tokio::time::sleep(Duration::from_millis(SWAP_DEADLINE as u64)).await;
+
+    let after_selling_a_balance = erc20::balance_of(client.clone(), token_a.address, owner).await;
+    let after_selling_b_balance = erc20::balance_of(client.clone(), token_b.address, owner).await;
+
+    let sold_a = before_selling_a_balance.saturating_sub(after_selling_a_balance);
+    println!(
+        "\nsold {} ({}) and received {} ({})\n",
+        format_units(sold_a, token_a.decimal).unwrap(),
+        token_a.symbol,
+        format_units(
+            after_selling_b_balance.saturating_sub(before_selling_b_balance),
+            token_b.decimal
+        )
+        .unwrap(),
+        token_b.symbol
+    );
+
+    if after_selling_b_balance <= before_selling_b_balance {
+        return Err(anyhow!(
+            "number of token after purchase does not increase, something is wrong"
+        ));
+    }
+
+    let token_b_gwei = after_selling_b_balance - before_selling_b_balance;
+
+    let buy = match tswap.halted() {
+        Some(reason) => {
+            println!("buying stage skipped, trading halted: {}", reason);
+            StageReport::default()
+        }
+        None => {
+            // approve router contract to use token for trading
+            check_allowance(
+                client.clone(),
+                tswap,
+                token_b.address,
+                owner,
+                router_address,
+                token_b_gwei,
+            )
+            .await;
+
+            println!(
+                "------------ buying stage ({} steps) ------------",
+                config.trade.tswap_buy
+            );
+            println!(
+                "sell {} ({}) to buy back {}",
+                format_units(token_b_gwei, token_b.decimal).unwrap(),
+                token_b.symbol,
+                token_a.symbol
+            );
+
+            let buy = stage(
+                client.clone(),
+                tswap,
+                config,
+                owner,
+                token_b.address,
+                token_a.address,
+                token_b_gwei,
+                config.trade.tswap_buy,
+            )
+            .await?;
+            // wait for last swap transaction finalized
+            tokio::time::sleep(Duration::from_millis(SWAP_DEADLINE as u64)).await;
+            buy
+        }
+    };
+
+    let after_buying_a_balance = erc20::balance_of(client.clone(), token_a.address, owner).await;
+    let after_buying_b_balance = erc20::balance_of(client.clone(), token_b.address, owner).await;
+
+    if tswap.halted().is_some() {
+        println!(
+            "{} ({}) left unspent",
+            format_units(
+                after_buying_b_balance.saturating_sub(before_selling_b_balance),
+                token_b.decimal
+            )
+            .unwrap(),
+            token_b.symbol
+        );
+    }
+
+    let spent_b = after_selling_b_balance.saturating_sub(after_buying_b_balance);
+    let bought_a = after_buying_a_balance.saturating_sub(after_selling_a_balance);
+    println!(
+        "\nsold {} ({}) and received {} ({})\n",
+        format_units(spent_b, token_b.decimal).unwrap(),
+        token_b.symbol,
+        format_units(bought_a, token_a.decimal).unwrap(),
+        token_a.symbol
+    );
+
+    println!("------------ result ------------");
+    println!(
+        "{}: {}",
+        token_a.symbol,
+        signed(
+            before_selling_a_balance,
+            after_buying_a_balance,
+            token_a.decimal
+        )
+    );
+    println!(
+        "{}: {}",
+        token_b.symbol,
+        signed(
+            before_selling_b_balance,
+            after_buying_b_balance,
+            token_b.decimal
+        )
+    );
+
+    let eth_spent =
+        eth_balance.saturating_sub(client.get_balance(owner, None).await.unwrap_or(eth_balance));
+
+    Ok(CycleResult {
+        sold_a: to_float(sold_a, token_a.decimal),
+        received_b: to_float(token_b_gwei, token_b.decimal),
+        spent_b: to_float(spent_b, token_b.decimal),
+        bought_a: to_float(bought_a, token_a.decimal),
+        gas_spent: to_float(eth_spent, 18),
+        sell,
+        buy,
+    })
+}
+
+/// Repeat `round_trip` until `max_cycles`, a loss or gas limit, or Ctrl+C during a pause.
+///
+/// Without a `cycle` section in config this runs a single cycle.
+#[allow(clippy::too_many_arguments)]
+pub async fn run<M: Middleware + 'static, D: DexClient>(
+    client: Arc<M>,
+    tswap: &Tswap<D>,
+    config: &Config,
+    owner: Address,
+    token_a: &Token,
+    token_b: &Token,
+    amount: U256,
+) -> Result<CycleStats> {
+    let cycle_config = config.cycle.clone().unwrap_or(CycleConfig {
+        max_cycles: 1,
+        ..Default::default()
+    });
+    let router_address = Address::from_str(&config.pool.router_address)?;
+    tswap.start_run();
+
+    let mut stats = CycleStats::default();
+    loop {
+        println!("============ cycle {} ============", stats.cycles + 1);
+        let (result, halted) = round_trip(
+            client.clone(),
+            tswap,
+            config,
+            owner,
+            router_address,
+            token_a,
+            token_b,
+            amount,
+        )
+        .await?;
+        stats.record(&result);
+
+        println!(
+            "cycle pnl {} ({}), gas {} ETH",
+            result.pnl(),
+            token_a.symbol,
+            result.gas_spent
+        );
+        println!(
+            "total after {} cycles: pnl {} ({}), gas {} ETH, {} ({}) unsold, {} ({}) unspent by stopped stages",
+            stats.cycles,
+            stats.pnl,
+            token_a.symbol,
+            stats.gas_spent,
+            format_units(stats.sell.unsold, token_a.decimal).unwrap(),
+            token_a.symbol,
+            format_units(stats.buy.unsold, token_b.decimal).unwrap(),
+            token_b.symbol
+        );
+
+        if let Some(reason) = halted.or_else(|| stats.stop_reason(&cycle_config)) {
+            println!("stop: {}", reason);
+            break;
+        }
+
+        let seconds = pause(&cycle_config, &mut rand::thread_rng());
+        println!("next cycle in {} seconds, press Ctrl+C to stop", seconds);
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            _ = tokio::time::sleep(Duration::from_secs(seconds)) => {}
+        }
+    }
+
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn result(sold_a: f64, received_b: f64, spent_b: f64, bought_a: f64) -> CycleResult {
+        CycleResult {
+            sold_a,
+            received_b,
+            spent_b,
+            bought_a,
+            gas_spent: 0.01f64,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn pnl_values_unspent_token_b_at_buying_price() {
+        assert_eq!(result(10f64, 20f64, 20f64, 9.5f64).pnl(), -0.5f64);
+        // 4 B left over, bought at 0.5 A per B
+        assert_eq!(result(10f64, 20f64, 16f64, 8f64).pnl(), 0f64);
+    }
+
+    #[test]
+    fn stats_sum_what_stopped_stages_left() {
+        let mut stats = CycleStats::default();
+        let halted = CycleResult {
+            sell: StageReport {
+                filled_steps: 1,
+                halted: true,
+                unsold: U256::from(5u64),
+                ..Default::default()
+            },
+            ..result(10f64, 20f64, 0f64, 0f64)
+        };
+        stats.record(&halted);
+        stats.record(&halted);
+
+        assert_eq!(stats.sell.filled_steps, 2);
+        assert!(stats.sell.halted);
+        assert_eq!(stats.sell.unsold, U256::from(10u64));
+        assert_eq!(stats.buy, StageReport::default());
+    }
+
+    #[test]
+    fn stops_on_first_limit_hit() {
+        let config = CycleConfig {
+            max_cycles: 0,
+            max_loss: Some(1f64),
+            max_gas: Some(0.025f64),
+            ..Default::default()
+        };
+        let mut stats = CycleStats::default();
+        stats.record(&result(10f64, 20f64, 20f64, 9.5f64));
+        assert_eq!(stats.stop_reason(&config), None);
+
+        stats.record(&result(10f64, 20f64, 20f64, 9.5f64));
+        assert!(stats.stop_reason(&config).unwrap().contains("max_loss"));
+
+        let config = CycleConfig {
+            max_loss: None,
+            ..config
+        };
+        stats.record(&result(10f64, 20f64, 20f64, 10f64));
+        assert!(stats.stop_reason(&config).unwrap().contains("max_gas"));
+
+        let config = CycleConfig {
+            max_cycles: 3,
+            ..Default::default()
+        };
+        assert!(stats.stop_reason(&config).unwrap().contains("3 cycles"));
+    }
+
+    #[test]
+    fn pause_stays_within_window() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let config = CycleConfig {
+            min_pause: 10,
+            max_pause: 20,
+            ..Default::default()
+        };
+        for _ in 0..100 {
+            let seconds = pause(&config, &mut rng);
+            assert!((10..=20).contains(&seconds));
+        }
+
+        let fixed = CycleConfig {
+            min_pause: 15,
+            max_pause: 15,
+            ..Default::default()
+        };
+        assert_eq!(pause(&fixed, &mut rng), 15);
+    }
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use ethers::types::{Address, U256};
+
+pub mod router;
+pub mod sim;
+
+pub use router::RouterClient;
+pub use sim::SimDex;
+
+/// Exact-in swap along `[token_in, token_out]`, priced and bounded by `Tswap`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapOrder {
+    pub token_in: Address,
+    pub token_out: Address,
+    pub amount_in: U256,
+    pub amount_out_min: U256,
+    /// Unix timestamp passed as the router deadline.
+    pub deadline: U256,
+    pub gas_price: U256,
+}
+
+/// Everything `Tswap` needs from the chain: quoting, approving and swapping.
+#[async_trait]
+pub trait DexClient: Send + Sync {
+    /// Expected output of swapping `amount_in` of `token_in` for `token_out`.
+    async fn quote(&self, token_in: Address, token_out: Address, amount_in: U256) -> Result<U256>;
+
+    /// Pool reserves of `token_in` and `token_out`, in that order.
+    async fn reserves(&self, token_in: Address, token_out: Address) -> Result<(U256, U256)>;
+
+    /// Balance of `token` held by the trading wallet.
+    async fn balance(&self, token: Address) -> Result<U256>;
+
+    async fn gas_price(&self) -> Result<U256>;
+
+    async fn approve(
+        &self,
+        token: Address,
+        spender: Address,
+        value: U256,
+        gas_price: U256,
+    ) -> Result<()>;
+
+    async fn swap(&self, order: SwapOrder) -> Result<()>;
+}
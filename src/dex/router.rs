@@ -0,0 +1,177 @@
+use super::{DexClient, SwapOrder};
+use crate::{pair, relay::Relay, supervisor::TxSupervisor, utils::gas_limit};
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use bindings_uniswapv2::{
+    ierc20::IERC20, uniswap_v2_factory::UniswapV2Factory, uniswap_v2_pair::UniswapV2Pair,
+    uniswap_v2_router_02::UniswapV2Router02,
+};
+use ethers::{
+    providers::Middleware,
+    types::{Address, U256},
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+pub const DEFAULT_APPROVE_CALL_GAS: u64 = 40000u64;
+pub const DEFAULT_SWAP_CALL_GAS: u64 = 200000u64;
+
+/// `DexClient` backed by a deployed `UniswapV2Router02`.
+#[derive(Debug)]
+pub struct RouterClient<M> {
+    /// Ethers client.
+    client: Arc<M>,
+    router_address: Address,
+    /// Receiver of the swapped tokens.
+    owner: Address,
+    /// Private submission of swaps, public mempool when None.
+    relay: Option<Arc<Relay>>,
+    /// Tracks sent swaps so stuck ones can be replaced, untracked when None.
+    supervisor: Option<Arc<TxSupervisor<M>>>,
+    /// Pair addresses by sorted tokens, looked up through the factory once.
+    pairs: Mutex<HashMap<(Address, Address), Address>>,
+}
+
+impl<M: Middleware + 'static> RouterClient<M> {
+    pub fn new(client: Arc<M>, router_address: Address, owner: Address) -> Self {
+        Self {
+            client,
+            router_address,
+            owner,
+            relay: None,
+            supervisor: None,
+            pairs: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_relay(mut self, relay: Arc<Relay>) -> Self {
+        self.relay = Some(relay);
+        self
+    }
+
+    pub fn with_supervisor(mut self, supervisor: Arc<TxSupervisor<M>>) -> Self {
+        self.supervisor = Some(supervisor);
+        self
+    }
+}
+
+#[async_trait]
+impl<M: Middleware + 'static> DexClient for RouterClient<M> {
+    async fn quote(&self, token_in: Address, token_out: Address, amount_in: U256) -> Result<U256> {
+        let route02 = UniswapV2Router02::new(self.router_address, self.client.clone());
+        let amounts = route02
+            .get_amounts_out(amount_in, vec![token_in, token_out])
+            .await?;
+        amounts
+            .last()
+            .copied()
+            .ok_or_else(|| anyhow!("empty get_amounts_out result"))
+    }
+
+    async fn reserves(&self, token_in: Address, token_out: Address) -> Result<(U256, U256)> {
+        let tokens = pair::sort_tokens(token_in, token_out);
+        let cached = self.pairs.lock().unwrap().get(&tokens).copied();
+        let pair_address = match cached {
+            Some(pair_address) => pair_address,
+            None => {
+                let route02 = UniswapV2Router02::new(self.router_address, self.client.clone());
+                let factory = UniswapV2Factory::new(route02.factory().await?, self.client.clone());
+                let pair_address = factory.get_pair(token_in, token_out).await?;
+                if pair_address.is_zero() {
+                    return Err(anyhow!("no pair for {:?} / {:?}", token_in, token_out));
+                }
+                self.pairs.lock().unwrap().insert(tokens, pair_address);
+                pair_address
+            }
+        };
+        let pair = UniswapV2Pair::new(pair_address, self.client.clone());
+        let (reserve_0, reserve_1, _) = pair.get_reserves().await?;
+        let (reserve_0, reserve_1) = (U256::from(reserve_0), U256::from(reserve_1));
+        if tokens.0 == token_in {
+            Ok((reserve_0, reserve_1))
+        } else {
+            Ok((reserve_1, reserve_0))
+        }
+    }
+
+    async fn balance(&self, token: Address) -> Result<U256> {
+        let token = IERC20::new(token, self.client.clone());
+        Ok(token.balance_of(self.owner).await?)
+    }
+
+    async fn gas_price(&self) -> Result<U256> {
+        self.client
+            .get_gas_price()
+            .await
+            .map_err(|e| anyhow!("get gas price fail: {}", e))
+    }
+
+    async fn approve(
+        &self,
+        token: Address,
+        spender: Address,
+        value: U256,
+        gas_price: U256,
+    ) -> Result<()> {
+        let token = IERC20::new(token, self.client.clone());
+
+        let approve_token_call = token.approve(spender, value);
+
+        let gas = approve_token_call
+            .estimate_gas()
+            .await
+            .unwrap_or(U256::from(DEFAULT_APPROVE_CALL_GAS));
+
+        approve_token_call
+            .gas(gas_limit(gas))
+            .gas_price(gas_price)
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    async fn swap(&self, order: SwapOrder) -> Result<()> {
+        let route02 = UniswapV2Router02::new(self.router_address, self.client.clone());
+
+        let swap_call = route02.swap_exact_tokens_for_tokens(
+            order.amount_in,
+            order.amount_out_min,
+            vec![order.token_in, order.token_out],
+            self.owner,
+            order.deadline,
+        );
+
+        let gas = swap_call
+            .estimate_gas()
+            .await
+            .unwrap_or(U256::from(DEFAULT_SWAP_CALL_GAS));
+
+        let swap_call = swap_call.gas(gas_limit(gas)).gas_price(order.gas_price);
+        match (&self.relay, &self.supervisor) {
+            (Some(relay), supervisor) => {
+                let mut tx = swap_call.tx;
+                tx.set_from(self.owner);
+                self.client
+                    .fill_transaction(&mut tx, None)
+                    .await
+                    .map_err(|e| anyhow!("fill transaction fail: {}", e))?;
+                let hash = relay
+                    .send(self.client.clone(), tx.clone(), self.owner)
+                    .await?;
+                // a publicly broadcast fallback can get stuck as well
+                if let Some(supervisor) = supervisor {
+                    supervisor.track(hash, tx).await?;
+                }
+            }
+            (None, Some(supervisor)) => {
+                supervisor.send(swap_call.tx).await?;
+            }
+            (None, None) => {
+                swap_call.send().await?;
+            }
+        }
+
+        Ok(())
+    }
+}
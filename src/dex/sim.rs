@@ -0,0 +1,192 @@
+use super::{DexClient, SwapOrder};
+use crate::amm::get_amount_out;
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+use ethers::types::{Address, U256};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// In-memory constant-product pool behaving like a router in front of one pair.
+///
+/// Balances and allowances belong to a single trader, swaps revert with the router's
+/// messages so schedule and error handling can be exercised without a node.
+#[derive(Debug)]
+pub struct SimDex {
+    router: Address,
+    state: Mutex<SimState>,
+}
+
+#[derive(Debug, Default)]
+struct SimState {
+    reserves: HashMap<Address, U256>,
+    balances: HashMap<Address, U256>,
+    allowances: HashMap<(Address, Address), U256>,
+    gas_price: Option<U256>,
+    fail_quotes: bool,
+    swaps: Vec<SwapOrder>,
+}
+
+impl SimState {
+    fn reserves(&self, token_in: Address, token_out: Address) -> Result<(U256, U256)> {
+        match (self.reserves.get(&token_in), self.reserves.get(&token_out)) {
+            (Some(reserve_in), Some(reserve_out)) if token_in != token_out => {
+                Ok((*reserve_in, *reserve_out))
+            }
+            _ => Err(anyhow!("UniswapV2Library: INVALID_PATH")),
+        }
+    }
+}
+
+impl SimDex {
+    pub fn new(
+        router: Address,
+        token_a: Address,
+        reserve_a: U256,
+        token_b: Address,
+        reserve_b: U256,
+    ) -> Self {
+        let state = SimState {
+            reserves: HashMap::from([(token_a, reserve_a), (token_b, reserve_b)]),
+            ..Default::default()
+        };
+        Self {
+            router,
+            state: Mutex::new(state),
+        }
+    }
+
+    pub fn router(&self) -> Address {
+        self.router
+    }
+
+    pub fn set_balance(&self, token: Address, amount: U256) {
+        self.state.lock().unwrap().balances.insert(token, amount);
+    }
+
+    pub fn balance_of(&self, token: Address) -> U256 {
+        let state = self.state.lock().unwrap();
+        state.balances.get(&token).copied().unwrap_or_default()
+    }
+
+    pub fn allowance(&self, token: Address, spender: Address) -> U256 {
+        let state = self.state.lock().unwrap();
+        state
+            .allowances
+            .get(&(token, spender))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn reserve(&self, token: Address) -> U256 {
+        let state = self.state.lock().unwrap();
+        state.reserves.get(&token).copied().unwrap_or_default()
+    }
+
+    /// `None` makes `gas_price` fail like an unreachable RPC.
+    pub fn set_gas_price(&self, gas_price: Option<U256>) {
+        self.state.lock().unwrap().gas_price = gas_price;
+    }
+
+    pub fn set_fail_quotes(&self, fail_quotes: bool) {
+        self.state.lock().unwrap().fail_quotes = fail_quotes;
+    }
+
+    /// Orders that were filled, in execution order.
+    pub fn swaps(&self) -> Vec<SwapOrder> {
+        self.state.lock().unwrap().swaps.clone()
+    }
+
+    /// Trade from another account, moving the pool price without touching our balances.
+    pub fn external_swap(&self, token_in: Address, token_out: Address, amount_in: U256) -> U256 {
+        let mut state = self.state.lock().unwrap();
+        let (reserve_in, reserve_out) = state.reserves(token_in, token_out).unwrap();
+        let amount_out = get_amount_out(amount_in, reserve_in, reserve_out);
+        state.reserves.insert(token_in, reserve_in + amount_in);
+        state.reserves.insert(token_out, reserve_out - amount_out);
+        amount_out
+    }
+}
+
+#[async_trait]
+impl DexClient for SimDex {
+    async fn quote(&self, token_in: Address, token_out: Address, amount_in: U256) -> Result<U256> {
+        let state = self.state.lock().unwrap();
+        if state.fail_quotes {
+            return Err(anyhow!("quote unavailable"));
+        }
+        let (reserve_in, reserve_out) = state.reserves(token_in, token_out)?;
+        Ok(get_amount_out(amount_in, reserve_in, reserve_out))
+    }
+
+    async fn reserves(&self, token_in: Address, token_out: Address) -> Result<(U256, U256)> {
+        self.state.lock().unwrap().reserves(token_in, token_out)
+    }
+
+    async fn balance(&self, token: Address) -> Result<U256> {
+        Ok(self.balance_of(token))
+    }
+
+    async fn gas_price(&self) -> Result<U256> {
+        let state = self.state.lock().unwrap();
+        state
+            .gas_price
+            .ok_or_else(|| anyhow!("gas price unavailable"))
+    }
+
+    async fn approve(
+        &self,
+        token: Address,
+        spender: Address,
+        value: U256,
+        _gas_price: U256,
+    ) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        state.allowances.insert((token, spender), value);
+        Ok(())
+    }
+
+    async fn swap(&self, order: SwapOrder) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+
+        if order.deadline < U256::from(Utc::now().timestamp()) {
+            return Err(anyhow!("UniswapV2Router: EXPIRED"));
+        }
+        let (reserve_in, reserve_out) = state.reserves(order.token_in, order.token_out)?;
+        let amount_out = get_amount_out(order.amount_in, reserve_in, reserve_out);
+        if amount_out < order.amount_out_min {
+            return Err(anyhow!("UniswapV2Router: INSUFFICIENT_OUTPUT_AMOUNT"));
+        }
+
+        let allowance = state
+            .allowances
+            .get(&(order.token_in, self.router))
+            .copied()
+            .unwrap_or_default();
+        let balance = state
+            .balances
+            .get(&order.token_in)
+            .copied()
+            .unwrap_or_default();
+        if allowance < order.amount_in || balance < order.amount_in {
+            return Err(anyhow!("TransferHelper: TRANSFER_FROM_FAILED"));
+        }
+
+        state
+            .allowances
+            .insert((order.token_in, self.router), allowance - order.amount_in);
+        state
+            .balances
+            .insert(order.token_in, balance - order.amount_in);
+        *state.balances.entry(order.token_out).or_default() += amount_out;
+        state
+            .reserves
+            .insert(order.token_in, reserve_in + order.amount_in);
+        state
+            .reserves
+            .insert(order.token_out, reserve_out - amount_out);
+        state.swaps.push(order);
+
+        Ok(())
+    }
+}
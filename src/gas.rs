@@ -0,0 +1,101 @@
+use crate::{config::GasBudgetConfig, utils::to_float, BASIS_POINT};
+use ethers::types::U256;
+
+/// Whether a step fits the gas budget.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GasVerdict {
+    Within,
+    /// Over the gas price or gas share limit, may pass once gas drops.
+    Exceeded(String),
+    /// Sending would spend more than `max_total`, waiting does not help.
+    OverBudget(String),
+}
+
+/// Check one step costing `swap_gas` at `gas_price` against `budget`, `spent` being the gas
+/// already paid over the run and `notional` the step value in ETH when known.
+pub fn verdict(
+    budget: &GasBudgetConfig,
+    gas_price: U256,
+    spent: U256,
+    notional: Option<U256>,
+) -> GasVerdict {
+    let cost = gas_price * U256::from(budget.swap_gas);
+
+    if let Some(max_total) = budget.max_total {
+        let total = to_float(spent + cost, 18);
+        if total > max_total {
+            return GasVerdict::OverBudget(format!(
+                "gas spent would reach {} ETH, above max_total {}",
+                total, max_total
+            ));
+        }
+    }
+    if let Some(max_gas_price) = budget.max_gas_price {
+        let gwei = to_float(gas_price, 9);
+        if gwei > max_gas_price {
+            return GasVerdict::Exceeded(format!(
+                "gas price {} gwei above max_gas_price {}",
+                gwei, max_gas_price
+            ));
+        }
+    }
+    if let (Some(max_gas_share), Some(notional)) = (budget.max_gas_share, notional) {
+        if cost * U256::from(BASIS_POINT) > notional * U256::from(max_gas_share) {
+            return GasVerdict::Exceeded(format!(
+                "gas cost {} ETH is over {} bps of the step value {} ETH",
+                to_float(cost, 18),
+                max_gas_share,
+                to_float(notional, 18)
+            ));
+        }
+    }
+    GasVerdict::Within
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GasAction;
+
+    fn budget() -> GasBudgetConfig {
+        GasBudgetConfig {
+            max_total: Some(0.01f64),
+            max_gas_price: Some(30f64),
+            max_gas_share: Some(100),
+            swap_gas: 200000,
+            on_exceed: GasAction::Delay,
+            max_delay: 600,
+        }
+    }
+
+    #[test]
+    fn verdict_checks_total_then_price_then_share() {
+        let budget = budget();
+        let gwei = U256::exp10(9);
+        let one_eth = U256::exp10(18);
+
+        // 20 gwei * 200000 = 0.004 ETH, 0.4% of 1 ETH
+        assert_eq!(
+            verdict(&budget, gwei * 20, U256::zero(), Some(one_eth)),
+            GasVerdict::Within
+        );
+        assert!(matches!(
+            verdict(&budget, gwei * 20, one_eth / 100, Some(one_eth)),
+            GasVerdict::OverBudget(_)
+        ));
+        assert!(matches!(
+            verdict(&budget, gwei * 31, U256::zero(), Some(one_eth)),
+            GasVerdict::Exceeded(_)
+        ));
+        // 0.004 ETH is 2% of 0.2 ETH
+        assert!(matches!(
+            verdict(&budget, gwei * 20, U256::zero(), Some(one_eth / 5)),
+            GasVerdict::Exceeded(_)
+        ));
+        // unknown value skips the share check
+        assert_eq!(
+            verdict(&budget, gwei * 20, U256::zero(), None),
+            GasVerdict::Within
+        );
+    }
+}
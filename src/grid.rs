@@ -0,0 +1,312 @@
+use crate::{
+    config::{Config, GridConfig},
+    dex::DexClient,
+    erc20, pair,
+    tswap::Tswap,
+    utils::{from_float, price, to_float},
+};
+use anyhow::{anyhow, Result};
+use ethers::{
+    providers::Middleware,
+    types::{Address, U256},
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+const GRID_PATH: &str = "./grid.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridOrder {
+    /// Buy a tranche of token A at level `i`.
+    Buy(usize),
+    /// Sell the tranche bought at level `i` one level up.
+    Sell(usize),
+}
+
+/// Grid levels and which of them hold a bought tranche, persisted across restarts.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GridState {
+    pub pair_address: Address,
+    /// Level prices, ascending.
+    pub prices: Vec<f64>,
+    /// `filled[i]` once a tranche was bought at `prices[i]` and not sold yet.
+    pub filled: Vec<bool>,
+    /// Token A the buy at `prices[i]` received, what its sell sells again.
+    #[serde(default)]
+    pub held: Vec<U256>,
+    /// Pool price at the last check that went through without failed orders.
+    pub last_price: f64,
+}
+
+/// `levels` prices evenly spaced from `lower_price` to `upper_price`.
+pub fn levels(config: &GridConfig) -> Result<Vec<f64>> {
+    if config.levels < 2 || config.lower_price <= 0f64 || config.lower_price >= config.upper_price {
+        return Err(anyhow!(
+            "grid needs at least 2 levels and 0 < lower_price < upper_price"
+        ));
+    }
+    let gap = (config.upper_price - config.lower_price) / (config.levels - 1) as f64;
+    Ok((0..config.levels)
+        .map(|i| config.lower_price + gap * i as f64)
+        .collect())
+}
+
+impl GridState {
+    pub fn new(pair_address: Address, prices: Vec<f64>, price: f64) -> Self {
+        Self {
+            pair_address,
+            filled: vec![false; prices.len()],
+            held: vec![U256::zero(); prices.len()],
+            prices,
+            last_price: price,
+        }
+    }
+
+    /// Orders for the levels crossed between `last_price` and `price`.
+    ///
+    /// Crossing level `i` downward buys a tranche there, crossing level `i + 1` upward sells
+    /// it again. The top level only ever closes the level below it.
+    pub fn orders(&self, price: f64) -> Vec<GridOrder> {
+        let mut orders = vec![];
+        for i in 0..self.prices.len().saturating_sub(1) {
+            let (level, next) = (self.prices[i], self.prices[i + 1]);
+            if !self.filled[i] && self.last_price > level && price <= level {
+                orders.push(GridOrder::Buy(i));
+            }
+            if self.filled[i] && self.last_price < next && price >= next {
+                orders.push(GridOrder::Sell(i));
+            }
+        }
+        orders
+    }
+
+    /// Mark `order` as mined, `received` is the token A a buy got.
+    pub fn fill(&mut self, order: GridOrder, received: U256) {
+        match order {
+            GridOrder::Buy(i) => {
+                self.filled[i] = true;
+                self.held[i] = received;
+            }
+            GridOrder::Sell(i) => {
+                self.filled[i] = false;
+                self.held[i] = U256::zero();
+            }
+        }
+    }
+
+    /// Token A to sell for level `i`, `tranche` for a level filled before amounts were kept.
+    pub fn sell_amount(&self, i: usize, tranche: U256) -> U256 {
+        match self.held.get(i) {
+            Some(held) if !held.is_zero() => *held,
+            _ => tranche,
+        }
+    }
+}
+
+pub fn load() -> Result<Option<GridState>> {
+    if !Path::new(GRID_PATH).exists() {
+        return Ok(None);
+    }
+    let mut state: GridState = serde_json::from_str(&fs::read_to_string(GRID_PATH)?)?;
+    state.held.resize(state.prices.len(), U256::zero());
+    Ok(Some(state))
+}
+
+pub fn save(state: &GridState) -> Result<()> {
+    fs::write(GRID_PATH, serde_json::to_string_pretty(state)?)?;
+    Ok(())
+}
+
+/// Trade a tranche on every crossed grid level until Ctrl+C.
+pub async fn run<M: Middleware + 'static, D: DexClient>(
+    client: Arc<M>,
+    tswap: &Tswap<D>,
+    config: &Config,
+    owner: Address,
+) -> Result<()> {
+    let grid_config = config
+        .grid
+        .clone()
+        .ok_or_else(|| anyhow!("grid is not set in config"))?;
+    tswap.start_run();
+    let prices = levels(&grid_config)?;
+    let layout = pair::layout(client.clone(), &config.pool).await?;
+    let router_address = Address::from_str(&config.pool.router_address)?;
+    let token_a_address = Address::from_str(&config.pool.token_a_address)?;
+    let token_b_address = Address::from_str(&config.pool.token_b_address)?;
+    let (decimal_a, decimal_b) = (config.pool.token_a_decimal, config.pool.token_b_decimal);
+
+    let pool_price = |reserve_0, reserve_1| {
+        let (reserve_a, reserve_b) = layout.split(reserve_0, reserve_1);
+        price(
+            to_float(reserve_a, decimal_a),
+            to_float(reserve_b, decimal_b),
+        )
+    };
+
+    let mut state = match load()? {
+        Some(state) if state.pair_address == layout.pair_address && state.prices == prices => state,
+        Some(_) => {
+            return Err(anyhow!(
+                "{} was saved for another grid, remove it to start a new one",
+                GRID_PATH
+            ))
+        }
+        None => {
+            let (reserve_0, reserve_1) =
+                pair::get_reserves(client.clone(), layout.pair_address).await;
+            let state = GridState::new(
+                layout.pair_address,
+                prices,
+                pool_price(reserve_0, reserve_1),
+            );
+            save(&state)?;
+            state
+        }
+    };
+    println!(
+        "grid of {} levels from {} to {} {}/{}, {} filled, press Ctrl+C to stop",
+        state.prices.len(),
+        grid_config.lower_price,
+        grid_config.upper_price,
+        config.pool.token_b_symbol,
+        config.pool.token_a_symbol,
+        state.filled.iter().filter(|f| **f).count()
+    );
+
+    loop {
+        let (reserve_0, reserve_1) = pair::get_reserves(client.clone(), layout.pair_address).await;
+        let current_price = pool_price(reserve_0, reserve_1);
+
+        let mut all_filled = true;
+        for order in state.orders(current_price) {
+            let (token_in, token_out, amount) = match order {
+                GridOrder::Buy(i) => (
+                    token_b_address,
+                    token_a_address,
+                    from_float(grid_config.tranche * state.prices[i], decimal_b),
+                ),
+                GridOrder::Sell(i) => (
+                    token_a_address,
+                    token_b_address,
+                    state.sell_amount(i, from_float(grid_config.tranche, decimal_a)),
+                ),
+            };
+
+            if erc20::allowance(client.clone(), token_in, owner, router_address).await < amount {
+                if let Err(e) = tswap.approve_token(token_in, router_address, amount).await {
+                    println!("approve fail: {}", e);
+                }
+            }
+            let before = erc20::balance_of(client.clone(), token_in, owner).await;
+            let before_out = erc20::balance_of(client.clone(), token_out, owner).await;
+            let sent = tswap.swap(token_in, token_out, amount).await;
+            // a sent swap is only a fill once mined, a reverted one is retried
+            let result = match sent {
+                Ok(sent) if tswap.confirm_spent(token_in, before, sent).await => {
+                    Ok(erc20::balance_of(client.clone(), token_out, owner)
+                        .await
+                        .saturating_sub(before_out))
+                }
+                Ok(_) => Err(anyhow!("not mined before the deadline")),
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(received) => {
+                    println!(
+                        "price {} -> {:?} success, received {}",
+                        current_price, order, received
+                    );
+                    state.fill(order, received);
+                }
+                Err(e) => {
+                    println!("price {} -> {:?} fail: {}", current_price, order, e);
+                    all_filled = false;
+                }
+            }
+        }
+        if all_filled {
+            state.last_price = current_price;
+        }
+        save(&state)?;
+
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            _ = tokio::time::sleep(Duration::from_secs(grid_config.interval)) => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(price: f64) -> GridState {
+        let config = GridConfig {
+            lower_price: 1f64,
+            upper_price: 2f64,
+            levels: 5,
+            tranche: 1f64,
+            interval: 1,
+        };
+        GridState::new(Address::zero(), levels(&config).unwrap(), price)
+    }
+
+    #[test]
+    fn levels_are_evenly_spaced_bounds_included() {
+        assert_eq!(
+            state(1f64).prices,
+            vec![1f64, 1.25f64, 1.5f64, 1.75f64, 2f64]
+        );
+    }
+
+    #[test]
+    fn buys_crossed_levels_and_sells_one_level_up() {
+        let mut grid = state(1.6f64);
+        let orders = grid.orders(1.2f64);
+        assert_eq!(orders, vec![GridOrder::Buy(1), GridOrder::Buy(2)]);
+        for order in orders {
+            grid.fill(order, U256::from(990u64));
+        }
+        grid.last_price = 1.2f64;
+
+        // level 2 sells at 1.75 only, level 1 sells at 1.5
+        assert_eq!(grid.orders(1.5f64), vec![GridOrder::Sell(1)]);
+        assert_eq!(
+            grid.orders(1.8f64),
+            vec![GridOrder::Sell(1), GridOrder::Sell(2)]
+        );
+        // already holding level 1, no second tranche
+        assert!(grid.orders(1.1f64).is_empty());
+    }
+
+    #[test]
+    fn sells_what_the_buy_received() {
+        let tranche = U256::from(1000u64);
+        let mut grid = state(1.6f64);
+        grid.fill(GridOrder::Buy(1), U256::from(996u64));
+        assert_eq!(grid.sell_amount(1, tranche), U256::from(996u64));
+
+        grid.fill(GridOrder::Sell(1), U256::zero());
+        assert!(!grid.filled[1]);
+        assert!(grid.held[1].is_zero());
+
+        // a level filled by a grid.json without held amounts sells a tranche
+        grid.filled[2] = true;
+        grid.held.clear();
+        assert_eq!(grid.sell_amount(2, tranche), tranche);
+    }
+
+    #[test]
+    fn no_orders_without_a_crossing() {
+        let grid = state(1.3f64);
+        assert!(grid.orders(1.4f64).is_empty());
+        assert!(grid.orders(1.3f64).is_empty());
+    }
+}
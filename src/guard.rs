@@ -0,0 +1,155 @@
+use crate::{
+    aggregator,
+    config::{Config, ReferenceSource},
+    pair::{self, PairLayout},
+    twap,
+    utils::{price, to_float},
+    BASIS_POINT,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use ethers::{providers::Middleware, types::Address};
+use std::fmt::Debug;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Traded pool price next to the reference price, token A priced in token B.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GuardReading {
+    pub pool_price: f64,
+    pub reference_price: f64,
+}
+
+impl GuardReading {
+    /// Distance between both prices relative to the reference, 4 decimals.
+    pub fn deviation(&self) -> f64 {
+        if self.reference_price <= 0f64 {
+            return f64::INFINITY;
+        }
+        (self.pool_price / self.reference_price - 1f64).abs() * BASIS_POINT as f64
+    }
+}
+
+/// Independent price check run by `Tswap` before every step.
+#[async_trait]
+pub trait PriceGuard: Debug + Send + Sync {
+    async fn read(&self) -> Result<GuardReading>;
+
+    /// Largest `deviation` still allowed to trade, 4 decimals.
+    fn max_deviation(&self) -> u64;
+}
+
+#[derive(Debug)]
+enum Reference {
+    Pool(PairLayout),
+    Chainlink {
+        aggregator_address: Address,
+        invert: bool,
+    },
+    Twap(u64),
+}
+
+/// Reads the traded pair against the configured reference source.
+#[derive(Debug)]
+pub struct ReferenceGuard<M> {
+    client: Arc<M>,
+    layout: PairLayout,
+    reference: Reference,
+    max_deviation: u64,
+}
+
+impl<M: Middleware + 'static> ReferenceGuard<M> {
+    /// Guard described by `config.guard`, None when it is not set.
+    pub async fn new(client: Arc<M>, config: &Config) -> Result<Option<Self>> {
+        let guard_config = match &config.guard {
+            Some(guard_config) => guard_config,
+            None => return Ok(None),
+        };
+        let layout = pair::layout(client.clone(), &config.pool).await?;
+        let reference = match &guard_config.reference {
+            ReferenceSource::Pool { pair_address } => Reference::Pool(
+                pair::layout_at(
+                    client.clone(),
+                    Address::from_str(pair_address)?,
+                    &config.pool,
+                )
+                .await?,
+            ),
+            ReferenceSource::Chainlink {
+                aggregator_address,
+                invert,
+            } => Reference::Chainlink {
+                aggregator_address: Address::from_str(aggregator_address)?,
+                invert: invert.unwrap_or(false),
+            },
+            ReferenceSource::Twap { window } => Reference::Twap(*window),
+        };
+
+        Ok(Some(Self {
+            client,
+            layout,
+            reference,
+            max_deviation: guard_config.max_deviation,
+        }))
+    }
+
+    async fn pool_price(&self, layout: &PairLayout) -> f64 {
+        let (reserve_0, reserve_1) =
+            pair::get_reserves(self.client.clone(), layout.pair_address).await;
+        let (reserve_a, reserve_b) = layout.split(reserve_0, reserve_1);
+        price(
+            to_float(reserve_a, layout.token_a_decimal),
+            to_float(reserve_b, layout.token_b_decimal),
+        )
+    }
+
+    async fn reference_price(&self) -> Result<f64> {
+        match &self.reference {
+            Reference::Pool(layout) => Ok(self.pool_price(layout).await),
+            Reference::Chainlink {
+                aggregator_address,
+                invert,
+            } => {
+                let answer =
+                    aggregator::latest_answer(self.client.clone(), *aggregator_address).await?;
+                Ok(if *invert { 1f64 / answer } else { answer })
+            }
+            Reference::Twap(window) => Ok(twap::twap(self.client.clone(), &self.layout, *window)
+                .await?
+                .price),
+        }
+    }
+}
+
+#[async_trait]
+impl<M: Middleware + 'static> PriceGuard for ReferenceGuard<M> {
+    async fn read(&self) -> Result<GuardReading> {
+        Ok(GuardReading {
+            pool_price: self.pool_price(&self.layout).await,
+            reference_price: self.reference_price().await?,
+        })
+    }
+
+    fn max_deviation(&self) -> u64 {
+        self.max_deviation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deviation_is_relative_to_reference() {
+        let reading = GuardReading {
+            pool_price: 1.02f64,
+            reference_price: 1f64,
+        };
+        assert!((reading.deviation() - 200f64).abs() < 1e-6);
+        let reading = GuardReading {
+            pool_price: 0.97f64,
+            reference_price: 1f64,
+        };
+        assert!((reading.deviation() - 300f64).abs() < 1e-6);
+    }
+}
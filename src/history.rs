@@ -0,0 +1,263 @@
+use crate::{
+    config::Config,
+    pair::{self, PairLayout},
+    utils::{price, to_float},
+};
+use anyhow::{anyhow, Result};
+use bindings_uniswapv2::uniswap_v2_pair::{SwapFilter, SyncFilter, UniswapV2PairEvents};
+use ethers::{
+    contract::{parse_log, EthEvent, LogMeta},
+    providers::Middleware,
+    types::{BlockNumber, Filter, U256},
+};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::sync::Arc;
+
+const CSV_HEADER: &str =
+    "block_number,timestamp,reserve_a,reserve_b,price,amount_a_in,amount_b_in,amount_a_out,amount_b_out";
+
+/// Largest block range requested per `eth_getLogs` call, halved whenever the provider refuses it.
+const MAX_CHUNK_SIZE: u64 = 2000u64;
+
+/// Pool state at the end of one block plus the external swap flow it saw, in raw token units.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BlockRecord {
+    pub block_number: u64,
+    /// Unix seconds, interpolated between chunk boundaries.
+    pub timestamp: u64,
+    pub reserve_a: U256,
+    pub reserve_b: U256,
+    /// Token A priced in token B, human units.
+    pub price: f64,
+    pub amount_a_in: U256,
+    pub amount_b_in: U256,
+    pub amount_a_out: U256,
+    pub amount_b_out: U256,
+}
+
+impl BlockRecord {
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{}",
+            self.block_number,
+            self.timestamp,
+            self.reserve_a,
+            self.reserve_b,
+            self.price,
+            self.amount_a_in,
+            self.amount_b_in,
+            self.amount_a_out,
+            self.amount_b_out
+        )
+    }
+
+    fn from_csv(line: &str) -> Result<Self> {
+        let fields: Vec<&str> = line.trim().split(',').collect();
+        if fields.len() != 9 {
+            return Err(anyhow!("malformed history row: {}", line));
+        }
+        Ok(Self {
+            block_number: fields[0].parse()?,
+            timestamp: fields[1].parse()?,
+            reserve_a: U256::from_dec_str(fields[2])?,
+            reserve_b: U256::from_dec_str(fields[3])?,
+            price: fields[4].parse()?,
+            amount_a_in: U256::from_dec_str(fields[5])?,
+            amount_b_in: U256::from_dec_str(fields[6])?,
+            amount_a_out: U256::from_dec_str(fields[7])?,
+            amount_b_out: U256::from_dec_str(fields[8])?,
+        })
+    }
+}
+
+pub fn write_csv(path: &str, records: &[BlockRecord]) -> Result<()> {
+    let mut file = File::create(path)?;
+    writeln!(file, "{}", CSV_HEADER)?;
+    for record in records {
+        writeln!(file, "{}", record.to_csv())?;
+    }
+    Ok(())
+}
+
+pub fn read_csv(path: &str) -> Result<Vec<BlockRecord>> {
+    let file = File::open(path)?;
+    let mut records = vec![];
+    for line in BufReader::new(file).lines().skip(1) {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        records.push(BlockRecord::from_csv(&line)?);
+    }
+    Ok(records)
+}
+
+async fn block_timestamp<M: Middleware + 'static>(
+    client: Arc<M>,
+    block_number: u64,
+) -> Result<u64> {
+    let block = client
+        .get_block(block_number)
+        .await
+        .map_err(|e| anyhow!("get block {} fail: {}", block_number, e))?
+        .ok_or_else(|| anyhow!("block {} not found", block_number))?;
+    Ok(block.timestamp.as_u64())
+}
+
+/// Fold decoded `Swap` / `Sync` logs of one chunk into per-block records.
+fn aggregate(
+    logs: Vec<(UniswapV2PairEvents, LogMeta)>,
+    layout: &PairLayout,
+    records: &mut Vec<BlockRecord>,
+) {
+    for (event, meta) in logs {
+        let block_number = meta.block_number.as_u64();
+        if records.last().map(|r| r.block_number) != Some(block_number) {
+            let previous = records.last().cloned().unwrap_or_default();
+            records.push(BlockRecord {
+                block_number,
+                reserve_a: previous.reserve_a,
+                reserve_b: previous.reserve_b,
+                price: previous.price,
+                ..Default::default()
+            });
+        }
+        let record = records.last_mut().unwrap();
+
+        match event {
+            UniswapV2PairEvents::SyncFilter(sync) => {
+                let (reserve_a, reserve_b) =
+                    layout.split(U256::from(sync.reserve_0), U256::from(sync.reserve_1));
+                record.reserve_a = reserve_a;
+                record.reserve_b = reserve_b;
+                record.price = price(
+                    to_float(reserve_a, layout.token_a_decimal),
+                    to_float(reserve_b, layout.token_b_decimal),
+                );
+            }
+            UniswapV2PairEvents::SwapFilter(swap) => {
+                let (a_in, b_in) = layout.split(swap.amount_0_in, swap.amount_1_in);
+                let (a_out, b_out) = layout.split(swap.amount_0_out, swap.amount_1_out);
+                record.amount_a_in += a_in;
+                record.amount_b_in += b_in;
+                record.amount_a_out += a_out;
+                record.amount_b_out += b_out;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Walks `[from_block, to_block]` in `eth_getLogs` chunks, halving the chunk whenever the
+/// provider refuses it.
+pub struct LogChunks {
+    filter: Filter,
+    next_block: u64,
+    to_block: u64,
+    chunk_size: u64,
+}
+
+impl LogChunks {
+    pub fn new(filter: Filter, from_block: u64, to_block: u64) -> Self {
+        Self {
+            filter,
+            next_block: from_block,
+            to_block,
+            chunk_size: MAX_CHUNK_SIZE,
+        }
+    }
+
+    /// Block range and decoded pair logs of the next chunk, None once past `to_block`.
+    pub async fn next<M: Middleware + 'static>(
+        &mut self,
+        client: &M,
+    ) -> Option<Result<(u64, u64, Vec<(UniswapV2PairEvents, LogMeta)>)>> {
+        while self.next_block <= self.to_block {
+            let chunk_start = self.next_block;
+            let chunk_end = (chunk_start + self.chunk_size - 1).min(self.to_block);
+            let chunk_filter = self
+                .filter
+                .clone()
+                .from_block(BlockNumber::Number(chunk_start.into()))
+                .to_block(BlockNumber::Number(chunk_end.into()));
+
+            let logs = match client.get_logs(&chunk_filter).await {
+                Ok(logs) => logs,
+                Err(e) if self.chunk_size > 1 => {
+                    self.chunk_size /= 2;
+                    println!(
+                        "get logs {}..{} fail ({}), retry with {} blocks",
+                        chunk_start, chunk_end, e, self.chunk_size
+                    );
+                    continue;
+                }
+                Err(e) => {
+                    return Some(Err(anyhow!(
+                        "get logs at block {} fail: {}",
+                        chunk_start,
+                        e
+                    )))
+                }
+            };
+
+            let decoded = logs
+                .into_iter()
+                .filter_map(|log| {
+                    let meta = LogMeta::from(&log);
+                    parse_log::<UniswapV2PairEvents>(log)
+                        .ok()
+                        .map(|e| (e, meta))
+                })
+                .collect::<Vec<_>>();
+
+            self.next_block = chunk_end + 1;
+            self.chunk_size = (self.chunk_size * 2).min(MAX_CHUNK_SIZE);
+            return Some(Ok((chunk_start, chunk_end, decoded)));
+        }
+        None
+    }
+}
+
+/// Walk `Swap` and `Sync` logs of the configured pair over `[from_block, to_block]`.
+pub async fn export<M: Middleware + 'static>(
+    client: Arc<M>,
+    config: &Config,
+    from_block: u64,
+    to_block: u64,
+) -> Result<Vec<BlockRecord>> {
+    let layout = pair::layout(client.clone(), &config.pool).await?;
+    let filter = Filter::new()
+        .address(layout.pair_address)
+        .topic0(vec![SwapFilter::signature(), SyncFilter::signature()]);
+
+    let mut records: Vec<BlockRecord> = vec![];
+    let mut chunks = LogChunks::new(filter, from_block, to_block);
+
+    while let Some(chunk) = chunks.next(client.as_ref()).await {
+        let (chunk_start, chunk_end, decoded) = chunk?;
+
+        let first_new = records.len();
+        aggregate(decoded, &layout, &mut records);
+
+        // interpolate timestamps between the chunk boundaries instead of one call per block
+        if records.len() > first_new {
+            let start_time = block_timestamp(client.clone(), chunk_start).await?;
+            let end_time = block_timestamp(client.clone(), chunk_end).await?;
+            let span = (chunk_end - chunk_start).max(1);
+            for record in records[first_new..].iter_mut() {
+                let offset = record.block_number - chunk_start;
+                record.timestamp = start_time + (end_time - start_time) * offset / span;
+            }
+        }
+
+        println!(
+            "blocks {}..{}: {} blocks with activity",
+            chunk_start,
+            chunk_end,
+            records.len() - first_new
+        );
+    }
+
+    Ok(records)
+}
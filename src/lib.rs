@@ -0,0 +1,34 @@
+pub mod params;
+pub use params::*;
+
+pub mod contracts;
+pub use contracts::*;
+
+pub mod amm;
+pub mod arb;
+pub mod backtest;
+pub mod cycle;
+pub mod dex;
+pub mod gas;
+pub mod grid;
+pub mod guard;
+pub mod history;
+pub mod liquidity;
+pub mod mm;
+pub mod positions;
+pub mod preflight;
+pub mod relay;
+pub mod sandwich;
+pub mod snapshot;
+pub mod supervisor;
+pub mod tswap;
+pub mod twap;
+pub mod utils;
+pub mod vwap;
+pub mod wallets;
+pub mod watch;
+
+pub const BASIS_POINT: u64 = 10000u64;
+
+pub const GAS_MULTIPLIER: u64 = 13000u64;
+pub const DEFAULT_GAS_PRICE: u64 = 50000000000u64; // 50 gwei
@@ -0,0 +1,262 @@
+use crate::{
+    config::{Config, PoolConfig},
+    pair::{self, PairLayout},
+    router::{self, LiquidityAmounts},
+    utils::{price, to_float},
+    BASIS_POINT,
+};
+use anyhow::{anyhow, Result};
+use ethers::{
+    providers::Middleware,
+    types::{Address, TransactionReceipt, U256},
+};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Pair LP tokens have 18 decimals.
+pub const LP_DECIMAL: u32 = 18u32;
+
+/// Our LP position and the reserves it was valued against.
+#[derive(Debug, Clone, Copy)]
+pub struct LpStatus {
+    pub lp_balance: U256,
+    pub total_supply: U256,
+    pub reserve_a: U256,
+    pub reserve_b: U256,
+    pub k_last: U256,
+    /// Underlying token A redeemable for `lp_balance`.
+    pub amount_a: U256,
+    /// Underlying token B redeemable for `lp_balance`.
+    pub amount_b: U256,
+}
+
+impl LpStatus {
+    /// Total supply after the protocol fee the pair mints on its next liquidity event.
+    ///
+    /// Mirrors `UniswapV2Pair._mintFee`: with the fee on, 1/6 of the `sqrt(k)` growth since
+    /// `kLast` goes to the fee receiver and dilutes every LP.
+    pub fn effective_supply(&self) -> U256 {
+        if self.k_last.is_zero() {
+            return self.total_supply;
+        }
+        let root_k = (self.reserve_a * self.reserve_b).integer_sqrt();
+        let root_k_last = self.k_last.integer_sqrt();
+        if root_k <= root_k_last {
+            return self.total_supply;
+        }
+        let numerator = self.total_supply * (root_k - root_k_last);
+        let denominator = root_k * 5 + root_k_last;
+        self.total_supply + numerator / denominator
+    }
+
+    /// Token amounts redeemable for `liquidity` LP tokens at these reserves.
+    pub fn underlying(&self, liquidity: U256) -> LiquidityAmounts {
+        let supply = self.effective_supply();
+        if supply.is_zero() {
+            return LiquidityAmounts {
+                amount_a: U256::zero(),
+                amount_b: U256::zero(),
+            };
+        }
+        LiquidityAmounts {
+            amount_a: liquidity * self.reserve_a / supply,
+            amount_b: liquidity * self.reserve_b / supply,
+        }
+    }
+
+    /// LP tokens minted for depositing `amounts`, as `UniswapV2Pair.mint` computes them.
+    pub fn minted(&self, amounts: LiquidityAmounts) -> U256 {
+        let supply = self.effective_supply();
+        if self.reserve_a.is_zero() || self.reserve_b.is_zero() {
+            return U256::zero();
+        }
+        (amounts.amount_a * supply / self.reserve_a).min(amounts.amount_b * supply / self.reserve_b)
+    }
+
+    pub fn share_bps(&self) -> f64 {
+        let supply = self.effective_supply();
+        if supply.is_zero() {
+            return 0f64;
+        }
+        to_float(self.lp_balance, LP_DECIMAL) / to_float(supply, LP_DECIMAL) * BASIS_POINT as f64
+    }
+}
+
+fn with_slippage(amounts: LiquidityAmounts, slippage: u64) -> LiquidityAmounts {
+    let bound = |amount: U256| {
+        amount
+            .checked_mul(U256::from(slippage))
+            .unwrap()
+            .checked_div(U256::from(BASIS_POINT))
+            .unwrap()
+    };
+    LiquidityAmounts {
+        amount_a: bound(amounts.amount_a),
+        amount_b: bound(amounts.amount_b),
+    }
+}
+
+fn tokens(pool: &PoolConfig) -> Result<(Address, Address, Address)> {
+    Ok((
+        Address::from_str(&pool.router_address)?,
+        Address::from_str(&pool.token_a_address)?,
+        Address::from_str(&pool.token_b_address)?,
+    ))
+}
+
+pub async fn status<M: Middleware + 'static>(
+    client: Arc<M>,
+    layout: &PairLayout,
+    owner: Address,
+) -> LpStatus {
+    let lp_balance = pair::balance_of(client.clone(), layout.pair_address, owner).await;
+    let total_supply = pair::total_supply(client.clone(), layout.pair_address).await;
+    let k_last = pair::k_last(client.clone(), layout.pair_address).await;
+    let (reserve_0, reserve_1) = pair::get_reserves(client, layout.pair_address).await;
+    let (reserve_a, reserve_b) = layout.split(reserve_0, reserve_1);
+
+    let mut status = LpStatus {
+        lp_balance,
+        total_supply,
+        reserve_a,
+        reserve_b,
+        k_last,
+        amount_a: U256::zero(),
+        amount_b: U256::zero(),
+    };
+    let underlying = status.underlying(lp_balance);
+    status.amount_a = underlying.amount_a;
+    status.amount_b = underlying.amount_b;
+    status
+}
+
+pub fn print_status(status: &LpStatus, pool: &PoolConfig) {
+    let amount_a = to_float(status.amount_a, pool.token_a_decimal);
+    let amount_b = to_float(status.amount_b, pool.token_b_decimal);
+    let pool_price = price(
+        to_float(status.reserve_a, pool.token_a_decimal),
+        to_float(status.reserve_b, pool.token_b_decimal),
+    );
+
+    println!(
+        "LP: {} of {} ({:.4}% of pool)",
+        to_float(status.lp_balance, LP_DECIMAL),
+        to_float(status.total_supply, LP_DECIMAL),
+        status.share_bps() / 100f64
+    );
+    println!(
+        "underlying: {} ({}) - {} ({})",
+        amount_a, pool.token_a_symbol, amount_b, pool.token_b_symbol
+    );
+    println!(
+        "value: {} ({}) at {} {}/{}",
+        amount_a * pool_price + amount_b,
+        pool.token_b_symbol,
+        pool_price,
+        pool.token_b_symbol,
+        pool.token_a_symbol
+    );
+}
+
+/// Token B needed next to `amount_a` token A to add liquidity at the current pool ratio.
+pub async fn plan_add<M: Middleware + 'static>(
+    client: Arc<M>,
+    config: &Config,
+    layout: &PairLayout,
+    amount_a: U256,
+) -> Result<LiquidityAmounts> {
+    let (router_address, _, _) = tokens(&config.pool)?;
+    let (reserve_0, reserve_1) = pair::get_reserves(client.clone(), layout.pair_address).await;
+    let (reserve_a, reserve_b) = layout.split(reserve_0, reserve_1);
+    if reserve_a.is_zero() || reserve_b.is_zero() {
+        return Err(anyhow!(
+            "pair has no liquidity yet, nothing to quote against"
+        ));
+    }
+
+    let amount_b = router::quote(client, router_address, amount_a, reserve_a, reserve_b).await?;
+    Ok(LiquidityAmounts { amount_a, amount_b })
+}
+
+/// Add `desired` liquidity, accepting down to `slippage` of each amount, and wait for it to
+/// be mined.
+pub async fn add<M: Middleware + 'static>(
+    client: Arc<M>,
+    config: &Config,
+    owner: Address,
+    desired: LiquidityAmounts,
+) -> Result<TransactionReceipt> {
+    let (router_address, token_a_address, token_b_address) = tokens(&config.pool)?;
+    router::add_liquidity(
+        client,
+        router_address,
+        token_a_address,
+        token_b_address,
+        desired,
+        with_slippage(desired, config.trade.slippage),
+        owner,
+        config,
+    )
+    .await
+}
+
+/// Err when `liquidity` is more than the LP tokens we hold.
+pub fn check_remove(status: &LpStatus, liquidity: U256) -> Result<()> {
+    if liquidity > status.lp_balance {
+        return Err(anyhow!("not enough LP tokens"));
+    }
+    Ok(())
+}
+
+/// Burn `liquidity` LP tokens, accepting down to `slippage` of the currently redeemable amounts.
+///
+/// Waits for the transaction, a reverted or dropped remove is an Err.
+pub async fn remove<M: Middleware + 'static>(
+    client: Arc<M>,
+    config: &Config,
+    status: &LpStatus,
+    owner: Address,
+    liquidity: U256,
+) -> Result<LiquidityAmounts> {
+    check_remove(status, liquidity)?;
+    let (router_address, token_a_address, token_b_address) = tokens(&config.pool)?;
+    let expected = status.underlying(liquidity);
+    router::remove_liquidity(
+        client,
+        router_address,
+        token_a_address,
+        token_b_address,
+        liquidity,
+        with_slippage(expected, config.trade.slippage),
+        owner,
+        config,
+    )
+    .await?;
+    Ok(expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn underlying_is_pro_rata_share_of_reserves() {
+        let status = LpStatus {
+            lp_balance: U256::from(250u64),
+            total_supply: U256::from(1000u64),
+            reserve_a: U256::from(4000u64),
+            reserve_b: U256::from(8000u64),
+            k_last: U256::zero(),
+            amount_a: U256::zero(),
+            amount_b: U256::zero(),
+        };
+        let underlying = status.underlying(status.lp_balance);
+        assert_eq!(underlying.amount_a, U256::from(1000u64));
+        assert_eq!(underlying.amount_b, U256::from(2000u64));
+        assert_eq!(status.share_bps(), 2500f64);
+
+        let min = with_slippage(underlying, 9500);
+        assert_eq!(min.amount_a, U256::from(950u64));
+        assert_eq!(min.amount_b, U256::from(1900u64));
+    }
+}
@@ -11,23 +11,39 @@ use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 use text_io::try_read;
-use tswap::{Tswap, SWAP_DEADLINE};
-
-pub mod params;
-pub use params::*;
-
-pub mod contracts;
-pub use contracts::*;
-
-pub mod tswap;
+use uniswapv2_mm::dex::RouterClient;
+use uniswapv2_mm::tswap::{check_allowance, Tswap};
+use uniswapv2_mm::*;
 
 const TRADING_ACTION: i32 = 1i32;
 const CONVERTING_ACTION: i32 = 2i32;
-
-const BASIS_POINT: u64 = 10000u64;
-
-const GAS_MULTIPLIER: u64 = 13000u64;
-const DEFAULT_GAS_PRICE: u64 = 50000000000u64; // 50 gwei
+const WATCHING_ACTION: i32 = 3i32;
+const EXPORTING_ACTION: i32 = 4i32;
+const BACKTESTING_ACTION: i32 = 5i32;
+const PROVIDING_ACTION: i32 = 6i32;
+const MARKET_MAKING_ACTION: i32 = 7i32;
+const GRID_ACTION: i32 = 8i32;
+const TWAP_ACTION: i32 = 9i32;
+const ARB_SCAN_ACTION: i32 = 10i32;
+const WALLETS_ACTION: i32 = 11i32;
+const PENDING_ACTION: i32 = 12i32;
+
+const MENU: [&str; 14] = [
+    "########################",
+    "#    1 is trading      #",
+    "#    2 is converting   #",
+    "#    3 is watching     #",
+    "#    4 is exporting    #",
+    "#    5 is backtesting  #",
+    "#    6 is providing    #",
+    "#    7 is rebalancing  #",
+    "#    8 is grid trading #",
+    "#    9 is twap         #",
+    "#   10 is arb scan     #",
+    "#   11 is wallets      #",
+    "#   12 is pending      #",
+    "########################",
+];
 
 #[tokio::main]
 async fn main() -> Result<(), ()> {
@@ -53,36 +69,141 @@ async fn main() -> Result<(), ()> {
         .with_chain_id(config.network.chain_id);
     let address = wallet.address();
 
-    let provider = Arc::new(provider.nonce_manager(address).with_signer(wallet.clone()));
-
-    let tswap = Tswap::new(provider.clone(), address, config.clone());
+    let provider = Arc::new(
+        provider
+            .clone()
+            .nonce_manager(address)
+            .with_signer(wallet.clone()),
+    );
+
+    let snapshot = match snapshot::SnapshotReader::new(provider.clone(), &config, address).await {
+        Ok(reader) => Some(Arc::new(reader)),
+        Err(e) => {
+            println!("state snapshot disabled: {}", e);
+            None
+        }
+    };
+
+    // private submission of swaps, signed with a throwaway identity unless RELAY_AUTH_KEY is set
+    let relay = config.network.relay.as_ref().map(|relay_config| {
+        let auth = match &env_vars.relay_auth_key {
+            Some(key) => key.parse::<LocalWallet>().unwrap(),
+            None => LocalWallet::new(&mut rand::thread_rng()),
+        };
+        Arc::new(relay::Relay::new(relay_config, auth))
+    });
+
+    // tracks sent swaps, resending or cancelling stuck ones in the background when configured
+    let supervise = |client, owner| {
+        let supervisor = Arc::new(supervisor::TxSupervisor::new(
+            client,
+            owner,
+            config.network.supervisor.clone(),
+        ));
+        if config.network.supervisor.is_some() {
+            let background = supervisor.clone();
+            tokio::spawn(async move { background.run().await });
+        }
+        supervisor
+    };
+    let tx_supervisor = supervise(provider.clone(), address);
+
+    let dex = RouterClient::new(provider.clone(), router_address, address)
+        .with_supervisor(tx_supervisor.clone());
+    let dex = match &relay {
+        Some(relay) => dex.with_relay(relay.clone()),
+        None => dex,
+    };
+    let tswap = Tswap::new(Arc::new(dex), config.clone());
+    let tswap = match &snapshot {
+        Some(reader) => tswap.with_state(reader.clone()),
+        None => tswap,
+    };
+    let guard = match guard::ReferenceGuard::new(provider.clone(), &config).await {
+        Ok(guard) => guard.map(Arc::new),
+        Err(e) => {
+            println!("reference guard disabled: {}", e);
+            None
+        }
+    };
+    let tswap = match &guard {
+        Some(guard) => tswap.with_guard(guard.clone()),
+        None => tswap,
+    };
+
+    // sub-wallets, each signing with its own nonce manager
+    let sub_wallets: Vec<_> = env_vars
+        .sub_private_keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| {
+            let sub_wallet: LocalWallet = key
+                .parse::<LocalWallet>()
+                .unwrap()
+                .with_chain_id(config.network.chain_id);
+            let sub_address = sub_wallet.address();
+            let client = Arc::new(
+                provider
+                    .inner()
+                    .inner()
+                    .clone()
+                    .nonce_manager(sub_address)
+                    .with_signer(sub_wallet),
+            );
+            let dex = RouterClient::new(client.clone(), router_address, sub_address)
+                .with_supervisor(supervise(client.clone(), sub_address));
+            let dex = match &relay {
+                Some(relay) => dex.with_relay(relay.clone()),
+                None => dex,
+            };
+            let sub_tswap = Tswap::new(Arc::new(dex), wallets::wallet_config(&config, index));
+            let sub_tswap = match &guard {
+                Some(guard) => sub_tswap.with_guard(guard.clone()),
+                None => sub_tswap,
+            };
+            wallets::Wallet {
+                address: sub_address,
+                client,
+                tswap: Arc::new(sub_tswap),
+            }
+        })
+        .collect();
 
     loop {
-        print!(
-            "\n{}\n{}\n{}\n{}\n",
-            "########################\n",
-            "#    1 is trading      #\n",
-            "#    2 is converting   #\n",
-            "########################\n"
-        );
+        println!();
+        for line in MENU {
+            println!("{}\n", line);
+        }
         print!("Action: ");
         let option: i32 = try_read!("{}\n").unwrap_or(0);
         match option {
             TRADING_ACTION => {
                 // log account balance
-                let token_a_balance =
-                    erc20::balance_of(provider.clone(), token_a_address, address).await;
-                let token_b_balance =
-                    erc20::balance_of(provider.clone(), token_b_address, address).await;
+                let state = match &snapshot {
+                    Some(reader) => reader.read().await.map_err(|e| {
+                        println!("state snapshot fail: {}", e);
+                    }),
+                    None => Err(()),
+                };
+                if let Ok(state) = state {
+                    println!("Account {}:", address);
+                    state.print(&config);
+                    println!();
+                } else {
+                    let token_a_balance =
+                        erc20::balance_of(provider.clone(), token_a_address, address).await;
+                    let token_b_balance =
+                        erc20::balance_of(provider.clone(), token_b_address, address).await;
 
-                println!(
-                    "Account {}: {} ({}) - {} ({})\n",
-                    address,
-                    format_units(token_a_balance, config.pool.token_a_decimal).unwrap(),
-                    config.pool.token_a_symbol,
-                    format_units(token_b_balance, config.pool.token_b_decimal).unwrap(),
-                    config.pool.token_b_symbol
-                );
+                    println!(
+                        "Account {}: {} ({}) - {} ({})\n",
+                        address,
+                        format_units(token_a_balance, config.pool.token_a_decimal).unwrap(),
+                        config.pool.token_a_symbol,
+                        format_units(token_b_balance, config.pool.token_b_decimal).unwrap(),
+                        config.pool.token_b_symbol
+                    );
+                }
                 // end of log
 
                 print!(
@@ -91,243 +212,65 @@ async fn main() -> Result<(), ()> {
                 );
                 let token_id: u32 = try_read!("{}\n").unwrap_or(1);
 
-                let (
-                    token_a_address,
-                    token_a_symbol,
-                    token_a_decimal,
-                    token_b_address,
-                    token_b_symbol,
-                    token_b_decimal,
-                ) = if token_id == 1 {
-                    (
-                        token_a_address,
-                        config.pool.token_a_symbol.clone(),
-                        config.pool.token_a_decimal,
-                        token_b_address,
-                        config.pool.token_b_symbol.clone(),
-                        config.pool.token_b_decimal,
-                    )
+                let (token_a, token_b) = (
+                    cycle::Token {
+                        address: token_a_address,
+                        symbol: config.pool.token_a_symbol.clone(),
+                        decimal: config.pool.token_a_decimal,
+                    },
+                    cycle::Token {
+                        address: token_b_address,
+                        symbol: config.pool.token_b_symbol.clone(),
+                        decimal: config.pool.token_b_decimal,
+                    },
+                );
+                let (token_a, token_b) = if token_id == 1 {
+                    (token_a, token_b)
                 } else {
-                    (
-                        token_b_address,
-                        config.pool.token_b_symbol.clone(),
-                        config.pool.token_b_decimal,
-                        token_a_address,
-                        config.pool.token_a_symbol.clone(),
-                        config.pool.token_a_decimal,
-                    )
+                    (token_b, token_a)
                 };
 
-                print!("Amount Sell ({}): ", token_a_symbol);
+                print!("Amount Sell ({}): ", token_a.symbol);
                 let eth: String = try_read!("{}\n").unwrap_or("0".to_string());
-                let gwei: U256 = parse_units(eth.clone(), token_a_decimal).unwrap().into();
+                let gwei: U256 = parse_units(eth.clone(), token_a.decimal).unwrap().into();
 
-                // approve router contract to use token for trading
-                check_allowance(
+                // refuse to start when the wallet or the pool cannot carry the round trip
+                let checks = match preflight::read(
                     provider.clone(),
-                    &tswap,
-                    token_a_address,
+                    &config,
                     address,
-                    router_address,
+                    token_a.address,
+                    token_b.address,
                     gwei,
                 )
-                .await;
-
-                //// log
-                println!("setup done, start trading");
-
-                let before_selling_a_balance =
-                    erc20::balance_of(provider.clone(), token_a_address, address).await;
-                let before_selling_b_balance =
-                    erc20::balance_of(provider.clone(), token_b_address, address).await;
-
-                println!(
-                    "------------ selling stage ({} steps) ------------",
-                    config.trade.tswap_sell
-                );
-                println!(
-                    "sell {} ({}) to buy {}",
-                    eth, token_a_symbol, token_b_symbol
-                );
-                //// end of log
-
-                // sell token A
-                tswap
-                    .tswap(
-                        router_address,
-                        token_a_address,
-                        token_b_address,
-                        gwei,
-                        config.trade.tswap_sell,
-                    )
-                    .await;
-
-                // wait for last swap transaction finalized
-                thread::sleep(Duration::from_millis(SWAP_DEADLINE as u64));
-
-                // recheck balance
-                let after_selling_a_balance =
-                    erc20::balance_of(provider.clone(), token_a_address, address).await;
-                let after_selling_b_balance =
-                    erc20::balance_of(provider.clone(), token_b_address, address).await;
-
-                //// log
-                println!(
-                    "\nsold {} ({}) and received {} ({})\n",
-                    format_units(
-                        before_selling_a_balance
-                            .checked_sub(after_selling_a_balance)
-                            .unwrap(),
-                        token_a_decimal
-                    )
-                    .unwrap(),
-                    token_a_symbol,
-                    format_units(
-                        after_selling_b_balance
-                            .checked_sub(before_selling_b_balance)
-                            .unwrap(),
-                        token_b_decimal
-                    )
-                    .unwrap(),
-                    token_b_symbol
-                );
-                //// end of log
-
-                if after_selling_b_balance <= before_selling_b_balance {
-                    println!(
-                        "\nnumber of token after purchase does not increase, something is wrong\n"
-                    );
+                .await
+                {
+                    Ok(preflight) => preflight.checks(&config, token_a.decimal),
+                    Err(e) => {
+                        println!("pre-flight fail: {}", e);
+                        continue;
+                    }
+                };
+                if let Err(e) = preflight::report(&checks) {
+                    println!("{}, not trading", e);
                     continue;
                 }
 
-                let token_b_gwei = after_selling_b_balance
-                    .checked_sub(before_selling_b_balance)
-                    .unwrap();
+                println!("setup done, start trading");
 
-                // approve router contract to use token for trading
-                check_allowance(
+                if let Err(e) = cycle::run(
                     provider.clone(),
                     &tswap,
-                    token_b_address,
+                    &config,
                     address,
-                    router_address,
-                    token_b_gwei,
+                    &token_a,
+                    &token_b,
+                    gwei,
                 )
-                .await;
-
-                //// log
-                println!(
-                    "------------ buying stage ({} steps) ------------",
-                    config.trade.tswap_buy
-                );
-                println!(
-                    "sell {} ({}) to buy back {}",
-                    format_units(token_b_gwei, token_b_decimal).unwrap(),
-                    token_b_symbol,
-                    token_a_symbol
-                );
-                //// end of log
-
-                tswap
-                    .tswap(
-                        router_address,
-                        token_b_address,
-                        token_a_address,
-                        token_b_gwei,
-                        config.trade.tswap_buy,
-                    )
-                    .await;
-                // wait for last swap transaction finalized
-                thread::sleep(Duration::from_millis(SWAP_DEADLINE as u64));
-
-                let after_buying_a_balance =
-                    erc20::balance_of(provider.clone(), token_a_address, address).await;
-                let after_buying_b_balance =
-                    erc20::balance_of(provider.clone(), token_b_address, address).await;
-
-                //// log
-                println!(
-                    "\nsold {} ({}) and received {} ({})\n",
-                    format_units(
-                        after_selling_b_balance
-                            .checked_sub(after_buying_b_balance)
-                            .unwrap(),
-                        token_b_decimal
-                    )
-                    .unwrap(),
-                    token_b_symbol,
-                    format_units(
-                        after_buying_a_balance
-                            .checked_sub(after_selling_a_balance)
-                            .unwrap(),
-                        token_a_decimal
-                    )
-                    .unwrap(),
-                    token_a_symbol
-                );
-                //// end of log
-
-                //// log
-                println!("------------ result ------------");
-                println!(
-                    "{}: {}",
-                    token_a_symbol,
-                    if after_buying_a_balance >= before_selling_a_balance {
-                        format!(
-                            "{}{}",
-                            "+",
-                            format_units(
-                                after_buying_a_balance
-                                    .checked_sub(before_selling_a_balance)
-                                    .unwrap(),
-                                token_a_decimal
-                            )
-                            .unwrap()
-                        )
-                    } else {
-                        format!(
-                            "{}{}",
-                            "-",
-                            format_units(
-                                before_selling_a_balance
-                                    .checked_sub(after_buying_a_balance)
-                                    .unwrap(),
-                                token_a_decimal
-                            )
-                            .unwrap()
-                        )
-                    }
-                );
-                println!(
-                    "{}: {}",
-                    token_b_symbol,
-                    if after_buying_b_balance >= before_selling_b_balance {
-                        format!(
-                            "{}{}",
-                            "+",
-                            format_units(
-                                after_buying_b_balance
-                                    .checked_sub(before_selling_b_balance)
-                                    .unwrap(),
-                                token_b_decimal
-                            )
-                            .unwrap()
-                        )
-                    } else {
-                        format!(
-                            "{}{}",
-                            "-",
-                            format_units(
-                                before_selling_b_balance
-                                    .checked_sub(after_buying_b_balance)
-                                    .unwrap(),
-                                token_b_decimal
-                            )
-                            .unwrap()
-                        )
-                    }
-                );
-                //// end of log
+                .await
+                {
+                    println!("trading fail: {}", e);
+                }
             }
             CONVERTING_ACTION => {
                 // log account balance
@@ -385,30 +328,533 @@ async fn main() -> Result<(), ()> {
                     _ => println!("unsupported option"),
                 }
             }
+            WATCHING_ACTION => {
+                print!("Output file (.csv or .jsonl, empty to skip): ");
+                let output: String = try_read!("{}\n").unwrap_or_default();
+                let output = Some(output.trim().to_string()).filter(|o| !o.is_empty());
+
+                if let Err(e) = watch::watch(provider.clone(), &config, output).await {
+                    println!("watch fail: {}", e);
+                }
+            }
+            EXPORTING_ACTION => {
+                let latest_block = provider
+                    .get_block_number()
+                    .await
+                    .map(|b| b.as_u64())
+                    .unwrap_or(0);
+
+                print!("From block: ");
+                let from_block: u64 = try_read!("{}\n").unwrap_or(0);
+                print!("To block (latest {}): ", latest_block);
+                let to_block: u64 = try_read!("{}\n").unwrap_or(latest_block);
+                print!("Output file (history.csv): ");
+                let output: String = try_read!("{}\n").unwrap_or_default();
+                let output = if output.trim().is_empty() {
+                    "history.csv".to_string()
+                } else {
+                    output.trim().to_string()
+                };
+
+                match history::export(provider.clone(), &config, from_block, to_block).await {
+                    Ok(records) => match history::write_csv(&output, &records) {
+                        Ok(()) => println!("exported {} blocks to {}", records.len(), output),
+                        Err(e) => println!("write history fail: {}", e),
+                    },
+                    Err(e) => println!("export fail: {}", e),
+                }
+            }
+            BACKTESTING_ACTION => {
+                print!("History file (history.csv): ");
+                let input: String = try_read!("{}\n").unwrap_or_default();
+                let path = if input.trim().is_empty() {
+                    "history.csv".to_string()
+                } else {
+                    input.trim().to_string()
+                };
+                let records = match history::read_csv(&path) {
+                    Ok(records) => records,
+                    Err(e) => {
+                        println!("read history fail: {}", e);
+                        continue;
+                    }
+                };
+
+                print!("Amount Sell ({}): ", config.pool.token_a_symbol);
+                let eth: String = try_read!("{}\n").unwrap_or("0".to_string());
+                let gwei: U256 = parse_units(eth, config.pool.token_a_decimal)
+                    .unwrap()
+                    .into();
+
+                print!(
+                    "Sell steps, comma separated ({}): ",
+                    config.trade.tswap_sell
+                );
+                let input: String = try_read!("{}\n").unwrap_or_default();
+                let tswap_sells = parse_list(&input, config.trade.tswap_sell);
+                print!("Buy steps, comma separated ({}): ", config.trade.tswap_buy);
+                let input: String = try_read!("{}\n").unwrap_or_default();
+                let tswap_buys = parse_list(&input, config.trade.tswap_buy);
+                print!(
+                    "Step durations, comma separated ({}): ",
+                    config.trade.tswap_step
+                );
+                let input: String = try_read!("{}\n").unwrap_or_default();
+                let tswap_steps = parse_list(&input, config.trade.tswap_step);
+
+                let grid = backtest::Grid {
+                    tswap_sells,
+                    tswap_buys,
+                    tswap_steps,
+                };
+                match backtest::grid_search(
+                    &records,
+                    gwei,
+                    &grid,
+                    config.trade.slippage,
+                    config.pool.token_a_decimal,
+                    config.pool.token_b_decimal,
+                ) {
+                    Ok(reports) => backtest::print_reports(&reports, &config.pool.token_a_symbol),
+                    Err(e) => println!("backtest fail: {}", e),
+                }
+            }
+            PROVIDING_ACTION => {
+                let layout = match pair::layout(provider.clone(), &config.pool).await {
+                    Ok(layout) => layout,
+                    Err(e) => {
+                        println!("read pair fail: {}", e);
+                        continue;
+                    }
+                };
+                let status = liquidity::status(provider.clone(), &layout, address).await;
+                liquidity::print_status(&status, &config.pool);
+
+                let mut tracked = positions::load().unwrap_or_else(|e| {
+                    println!("load positions fail: {}", e);
+                    vec![]
+                });
+
+                print!("\nChoose (1 is add, 2 is remove, 3 is positions, 4 is export positions): ");
+                let lp_action: u32 = try_read!("{}\n").unwrap_or(3);
+
+                match lp_action {
+                    1 => {
+                        print!("Amount Add ({}): ", config.pool.token_a_symbol);
+                        let eth: String = try_read!("{}\n").unwrap_or("0".to_string());
+                        let gwei: U256 = parse_units(eth, config.pool.token_a_decimal)
+                            .unwrap()
+                            .into();
+
+                        let desired =
+                            match liquidity::plan_add(provider.clone(), &config, &layout, gwei)
+                                .await
+                            {
+                                Ok(desired) => desired,
+                                Err(e) => {
+                                    println!("quote fail: {}", e);
+                                    continue;
+                                }
+                            };
+                        println!(
+                            "add {} ({}) - {} ({})",
+                            format_units(desired.amount_a, config.pool.token_a_decimal).unwrap(),
+                            config.pool.token_a_symbol,
+                            format_units(desired.amount_b, config.pool.token_b_decimal).unwrap(),
+                            config.pool.token_b_symbol
+                        );
+
+                        // approve router contract to pull both tokens
+                        check_allowance(
+                            provider.clone(),
+                            &tswap,
+                            token_a_address,
+                            address,
+                            router_address,
+                            desired.amount_a,
+                        )
+                        .await;
+                        check_allowance(
+                            provider.clone(),
+                            &tswap,
+                            token_b_address,
+                            address,
+                            router_address,
+                            desired.amount_b,
+                        )
+                        .await;
+
+                        let receipt =
+                            match liquidity::add(provider.clone(), &config, address, desired).await
+                            {
+                                Ok(receipt) => receipt,
+                                Err(e) => {
+                                    println!("add liquidity fail: {}", e);
+                                    continue;
+                                }
+                            };
+                        match positions::minted(&receipt, &layout, address) {
+                            Ok(minted) => {
+                                println!(
+                                    "added {} ({}) - {} ({}) for {} LP",
+                                    format_units(
+                                        minted.deposited.amount_a,
+                                        config.pool.token_a_decimal
+                                    )
+                                    .unwrap(),
+                                    config.pool.token_a_symbol,
+                                    format_units(
+                                        minted.deposited.amount_b,
+                                        config.pool.token_b_decimal
+                                    )
+                                    .unwrap(),
+                                    config.pool.token_b_symbol,
+                                    format_units(minted.liquidity, liquidity::LP_DECIMAL).unwrap()
+                                );
+                                tracked.push(positions::open(
+                                    layout.pair_address,
+                                    &status,
+                                    &minted,
+                                ));
+                                if let Err(e) = positions::save(&tracked) {
+                                    println!("save positions fail: {}", e);
+                                }
+                            }
+                            Err(e) => println!("read add liquidity receipt fail: {}", e),
+                        }
+                    }
+                    2 => {
+                        print!("Amount Remove (LP): ");
+                        let eth: String = try_read!("{}\n").unwrap_or("0".to_string());
+                        let gwei: U256 = parse_units(eth, liquidity::LP_DECIMAL).unwrap().into();
+                        // no approval spent on a remove bound to fail
+                        if let Err(e) = liquidity::check_remove(&status, gwei) {
+                            println!("remove liquidity fail: {}", e);
+                            continue;
+                        }
+
+                        // approve router contract to burn LP tokens
+                        check_allowance(
+                            provider.clone(),
+                            &tswap,
+                            layout.pair_address,
+                            address,
+                            router_address,
+                            gwei,
+                        )
+                        .await;
+
+                        match liquidity::remove(provider.clone(), &config, &status, address, gwei)
+                            .await
+                        {
+                            Ok(expected) => {
+                                println!(
+                                    "remove liquidity sent, expecting {} ({}) - {} ({})",
+                                    format_units(expected.amount_a, config.pool.token_a_decimal)
+                                        .unwrap(),
+                                    config.pool.token_a_symbol,
+                                    format_units(expected.amount_b, config.pool.token_b_decimal)
+                                        .unwrap(),
+                                    config.pool.token_b_symbol
+                                );
+                                positions::close(&mut tracked, layout.pair_address, gwei);
+                                if let Err(e) = positions::save(&tracked) {
+                                    println!("save positions fail: {}", e);
+                                }
+                            }
+                            Err(e) => println!("remove liquidity fail: {}", e),
+                        }
+                    }
+                    3 | 4 => {
+                        let mut reports: Vec<positions::PositionReport> = vec![];
+                        for position in tracked
+                            .iter()
+                            .filter(|p| p.pair_address == layout.pair_address)
+                        {
+                            let growth = match positions::fee_growth(provider.clone(), position)
+                                .await
+                            {
+                                Ok(growth) => Some(growth),
+                                Err(e) => {
+                                    println!("read Sync history fail ({}), fees from reserves", e);
+                                    None
+                                }
+                            };
+                            reports.push(positions::report(
+                                position,
+                                &status,
+                                &config.pool,
+                                growth,
+                            ));
+                        }
+                        if reports.is_empty() {
+                            println!("no tracked positions");
+                        }
+                        for report in reports.iter() {
+                            positions::print_report(report, &config.pool);
+                        }
+
+                        if lp_action == 4 {
+                            print!("Output file (positions_report.json): ");
+                            let output: String = try_read!("{}\n").unwrap_or_default();
+                            let output = if output.trim().is_empty() {
+                                "positions_report.json".to_string()
+                            } else {
+                                output.trim().to_string()
+                            };
+                            match positions::export(&output, &reports) {
+                                Ok(()) => {
+                                    println!("exported {} positions to {}", reports.len(), output)
+                                }
+                                Err(e) => println!("export positions fail: {}", e),
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            MARKET_MAKING_ACTION => {
+                if let Err(e) = mm::run(provider.clone(), &tswap, &config, address).await {
+                    println!("market making fail: {}", e);
+                }
+            }
+            GRID_ACTION => {
+                if let Err(e) = grid::run(provider.clone(), &tswap, &config, address).await {
+                    println!("grid trading fail: {}", e);
+                }
+            }
+            TWAP_ACTION => {
+                print!("Window (30m): ");
+                let input: String = try_read!("{}\n").unwrap_or_default();
+                let input = if input.trim().is_empty() {
+                    "30m".to_string()
+                } else {
+                    input
+                };
+                let window = match twap::parse_window(&input) {
+                    Ok(window) => window,
+                    Err(e) => {
+                        println!("{}", e);
+                        continue;
+                    }
+                };
+                let layout = match pair::layout(provider.clone(), &config.pool).await {
+                    Ok(layout) => layout,
+                    Err(e) => {
+                        println!("twap fail: {}", e);
+                        continue;
+                    }
+                };
+
+                match twap::twap(provider.clone(), &layout, window).await {
+                    Ok(twap) => {
+                        println!(
+                            "twap over blocks {} -> {} ({} seconds): {} {}/{} ({} {}/{})",
+                            twap.from.block_number,
+                            twap.to.block_number,
+                            twap.to.timestamp - twap.from.timestamp,
+                            twap.price,
+                            config.pool.token_b_symbol,
+                            config.pool.token_a_symbol,
+                            1f64 / twap.price,
+                            config.pool.token_a_symbol,
+                            config.pool.token_b_symbol
+                        );
+                    }
+                    Err(e) => println!("twap fail: {}", e),
+                }
+            }
+            ARB_SCAN_ACTION => {
+                if let Err(e) = arb::scan(provider.clone(), &config, address).await {
+                    println!("arb scan fail: {}", e);
+                }
+            }
+            WALLETS_ACTION => {
+                if sub_wallets.is_empty() {
+                    println!("no SUB_PRIVATE_KEYS in .env");
+                    continue;
+                }
+                print!("\nChoose (1 is balances, 2 is fund, 3 is stage, 4 is sweep): ");
+                let wallet_action: u32 = try_read!("{}\n").unwrap_or(1);
+
+                match wallet_action {
+                    1 => {
+                        let addresses = std::iter::once(address)
+                            .chain(sub_wallets.iter().map(|w| w.address))
+                            .collect::<Vec<_>>();
+                        for account in addresses {
+                            let eth_balance = provider
+                                .get_balance(account, None)
+                                .await
+                                .unwrap_or(U256::zero());
+                            let token_a_balance =
+                                erc20::balance_of(provider.clone(), token_a_address, account).await;
+                            let token_b_balance =
+                                erc20::balance_of(provider.clone(), token_b_address, account).await;
+                            println!(
+                                "{:?}: {} (ETH) - {} ({}) - {} ({})",
+                                account,
+                                format_units(eth_balance, 18).unwrap(),
+                                format_units(token_a_balance, config.pool.token_a_decimal).unwrap(),
+                                config.pool.token_a_symbol,
+                                format_units(token_b_balance, config.pool.token_b_decimal).unwrap(),
+                                config.pool.token_b_symbol
+                            );
+                        }
+                    }
+                    2 => {
+                        print!(
+                            "Choose Asset (1 is ETH, 2 is {}, 3 is {}): ",
+                            config.pool.token_a_symbol, config.pool.token_b_symbol
+                        );
+                        let asset: u32 = try_read!("{}\n").unwrap_or(1);
+                        let (token, decimal) = match asset {
+                            2 => (Some(token_a_address), config.pool.token_a_decimal),
+                            3 => (Some(token_b_address), config.pool.token_b_decimal),
+                            _ => (None, 18),
+                        };
+                        print!("Amount Per Wallet: ");
+                        let eth: String = try_read!("{}\n").unwrap_or("0".to_string());
+                        let gwei: U256 = parse_units(eth, decimal).unwrap().into();
+
+                        let recipients: Vec<Address> =
+                            sub_wallets.iter().map(|w| w.address).collect();
+                        if let Err(e) =
+                            wallets::fund(provider.clone(), &config, token, &recipients, gwei).await
+                        {
+                            println!("fund fail: {}", e);
+                        }
+                    }
+                    3 => {
+                        print!(
+                            "Choose Token (1 is {}, 2 is {}): ",
+                            config.pool.token_a_symbol, config.pool.token_b_symbol
+                        );
+                        let token_id: u32 = try_read!("{}\n").unwrap_or(1);
+                        let (token_in, token_out, decimal_in, decimal_out, max_step) =
+                            if token_id == 1 {
+                                (
+                                    token_a_address,
+                                    token_b_address,
+                                    config.pool.token_a_decimal,
+                                    config.pool.token_b_decimal,
+                                    config.trade.tswap_sell,
+                                )
+                            } else {
+                                (
+                                    token_b_address,
+                                    token_a_address,
+                                    config.pool.token_b_decimal,
+                                    config.pool.token_a_decimal,
+                                    config.trade.tswap_buy,
+                                )
+                            };
+                        print!("Total Amount Sell: ");
+                        let eth: String = try_read!("{}\n").unwrap_or("0".to_string());
+                        let gwei: U256 = parse_units(eth, decimal_in).unwrap().into();
+
+                        println!(
+                            "split over {} wallets, {} steps each",
+                            sub_wallets.len(),
+                            max_step
+                        );
+                        match wallets::stage(
+                            &sub_wallets,
+                            &config,
+                            token_in,
+                            token_out,
+                            gwei,
+                            max_step,
+                        )
+                        .await
+                        {
+                            Ok(stages) => {
+                                let print_fill = |name: String, fill: &wallets::StageFill| {
+                                    println!(
+                                        "{}: {}/{} steps filled, sold {} of {}, received {}",
+                                        name,
+                                        fill.report.filled_steps,
+                                        fill.report.filled_steps + fill.report.failed_steps,
+                                        format_units(fill.sold, decimal_in).unwrap(),
+                                        format_units(fill.amount, decimal_in).unwrap(),
+                                        format_units(fill.received, decimal_out).unwrap()
+                                    );
+                                };
+                                for stage in stages.iter() {
+                                    print_fill(format!("{:?}", stage.address), &stage.fill);
+                                }
+                                print_fill("total".to_string(), &wallets::aggregate(&stages));
+                            }
+                            Err(e) => println!("stage fail: {}", e),
+                        }
+                    }
+                    4 => {
+                        for wallet in sub_wallets.iter() {
+                            if let Err(e) = wallets::sweep(
+                                wallet,
+                                &config,
+                                &[token_a_address, token_b_address],
+                                address,
+                            )
+                            .await
+                            {
+                                println!("sweep {:?} fail: {}", wallet.address, e);
+                            }
+                        }
+                    }
+                    _ => println!("unsupported option"),
+                }
+            }
+            PENDING_ACTION => {
+                let (mined, next) = match tx_supervisor.nonces().await {
+                    Ok(nonces) => nonces,
+                    Err(e) => {
+                        println!("pending fail: {}", e);
+                        continue;
+                    }
+                };
+                println!("Account {}: nonce {} mined, next {}", address, mined, next);
+                let tracked = tx_supervisor.pending().await.unwrap_or_default();
+                for nonce in mined.as_u64()..next.as_u64() {
+                    if !tracked.iter().any(|t| t.nonce == nonce.into()) {
+                        println!("nonce {}: pending, not tracked", nonce);
+                    }
+                }
+                for t in tracked.iter() {
+                    println!(
+                        "nonce {}: {:?} {}sent at block {}, gas price {}, {} resends",
+                        t.nonce,
+                        t.hash,
+                        if t.cancel { "cancel " } else { "" },
+                        t.sent_block,
+                        t.tx.gas_price().unwrap_or_default(),
+                        t.replacements
+                    );
+                }
+
+                print!("Cancel nonce (empty to skip): ");
+                let input: String = try_read!("{}\n").unwrap_or_default();
+                if let Ok(nonce) = input.trim().parse::<u64>() {
+                    match tx_supervisor.cancel(U256::from(nonce)).await {
+                        Ok(hash) => println!("cancel of nonce {} sent: {:?}", nonce, hash),
+                        Err(e) => println!("cancel fail: {}", e),
+                    }
+                }
+            }
             _ => println!("unsupported option"),
         }
     }
 }
 
-async fn check_allowance<M: Middleware + 'static>(
-    provider: Arc<M>,
-    tswap: &Tswap<M>,
-    token_address: Address,
-    owner: Address,
-    spender: Address,
-    expected: U256,
-) {
-    let mut check = false;
-    while erc20::allowance(provider.clone(), token_address, owner, spender).await < expected {
-        if !check {
-            tswap
-                .approve_token(token_address, spender, expected)
-                .await
-                .unwrap();
-
-            check = !check;
-        }
-        thread::sleep(Duration::from_secs(5));
-        println!("prev setup ...");
+/// Parse a comma separated list of numbers, falling back to `default` when empty.
+fn parse_list(input: &str, default: u64) -> Vec<u64> {
+    let values: Vec<u64> = input
+        .split(',')
+        .filter_map(|v| v.trim().parse::<u64>().ok())
+        .collect();
+    if values.is_empty() {
+        vec![default]
+    } else {
+        values
     }
 }
@@ -0,0 +1,302 @@
+use crate::{
+    config::{Config, MarketMakingConfig},
+    dex::DexClient,
+    erc20, pair,
+    tswap::Tswap,
+    utils::{from_float, price, to_float},
+    BASIS_POINT,
+};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use ethers::{providers::Middleware, types::Address};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Wallet holdings valued at the pool price, human units.
+#[derive(Debug, Clone, Copy)]
+pub struct Inventory {
+    pub amount_a: f64,
+    pub amount_b: f64,
+    /// Token A priced in token B.
+    pub price: f64,
+}
+
+impl Inventory {
+    /// Share of token A in the portfolio value, 4 decimals.
+    pub fn ratio(&self) -> f64 {
+        let value_a = self.amount_a * self.price;
+        let total = value_a + self.amount_b;
+        if total == 0f64 {
+            0f64
+        } else {
+            value_a / total * BASIS_POINT as f64
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    Hold,
+    /// Sell this much token A.
+    SellA(f64),
+    /// Spend this much token B on token A.
+    BuyA(f64),
+}
+
+/// Rebalancing state carried between pool checks.
+///
+/// Trades only go back toward `target_ratio`, and only on the profitable side of the
+/// price of our last trade: sell token A above it, buy token A below it.
+#[derive(Debug)]
+pub struct MarketMaker {
+    config: MarketMakingConfig,
+    reference_price: f64,
+    last_trade: Option<i64>,
+    day: NaiveDate,
+    day_volume: f64,
+}
+
+impl MarketMaker {
+    pub fn new(config: MarketMakingConfig, reference_price: f64, now: DateTime<Utc>) -> Self {
+        Self {
+            config,
+            reference_price,
+            last_trade: None,
+            day: now.date_naive(),
+            day_volume: 0f64,
+        }
+    }
+
+    pub fn reference_price(&self) -> f64 {
+        self.reference_price
+    }
+
+    /// Token A volume still allowed today.
+    fn remaining_volume(&self, now: DateTime<Utc>) -> f64 {
+        if now.date_naive() != self.day {
+            self.config.daily_volume_cap
+        } else {
+            (self.config.daily_volume_cap - self.day_volume).max(0f64)
+        }
+    }
+
+    pub fn decide(&self, inventory: &Inventory, now: DateTime<Utc>) -> Action {
+        if inventory.price <= 0f64 {
+            return Action::Hold;
+        }
+        if let Some(last_trade) = self.last_trade {
+            if now.timestamp() - last_trade < self.config.cooldown as i64 {
+                return Action::Hold;
+            }
+        }
+
+        let limit = self.config.max_trade.min(self.remaining_volume(now));
+        if limit <= 0f64 {
+            return Action::Hold;
+        }
+
+        let ratio = inventory.ratio();
+        let target = self.config.target_ratio as f64;
+        let band = self.config.band as f64;
+        let total = inventory.amount_a * inventory.price + inventory.amount_b;
+        let target_value_a = total * target / BASIS_POINT as f64;
+
+        if ratio > target + band && inventory.price >= self.reference_price {
+            let excess_a = inventory.amount_a - target_value_a / inventory.price;
+            Action::SellA(excess_a.min(limit))
+        } else if ratio < target - band && inventory.price <= self.reference_price {
+            let deficit_b = target_value_a - inventory.amount_a * inventory.price;
+            Action::BuyA(deficit_b.min(limit * inventory.price))
+        } else {
+            Action::Hold
+        }
+    }
+
+    /// Account a filled trade against cooldown, volume cap and reference price.
+    pub fn record(&mut self, action: Action, trade_price: f64, now: DateTime<Utc>) {
+        let volume_a = match action {
+            Action::SellA(amount_a) => amount_a,
+            Action::BuyA(amount_b) if trade_price > 0f64 => amount_b / trade_price,
+            _ => return,
+        };
+        if now.date_naive() != self.day {
+            self.day = now.date_naive();
+            self.day_volume = 0f64;
+        }
+        self.day_volume += volume_a;
+        self.last_trade = Some(now.timestamp());
+        self.reference_price = trade_price;
+    }
+}
+
+/// Rebalance the wallet around `mm.target_ratio` until Ctrl+C.
+pub async fn run<M: Middleware + 'static, D: DexClient>(
+    client: Arc<M>,
+    tswap: &Tswap<D>,
+    config: &Config,
+    owner: Address,
+) -> Result<()> {
+    let mm_config = config
+        .mm
+        .clone()
+        .ok_or_else(|| anyhow!("mm is not set in config"))?;
+    tswap.start_run();
+    let layout = pair::layout(client.clone(), &config.pool).await?;
+    let router_address = Address::from_str(&config.pool.router_address)?;
+    let token_a_address = Address::from_str(&config.pool.token_a_address)?;
+    let token_b_address = Address::from_str(&config.pool.token_b_address)?;
+    let (decimal_a, decimal_b) = (config.pool.token_a_decimal, config.pool.token_b_decimal);
+
+    let pool_price = |reserve_0, reserve_1| {
+        let (reserve_a, reserve_b) = layout.split(reserve_0, reserve_1);
+        price(
+            to_float(reserve_a, decimal_a),
+            to_float(reserve_b, decimal_b),
+        )
+    };
+
+    let (reserve_0, reserve_1) = pair::get_reserves(client.clone(), layout.pair_address).await;
+    let mut mm = MarketMaker::new(
+        mm_config.clone(),
+        pool_price(reserve_0, reserve_1),
+        Utc::now(),
+    );
+    println!(
+        "market making around {} {}/{}, press Ctrl+C to stop",
+        mm.reference_price(),
+        config.pool.token_b_symbol,
+        config.pool.token_a_symbol
+    );
+
+    loop {
+        let balance_a = erc20::balance_of(client.clone(), token_a_address, owner).await;
+        let balance_b = erc20::balance_of(client.clone(), token_b_address, owner).await;
+        let (reserve_0, reserve_1) = pair::get_reserves(client.clone(), layout.pair_address).await;
+        let inventory = Inventory {
+            amount_a: to_float(balance_a, decimal_a),
+            amount_b: to_float(balance_b, decimal_b),
+            price: pool_price(reserve_0, reserve_1),
+        };
+
+        let now = Utc::now();
+        let action = mm.decide(&inventory, now);
+        let (token_in, token_out, amount_in) = match action {
+            Action::Hold => (None, None, 0f64),
+            Action::SellA(amount_a) => (Some(token_a_address), Some(token_b_address), amount_a),
+            Action::BuyA(amount_b) => (Some(token_b_address), Some(token_a_address), amount_b),
+        };
+
+        if let (Some(token_in), Some(token_out)) = (token_in, token_out) {
+            let decimal_in = if token_in == token_a_address {
+                decimal_a
+            } else {
+                decimal_b
+            };
+            let amount = from_float(amount_in, decimal_in);
+            println!(
+                "price {}, ratio {:.0} -> {:?}",
+                inventory.price,
+                inventory.ratio(),
+                action
+            );
+
+            if erc20::allowance(client.clone(), token_in, owner, router_address).await < amount {
+                if let Err(e) = tswap.approve_token(token_in, router_address, amount).await {
+                    println!("approve fail: {}", e);
+                }
+            }
+            let before = if token_in == token_a_address {
+                balance_a
+            } else {
+                balance_b
+            };
+            let report = tswap.tswap(token_in, token_out, amount, 1).await;
+            // a sent swap only counts against cooldown and volume once mined
+            let sent = amount.saturating_sub(report.unsold);
+            if report.filled_steps > 0 && tswap.confirm_spent(token_in, before, sent).await {
+                mm.record(action, inventory.price, now);
+            } else if report.filled_steps > 0 {
+                println!("{:?} not mined before the deadline", action);
+            }
+        }
+
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            _ = tokio::time::sleep(Duration::from_secs(mm_config.interval)) => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> MarketMakingConfig {
+        MarketMakingConfig {
+            target_ratio: 5000,
+            band: 200,
+            max_trade: 10f64,
+            cooldown: 60,
+            daily_volume_cap: 15f64,
+            interval: 1,
+        }
+    }
+
+    fn at(timestamp: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(timestamp, 0).unwrap()
+    }
+
+    #[test]
+    fn sells_token_a_above_band_and_reference() {
+        let mm = MarketMaker::new(config(), 1f64, at(0));
+        // 100 A at 1.2 = 120 vs 100 B
+        let inventory = Inventory {
+            amount_a: 100f64,
+            amount_b: 100f64,
+            price: 1.2f64,
+        };
+        match mm.decide(&inventory, at(0)) {
+            Action::SellA(amount) => assert!((amount - 8.333333).abs() < 1e-5),
+            action => panic!("unexpected {:?}", action),
+        }
+    }
+
+    #[test]
+    fn holds_when_price_is_on_the_wrong_side_of_reference() {
+        let mm = MarketMaker::new(config(), 1.3f64, at(0));
+        let inventory = Inventory {
+            amount_a: 100f64,
+            amount_b: 100f64,
+            price: 1.2f64,
+        };
+        assert_eq!(mm.decide(&inventory, at(0)), Action::Hold);
+    }
+
+    #[test]
+    fn respects_cooldown_and_daily_volume() {
+        let mut mm = MarketMaker::new(config(), 1f64, at(0));
+        let inventory = Inventory {
+            amount_a: 50f64,
+            amount_b: 200f64,
+            price: 0.8f64,
+        };
+        // buy capped by max_trade (10 A at 0.8)
+        assert_eq!(mm.decide(&inventory, at(0)), Action::BuyA(8f64));
+        mm.record(Action::BuyA(8f64), 0.8f64, at(0));
+
+        assert_eq!(mm.decide(&inventory, at(30)), Action::Hold);
+
+        // cooldown over, 5 A of the daily cap left, below the new reference price
+        let cheaper = Inventory {
+            price: 0.5f64,
+            ..inventory
+        };
+        assert_eq!(mm.decide(&cheaper, at(120)), Action::BuyA(2.5f64));
+
+        // next UTC day resets the cap
+        assert_eq!(mm.decide(&cheaper, at(86400)), Action::BuyA(5f64));
+    }
+}
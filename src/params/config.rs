@@ -6,6 +6,12 @@ const CONFIG_PATH: &str = "./config.json";
 #[derive(Debug, Deserialize, Clone)]
 pub struct PoolConfig {
     pub router_address: String,
+    /// Token A / token B pair, checked against the router's factory, discovered when missing.
+    pub pair_address: Option<String>,
+    /// Factory of the router, read from `router.factory()` when missing.
+    pub factory_address: Option<String>,
+    /// Pair init code hash of the factory, to compute the pair address offline.
+    pub init_code_hash: Option<String>,
     pub weth_address: String,
     pub token_a_address: String,
     pub token_a_symbol: String,
@@ -21,6 +27,73 @@ pub struct TradeConfig {
     pub tswap_sell: u64,
     pub tswap_step: u64,
     pub slippage: u64,
+    /// Randomize step sizes and intervals, equal steps every `tswap_step` when missing.
+    pub jitter: Option<JitterConfig>,
+    /// Size steps by the pool's recent volume instead of splitting the stage evenly.
+    pub vwap: Option<VwapConfig>,
+    /// Tighten slippage or shrink the step when a sandwich could extract too much of it.
+    pub sandwich: Option<SandwichConfig>,
+    /// Limits on what swaps of this run may spend on gas, unlimited when missing.
+    pub gas_budget: Option<GasBudgetConfig>,
+    /// Halt a round trip once its projected token A loss reaches this share of the starting
+    /// token A balance, 4 decimals.
+    pub max_round_trip_loss: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct JitterConfig {
+    /// Max deviation of a step size from the even split, 4 decimals, below 10000.
+    pub size: u64,
+    /// Max deviation of a step interval from `tswap_step`, 4 decimals.
+    pub timing: u64,
+    /// Seed for a reproducible schedule, random when missing.
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct VwapConfig {
+    /// Largest share of the volume others traded since the previous step, 4 decimals.
+    pub participation: u64,
+    /// Stage deadline in second, the remainder is swapped at once when it passes.
+    pub duration: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct SandwichConfig {
+    /// Largest share of a step an optimal front-run and back-run may extract, 4 decimals.
+    pub max_extractable: u64,
+    /// Highest `amount_out_min` allowed when tightening, share of the quote, 4 decimals.
+    pub tightest_slippage: u64,
+}
+
+/// What a stage does with a step breaking the gas price or gas share limit.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum GasAction {
+    /// Wait every `tswap_step` seconds until gas is back under the limits.
+    Delay,
+    /// Add the step to the next one, delay the last step.
+    Merge,
+    /// Stop the stage.
+    Abort,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct GasBudgetConfig {
+    /// Most ETH spent on swap gas over the run, the stage aborts before going above.
+    ///
+    /// Spending is an estimate, `swap_gas` at the gas price of every sent swap, as swaps are
+    /// not waited on for their receipts.
+    pub max_total: Option<f64>,
+    /// Highest gas price paid in gwei, after `gas_price_multipler`.
+    pub max_gas_price: Option<f64>,
+    /// Highest gas cost of a step as a share of its value in ETH, 4 decimals.
+    pub max_gas_share: Option<u64>,
+    /// Gas used by one swap, to cost a step before sending it.
+    pub swap_gas: u64,
+    pub on_exceed: GasAction,
+    /// Seconds a step may wait for gas to drop before the stage aborts.
+    pub max_delay: u64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -30,6 +103,136 @@ pub struct NetworkConfig {
     /// Network rpc.
     pub rpc: String,
     pub gas_price_multipler: u64,
+    /// Multicall3 deployment, the canonical address when missing.
+    pub multicall_address: Option<String>,
+    /// Send swaps to a private relay instead of the public mempool.
+    pub relay: Option<RelayConfig>,
+    /// Resend or cancel transactions that stay unmined, manual `cancel` only when missing.
+    pub supervisor: Option<SupervisorConfig>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct SupervisorConfig {
+    /// Blocks without inclusion before a transaction counts as stuck.
+    pub stuck_blocks: u64,
+    /// Gas price increase of a resend, 4 decimals, raised to the 10% nodes require.
+    pub gas_bump: u64,
+    /// Resends before giving up and cancelling with a self-transfer.
+    pub max_replacements: u64,
+    /// Seconds between two checks.
+    pub interval: u64,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RelayMethod {
+    /// `eth_sendBundle` of the single transaction, resubmitted for every block.
+    Bundle,
+    /// `eth_sendPrivateTransaction`, submitted once.
+    Private,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RelayConfig {
+    pub url: String,
+    pub method: RelayMethod,
+    /// Blocks to wait for inclusion before broadcasting publicly.
+    pub fallback_blocks: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MarketMakingConfig {
+    /// Target share of token A in the portfolio value, 4 decimals.
+    pub target_ratio: u64,
+    /// Allowed deviation from `target_ratio` before trading, 4 decimals.
+    pub band: u64,
+    /// Largest single trade, token A.
+    pub max_trade: f64,
+    /// Minimum seconds between two trades.
+    pub cooldown: u64,
+    /// Largest volume traded per UTC day, token A.
+    pub daily_volume_cap: f64,
+    /// Seconds between two pool checks.
+    pub interval: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct GridConfig {
+    /// Lowest grid level, token A priced in token B.
+    pub lower_price: f64,
+    /// Highest grid level, token A priced in token B.
+    pub upper_price: f64,
+    /// Number of evenly spaced levels, bounds included.
+    pub levels: u64,
+    /// Token A bought or sold per crossed level.
+    pub tranche: f64,
+    /// Seconds between two pool checks.
+    pub interval: u64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct CycleConfig {
+    /// Round trips to run, 0 to repeat until a stop condition hits.
+    pub max_cycles: u64,
+    /// Shortest pause between two cycles in second.
+    pub min_pause: u64,
+    /// Longest pause between two cycles in second, equal to `min_pause` for a fixed cadence.
+    pub max_pause: u64,
+    /// Stop once the cumulative loss reaches this much of the sold token.
+    pub max_loss: Option<f64>,
+    /// Stop once this much ETH was spent on gas.
+    pub max_gas: Option<f64>,
+}
+
+/// Where the reference price for the guard comes from.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "source", rename_all = "lowercase")]
+pub enum ReferenceSource {
+    /// The same token A / token B pair on another V2 pool.
+    Pool { pair_address: String },
+    /// Chainlink-style aggregator answering token A priced in token B.
+    Chainlink {
+        aggregator_address: String,
+        /// Set when the feed answers token B priced in token A.
+        invert: Option<bool>,
+    },
+    /// Time-weighted average price of the traded pair over `window` seconds.
+    Twap { window: u64 },
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct GuardConfig {
+    pub reference: ReferenceSource,
+    /// Pause steps while the pool price is further than this from the reference, 4 decimals.
+    pub max_deviation: u64,
+}
+
+/// Another V2-compatible deployment holding the token A / token B pair.
+#[derive(Debug, Deserialize, Clone)]
+pub struct VenueConfig {
+    pub name: String,
+    pub router_address: String,
+    /// Discovered from the router's factory when missing.
+    pub pair_address: Option<String>,
+    /// Factory of the venue's router, read from `router.factory()` when missing.
+    pub factory_address: Option<String>,
+    /// Pair init code hash of the venue's factory, to compute the pair address offline.
+    pub init_code_hash: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ArbConfig {
+    pub venues: Vec<VenueConfig>,
+    /// Smallest profit after gas worth reporting, token B.
+    pub min_profit: f64,
+    /// Token B per ETH, to value gas in token B.
+    pub eth_price: f64,
+    /// Gas used by one swap.
+    pub swap_gas: u64,
+    /// Seconds between two scans.
+    pub interval: u64,
+    /// Send both swaps when an opportunity shows up, report only otherwise.
+    pub execute: bool,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -37,6 +240,11 @@ pub struct Config {
     pub pool: PoolConfig,
     pub trade: TradeConfig,
     pub network: NetworkConfig,
+    pub mm: Option<MarketMakingConfig>,
+    pub grid: Option<GridConfig>,
+    pub cycle: Option<CycleConfig>,
+    pub guard: Option<GuardConfig>,
+    pub arb: Option<ArbConfig>,
 }
 
 pub fn load_config() -> Result<Config, ConfigFileError> {
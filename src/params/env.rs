@@ -4,13 +4,26 @@ use std::env;
 #[derive(Debug, Clone)]
 pub struct EnvVars {
     pub private_key: String,
+    /// Sub-wallets trading a stage in parallel, from the comma separated `SUB_PRIVATE_KEYS`.
+    pub sub_private_keys: Vec<String>,
+    /// Key signing private relay requests from the optional `RELAY_AUTH_KEY`.
+    pub relay_auth_key: Option<String>,
 }
 
 pub fn load_env() -> Result<EnvVars, String> {
     dotenv().ok(); // Reads the .env file
 
     let private_key = env::var("PRIVATE_KEY");
+    let sub_private_keys = env::var("SUB_PRIVATE_KEYS").unwrap_or_default();
     Ok(EnvVars {
         private_key: private_key.map_err(|e| format!("Error PRIVATE_KEY: {}", e))?,
+        sub_private_keys: sub_private_keys
+            .split(',')
+            .map(|key| key.trim().to_string())
+            .filter(|key| !key.is_empty())
+            .collect(),
+        relay_auth_key: env::var("RELAY_AUTH_KEY")
+            .ok()
+            .filter(|key| !key.trim().is_empty()),
     })
 }
@@ -0,0 +1,538 @@
+use crate::{
+    config::PoolConfig,
+    history::LogChunks,
+    liquidity::{LpStatus, LP_DECIMAL},
+    pair::PairLayout,
+    router::LiquidityAmounts,
+    utils::{price, to_float},
+    BASIS_POINT,
+};
+use anyhow::{anyhow, Result};
+use bindings_uniswapv2::uniswap_v2_pair::{
+    BurnFilter, MintFilter, SyncFilter, UniswapV2PairEvents,
+};
+use chrono::Utc;
+use ethers::{
+    contract::{parse_log, EthEvent, LogMeta},
+    providers::Middleware,
+    types::{Address, Filter, TransactionReceipt, H256, U256},
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+const POSITIONS_PATH: &str = "./positions.json";
+
+/// LP tokens the pair locks away on its first mint.
+const MINIMUM_LIQUIDITY: u64 = 1000u64;
+
+/// Liquidity we added, with the pool state it entered at.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Position {
+    pub pair_address: Address,
+    /// Unix seconds.
+    pub opened_at: i64,
+    /// LP tokens still held for this position.
+    pub liquidity: U256,
+    /// Amounts deposited for `liquidity`, scaled down on partial removal.
+    pub deposited_a: U256,
+    pub deposited_b: U256,
+    /// Reserves right after the deposit.
+    pub entry_reserve_a: U256,
+    pub entry_reserve_b: U256,
+    /// Total supply right after the deposit.
+    pub entry_supply: U256,
+    /// Block the deposit was mined in, 0 for positions recorded before it was tracked.
+    #[serde(default)]
+    pub entry_block: u64,
+}
+
+/// What an `addLiquidity` receipt shows the pair took and minted to us.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Minted {
+    /// `Mint` event amounts.
+    pub deposited: LiquidityAmounts,
+    /// LP `Transfer` from the zero address to us.
+    pub liquidity: U256,
+    /// `Sync` reserves after the deposit.
+    pub reserve_a: U256,
+    pub reserve_b: U256,
+    pub block_number: u64,
+}
+
+/// Position valued at the current pool state, values in token B.
+#[derive(Debug, Clone, Serialize)]
+pub struct PositionReport {
+    pub pair_address: Address,
+    pub opened_at: i64,
+    pub liquidity: f64,
+    pub entry_price: f64,
+    pub current_price: f64,
+    pub underlying_a: f64,
+    pub underlying_b: f64,
+    /// Value of the deposited amounts had we kept them.
+    pub hodl_value: f64,
+    pub lp_value: f64,
+    /// LP value without fees minus HODL value, negative is a loss.
+    pub impermanent_loss: f64,
+    pub impermanent_loss_bps: f64,
+    pub fee_income: f64,
+    pub fee_income_bps: f64,
+}
+
+pub fn load() -> Result<Vec<Position>> {
+    if !Path::new(POSITIONS_PATH).exists() {
+        return Ok(vec![]);
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(POSITIONS_PATH)?)?)
+}
+
+pub fn save(positions: &[Position]) -> Result<()> {
+    fs::write(POSITIONS_PATH, serde_json::to_string_pretty(positions)?)?;
+    Ok(())
+}
+
+/// Read the pair's `Mint`, LP `Transfer` to `owner` and `Sync` logs of an `addLiquidity`
+/// receipt.
+pub fn minted(receipt: &TransactionReceipt, layout: &PairLayout, owner: Address) -> Result<Minted> {
+    let (mut deposited, mut liquidity, mut reserves) = (None, None, None);
+    for log in receipt
+        .logs
+        .iter()
+        .filter(|log| log.address == layout.pair_address)
+    {
+        match parse_log::<UniswapV2PairEvents>(log.clone()) {
+            Ok(UniswapV2PairEvents::MintFilter(mint)) => {
+                let (amount_a, amount_b) = layout.split(mint.amount_0, mint.amount_1);
+                deposited = Some(LiquidityAmounts { amount_a, amount_b });
+            }
+            Ok(UniswapV2PairEvents::TransferFilter(transfer))
+                if transfer.from.is_zero() && transfer.to == owner =>
+            {
+                liquidity = Some(transfer.value);
+            }
+            Ok(UniswapV2PairEvents::SyncFilter(sync)) => {
+                reserves =
+                    Some(layout.split(U256::from(sync.reserve_0), U256::from(sync.reserve_1)));
+            }
+            _ => {}
+        }
+    }
+
+    let hash = receipt.transaction_hash;
+    let (reserve_a, reserve_b) = reserves.ok_or_else(|| anyhow!("no Sync in {:?}", hash))?;
+    Ok(Minted {
+        deposited: deposited.ok_or_else(|| anyhow!("no Mint in {:?}", hash))?,
+        liquidity: liquidity.ok_or_else(|| anyhow!("no LP minted to us in {:?}", hash))?,
+        reserve_a,
+        reserve_b,
+        block_number: receipt.block_number.unwrap_or_default().as_u64(),
+    })
+}
+
+/// Record `minted` liquidity, `entry` being the pool state right before the deposit.
+pub fn open(pair_address: Address, entry: &LpStatus, minted: &Minted) -> Position {
+    let locked = if entry.total_supply.is_zero() {
+        U256::from(MINIMUM_LIQUIDITY)
+    } else {
+        U256::zero()
+    };
+    Position {
+        pair_address,
+        opened_at: Utc::now().timestamp(),
+        liquidity: minted.liquidity,
+        deposited_a: minted.deposited.amount_a,
+        deposited_b: minted.deposited.amount_b,
+        entry_reserve_a: minted.reserve_a,
+        entry_reserve_b: minted.reserve_b,
+        // the pending protocol fee is minted along with our deposit
+        entry_supply: entry.effective_supply() + locked + minted.liquidity,
+        entry_block: minted.block_number,
+    }
+}
+
+fn root_k(reserve_0: U256, reserve_1: U256) -> f64 {
+    to_float((reserve_0 * reserve_1).integer_sqrt(), 0)
+}
+
+/// `sqrt(k)` growth from swaps over `events`, the pair's `Sync`, `Mint` and `Burn` logs in
+/// order after entering at `entry_reserves`.
+///
+/// Syncs of a transaction that also mints or burns change `k` by moving liquidity, not by
+/// collecting fees, so they only move the baseline.
+pub fn swap_growth(entry_reserves: (U256, U256), events: &[(UniswapV2PairEvents, LogMeta)]) -> f64 {
+    let liquidity_txs: Vec<H256> = events
+        .iter()
+        .filter(|(event, _)| {
+            matches!(
+                event,
+                UniswapV2PairEvents::MintFilter(_) | UniswapV2PairEvents::BurnFilter(_)
+            )
+        })
+        .map(|(_, meta)| meta.transaction_hash)
+        .collect();
+
+    let mut last = root_k(entry_reserves.0, entry_reserves.1);
+    let mut growth = 1f64;
+    for (event, meta) in events {
+        if let UniswapV2PairEvents::SyncFilter(sync) = event {
+            let current = root_k(U256::from(sync.reserve_0), U256::from(sync.reserve_1));
+            if last > 0f64 && !liquidity_txs.contains(&meta.transaction_hash) {
+                growth *= current / last;
+            }
+            last = current;
+        }
+    }
+    growth
+}
+
+/// `swap_growth` of `position` from the pair's logs since its entry block.
+pub async fn fee_growth<M: Middleware + 'static>(
+    client: Arc<M>,
+    position: &Position,
+) -> Result<f64> {
+    if position.entry_block == 0 {
+        return Err(anyhow!("position has no entry block"));
+    }
+    let to_block = client
+        .get_block_number()
+        .await
+        .map_err(|e| anyhow!("get block number fail: {}", e))?
+        .as_u64();
+    let filter = Filter::new().address(position.pair_address).topic0(vec![
+        SyncFilter::signature(),
+        MintFilter::signature(),
+        BurnFilter::signature(),
+    ]);
+
+    let mut events = vec![];
+    let mut chunks = LogChunks::new(filter, position.entry_block + 1, to_block);
+    while let Some(chunk) = chunks.next(client.as_ref()).await {
+        events.extend(chunk?.2);
+    }
+    Ok(swap_growth(
+        (position.entry_reserve_a, position.entry_reserve_b),
+        &events,
+    ))
+}
+
+/// Take `liquidity` out of the oldest positions first, scaling their deposits down.
+pub fn close(positions: &mut Vec<Position>, pair_address: Address, liquidity: U256) {
+    let mut remaining = liquidity;
+    for position in positions
+        .iter_mut()
+        .filter(|p| p.pair_address == pair_address)
+    {
+        if remaining.is_zero() {
+            break;
+        }
+        let taken = remaining.min(position.liquidity);
+        let left = position.liquidity - taken;
+        position.deposited_a = position.deposited_a * left / position.liquidity;
+        position.deposited_b = position.deposited_b * left / position.liquidity;
+        position.liquidity = left;
+        remaining -= taken;
+    }
+    positions.retain(|p| !p.liquidity.is_zero());
+}
+
+/// Split the position's value change into impermanent loss and fee income.
+///
+/// Fees grow `sqrt(k)` per LP token while price moves alone do not, so the LP value
+/// divided by that growth is what the position would be worth without fees. The growth is
+/// taken from the `Sync` history when `swap_growth` is known, from the entry and current
+/// reserves per LP token otherwise.
+pub fn report(
+    position: &Position,
+    current: &LpStatus,
+    pool: &PoolConfig,
+    swap_growth: Option<f64>,
+) -> PositionReport {
+    let (decimal_a, decimal_b) = (pool.token_a_decimal, pool.token_b_decimal);
+
+    let entry_a = to_float(position.entry_reserve_a, decimal_a);
+    let entry_b = to_float(position.entry_reserve_b, decimal_b);
+    let entry_supply = to_float(position.entry_supply, LP_DECIMAL);
+    let reserve_a = to_float(current.reserve_a, decimal_a);
+    let reserve_b = to_float(current.reserve_b, decimal_b);
+    let supply = to_float(current.effective_supply(), LP_DECIMAL);
+    let current_price = price(reserve_a, reserve_b);
+
+    let underlying = current.underlying(position.liquidity);
+    let underlying_a = to_float(underlying.amount_a, decimal_a);
+    let underlying_b = to_float(underlying.amount_b, decimal_b);
+
+    let hodl_value = to_float(position.deposited_a, decimal_a) * current_price
+        + to_float(position.deposited_b, decimal_b);
+    let lp_value = underlying_a * current_price + underlying_b;
+
+    let entry_root_k = (entry_a * entry_b).sqrt() / entry_supply;
+    let root_k = (reserve_a * reserve_b).sqrt() / supply;
+    let growth = match swap_growth {
+        // with the protocol fee on (`k_last` set) 1/6 of the growth is minted away from LPs
+        Some(growth) if !current.k_last.is_zero() => (5f64 * growth + 1f64) / 6f64,
+        Some(growth) => growth,
+        None if entry_root_k > 0f64 && root_k > 0f64 => root_k / entry_root_k,
+        None => 1f64,
+    };
+    let value_without_fees = lp_value / growth;
+
+    let bps = |value: f64| {
+        if hodl_value == 0f64 {
+            0f64
+        } else {
+            value / hodl_value * BASIS_POINT as f64
+        }
+    };
+    let impermanent_loss = value_without_fees - hodl_value;
+    let fee_income = lp_value - value_without_fees;
+
+    PositionReport {
+        pair_address: position.pair_address,
+        opened_at: position.opened_at,
+        liquidity: to_float(position.liquidity, LP_DECIMAL),
+        entry_price: price(entry_a, entry_b),
+        current_price,
+        underlying_a,
+        underlying_b,
+        hodl_value,
+        lp_value,
+        impermanent_loss,
+        impermanent_loss_bps: bps(impermanent_loss),
+        fee_income,
+        fee_income_bps: bps(fee_income),
+    }
+}
+
+pub fn print_report(report: &PositionReport, pool: &PoolConfig) {
+    println!(
+        "position opened {} ({} LP), price {} -> {}",
+        chrono::DateTime::from_timestamp(report.opened_at, 0)
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_default(),
+        report.liquidity,
+        report.entry_price,
+        report.current_price
+    );
+    println!(
+        "    underlying {} ({}) - {} ({}), value {} vs hodl {} ({})",
+        report.underlying_a,
+        pool.token_a_symbol,
+        report.underlying_b,
+        pool.token_b_symbol,
+        report.lp_value,
+        report.hodl_value,
+        pool.token_b_symbol
+    );
+    println!(
+        "    impermanent loss {} ({:.2} bps), fees {} ({:.2} bps)",
+        report.impermanent_loss,
+        report.impermanent_loss_bps,
+        report.fee_income,
+        report.fee_income_bps
+    );
+}
+
+pub fn export(path: &str, reports: &[PositionReport]) -> Result<()> {
+    fs::write(path, serde_json::to_string_pretty(reports)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bindings_uniswapv2::uniswap_v2_pair::TransferFilter;
+    use ethers::abi::{encode, Token};
+    use ethers::types::Log;
+
+    fn pool() -> PoolConfig {
+        PoolConfig {
+            router_address: String::new(),
+            pair_address: None,
+            factory_address: None,
+            init_code_hash: None,
+            weth_address: String::new(),
+            token_a_address: String::new(),
+            token_a_symbol: "TKA".to_string(),
+            token_a_decimal: 18,
+            token_b_address: String::new(),
+            token_b_symbol: "TKB".to_string(),
+            token_b_decimal: 18,
+        }
+    }
+
+    fn status(reserve_a: u64, reserve_b: u64, total_supply: u64) -> LpStatus {
+        LpStatus {
+            lp_balance: U256::zero(),
+            total_supply: U256::exp10(18) * total_supply,
+            reserve_a: U256::exp10(18) * reserve_a,
+            reserve_b: U256::exp10(18) * reserve_b,
+            k_last: U256::zero(),
+            amount_a: U256::zero(),
+            amount_b: U256::zero(),
+        }
+    }
+
+    /// Deposit of `amount_a` / `amount_b` minted at `entry`.
+    fn deposit(entry: &LpStatus, amount_a: u64, amount_b: u64) -> Minted {
+        let deposited = LiquidityAmounts {
+            amount_a: U256::exp10(18) * amount_a,
+            amount_b: U256::exp10(18) * amount_b,
+        };
+        Minted {
+            deposited,
+            liquidity: entry.minted(deposited),
+            reserve_a: entry.reserve_a + deposited.amount_a,
+            reserve_b: entry.reserve_b + deposited.amount_b,
+            block_number: 1,
+        }
+    }
+
+    fn meta(transaction: u64) -> LogMeta {
+        LogMeta {
+            address: Address::zero(),
+            block_number: 2u64.into(),
+            block_hash: H256::zero(),
+            transaction_hash: H256::from_low_u64_be(transaction),
+            transaction_index: 0u64.into(),
+            log_index: U256::zero(),
+        }
+    }
+
+    fn sync(transaction: u64, reserve_0: u64, reserve_1: u64) -> (UniswapV2PairEvents, LogMeta) {
+        let event = UniswapV2PairEvents::SyncFilter(SyncFilter {
+            reserve_0: u128::from(reserve_0) * 10u128.pow(18),
+            reserve_1: u128::from(reserve_1) * 10u128.pow(18),
+        });
+        (event, meta(transaction))
+    }
+
+    #[test]
+    fn price_move_without_fees_is_pure_impermanent_loss() {
+        let entry = status(900, 900, 900);
+        let position = open(Address::zero(), &entry, &deposit(&entry, 100, 100));
+        assert_eq!(position.liquidity, U256::exp10(18) * 100);
+
+        // price of A x4 along the same k: 500 * 2000
+        let current = status(500, 2000, 1000);
+        let report = report(&position, &current, &pool(), None);
+
+        // 2 * sqrt(4) / (1 + 4) - 1 = -20%
+        assert!((report.impermanent_loss_bps + 2000f64).abs() < 1e-6);
+        assert!(report.fee_income.abs() < 1e-9);
+    }
+
+    #[test]
+    fn k_growth_at_same_price_is_fee_income() {
+        let entry = status(900, 900, 900);
+        let position = open(Address::zero(), &entry, &deposit(&entry, 100, 100));
+
+        let current = status(1210, 1210, 1100);
+        let report = report(&position, &current, &pool(), None);
+
+        assert!(report.impermanent_loss.abs() < 1e-9);
+        assert!((report.fee_income_bps - 1000f64).abs() < 1e-6);
+
+        // the same growth read from the Sync history
+        let growth = swap_growth(
+            (position.entry_reserve_a, position.entry_reserve_b),
+            &[sync(1, 1210, 1210)],
+        );
+        assert!((growth - 1.21f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn liquidity_syncs_do_not_count_as_fees() {
+        let e18 = U256::exp10(18);
+        let entry = (e18 * 1000, e18 * 1000);
+        let mint = UniswapV2PairEvents::MintFilter(MintFilter::default());
+        let events = vec![
+            // a swap paying fees
+            sync(1, 1100, 1100),
+            // someone else adds liquidity
+            sync(2, 2200, 2200),
+            (mint, meta(2)),
+            // another swap
+            sync(3, 2420, 2420),
+        ];
+        let growth = swap_growth(entry, &events);
+        // 1100 / 1000 * 2420 / 2200
+        assert!((growth - 1.21f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn entry_is_read_from_the_receipt() {
+        let (pair_address, owner) = (Address::repeat_byte(1), Address::repeat_byte(2));
+        let layout = PairLayout {
+            pair_address,
+            token_a_is_0: false,
+            token_a_decimal: 18,
+            token_b_decimal: 18,
+        };
+        let log = |topics: Vec<H256>, values: &[u64]| Log {
+            address: pair_address,
+            topics,
+            data: encode(
+                &values
+                    .iter()
+                    .map(|v| Token::Uint((*v).into()))
+                    .collect::<Vec<_>>(),
+            )
+            .into(),
+            ..Default::default()
+        };
+        let receipt = TransactionReceipt {
+            block_number: Some(7u64.into()),
+            logs: vec![
+                // protocol fee minted to the fee receiver first
+                log(
+                    vec![
+                        TransferFilter::signature(),
+                        H256::zero(),
+                        H256::from(Address::repeat_byte(3)),
+                    ],
+                    &[5],
+                ),
+                log(
+                    vec![TransferFilter::signature(), H256::zero(), H256::from(owner)],
+                    &[90],
+                ),
+                log(vec![SyncFilter::signature()], &[1100, 2200]),
+                log(
+                    vec![MintFilter::signature(), H256::from(owner)],
+                    &[100, 200],
+                ),
+            ],
+            ..Default::default()
+        };
+
+        let minted = minted(&receipt, &layout, owner).unwrap();
+        // token A is token1
+        assert_eq!(minted.deposited.amount_a, U256::from(200u64));
+        assert_eq!(minted.deposited.amount_b, U256::from(100u64));
+        assert_eq!(minted.liquidity, U256::from(90u64));
+        assert_eq!(
+            (minted.reserve_a, minted.reserve_b),
+            (U256::from(2200u64), U256::from(1100u64))
+        );
+        assert_eq!(minted.block_number, 7);
+
+        assert!(super::minted(&TransactionReceipt::default(), &layout, owner).is_err());
+    }
+
+    #[test]
+    fn close_takes_oldest_liquidity_first() {
+        let entry = status(1000, 1000, 1000);
+        let mut positions = vec![
+            open(Address::zero(), &entry, &deposit(&entry, 100, 100)),
+            open(Address::zero(), &entry, &deposit(&entry, 50, 50)),
+        ];
+
+        close(&mut positions, Address::zero(), U256::exp10(18) * 120);
+
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].liquidity, U256::exp10(18) * 30);
+        assert_eq!(positions[0].deposited_a, U256::exp10(18) * 30);
+    }
+}
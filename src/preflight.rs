@@ -0,0 +1,314 @@
+use crate::{
+    amm::get_amount_out,
+    config::Config,
+    dex::router::{DEFAULT_APPROVE_CALL_GAS, DEFAULT_SWAP_CALL_GAS},
+    erc20, pair,
+    tswap::step_amount,
+    utils::{gas_price, to_float},
+    BASIS_POINT, GAS_MULTIPLIER,
+};
+use anyhow::{anyhow, Result};
+use ethers::{
+    providers::Middleware,
+    types::{Address, U256},
+};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// What a round trip selling `amount` of the sold token needs, and what the wallet and pool
+/// hold, read before trading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Preflight {
+    pub amount: U256,
+    pub balance: U256,
+    pub eth_balance: U256,
+    /// Node gas price scaled by `gas_price_multipler`.
+    pub gas_price: U256,
+    /// Swaps of the selling and the buying stage over every cycle, of one cycle when they
+    /// repeat until a stop condition.
+    pub swaps: u64,
+    /// Router approvals still missing.
+    pub approvals: u64,
+    /// Gas limit of one swap.
+    pub swap_gas: u64,
+    pub reserve_in: U256,
+    pub reserve_out: U256,
+    /// Steps of the selling stage.
+    pub sell_steps: u64,
+    /// Steps of the buying stage.
+    pub buy_steps: u64,
+}
+
+/// Outcome of a simulated stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StageSim {
+    pub received: U256,
+    /// Shortfall against the spot value in basis points.
+    pub shortfall: u64,
+    /// Pool reserves once the stage is done.
+    pub reserve_in: U256,
+    pub reserve_out: U256,
+}
+
+/// One line of the pre-flight breakdown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub name: &'static str,
+    pub passed: bool,
+    pub detail: String,
+}
+
+/// Selling `amount` in `steps` even steps one after the other.
+pub fn simulate_stage(
+    amount: U256,
+    steps: u64,
+    mut reserve_in: U256,
+    mut reserve_out: U256,
+) -> StageSim {
+    if reserve_in.is_zero() || reserve_out.is_zero() {
+        return StageSim {
+            received: U256::zero(),
+            shortfall: BASIS_POINT,
+            reserve_in,
+            reserve_out,
+        };
+    }
+    let spot_value = amount * reserve_out / reserve_in;
+    let steps = steps.max(1);
+    let mut received = U256::zero();
+    for step_count in 1..=steps {
+        let step = step_amount(amount, steps, step_count);
+        let out = get_amount_out(step, reserve_in, reserve_out);
+        reserve_in += step;
+        reserve_out -= out;
+        received += out;
+    }
+    let shortfall = if spot_value.is_zero() {
+        0
+    } else {
+        (spot_value.saturating_sub(received) * U256::from(BASIS_POINT) / spot_value).as_u64()
+    };
+    StageSim {
+        received,
+        shortfall,
+        reserve_in,
+        reserve_out,
+    }
+}
+
+impl Preflight {
+    /// ETH to hold for every swap and missing approval at their padded gas limits.
+    pub fn gas_cost(&self) -> U256 {
+        let padded =
+            |gas: u64| U256::from(gas) * U256::from(GAS_MULTIPLIER) / U256::from(BASIS_POINT);
+        self.gas_price
+            * (padded(self.swap_gas) * U256::from(self.swaps)
+                + padded(DEFAULT_APPROVE_CALL_GAS) * U256::from(self.approvals))
+    }
+
+    pub fn checks(&self, config: &Config, decimal_in: u32) -> Vec<Check> {
+        let gas_cost = self.gas_cost();
+        let sell = simulate_stage(
+            self.amount,
+            self.sell_steps,
+            self.reserve_in,
+            self.reserve_out,
+        );
+        // buying back trades the other way against what the selling stage left
+        let buy = simulate_stage(
+            sell.received,
+            self.buy_steps,
+            sell.reserve_out,
+            sell.reserve_in,
+        );
+        let max_shortfall = BASIS_POINT.saturating_sub(config.trade.slippage);
+        let cycles = match &config.cycle {
+            Some(cycle) if cycle.max_cycles == 0 => "per cycle".to_string(),
+            Some(cycle) => format!("over {} cycles", cycle.max_cycles),
+            None => "over 1 cycle".to_string(),
+        };
+
+        vec![
+            Check {
+                name: "balance",
+                passed: self.balance >= self.amount,
+                detail: format!(
+                    "hold {}, sell {}",
+                    to_float(self.balance, decimal_in),
+                    to_float(self.amount, decimal_in)
+                ),
+            },
+            Check {
+                name: "gas",
+                passed: self.eth_balance >= gas_cost,
+                detail: format!(
+                    "hold {} ETH, {} swaps {} and {} approvals at {} gwei need {} ETH",
+                    to_float(self.eth_balance, 18),
+                    self.swaps,
+                    cycles,
+                    self.approvals,
+                    to_float(self.gas_price, 9),
+                    to_float(gas_cost, 18)
+                ),
+            },
+            Check {
+                name: "liquidity",
+                passed: !sell.received.is_zero()
+                    && !buy.received.is_zero()
+                    && sell.shortfall <= max_shortfall
+                    && buy.shortfall <= max_shortfall,
+                detail: format!(
+                    "selling stage moves the price {} bps from spot, buying back {} bps, slippage allows {} bps",
+                    sell.shortfall, buy.shortfall, max_shortfall
+                ),
+            },
+        ]
+    }
+}
+
+/// Read what trading `amount` of `token_in` for `token_out` needs from `owner`'s wallet and
+/// the configured pool.
+pub async fn read<M: Middleware + 'static>(
+    client: Arc<M>,
+    config: &Config,
+    owner: Address,
+    token_in: Address,
+    token_out: Address,
+    amount: U256,
+) -> Result<Preflight> {
+    let router_address = Address::from_str(&config.pool.router_address)?;
+    let token_a = Address::from_str(&config.pool.token_a_address)?;
+    let layout = pair::layout(client.clone(), &config.pool).await?;
+    let (reserve_0, reserve_1) = pair::get_reserves(client.clone(), layout.pair_address).await;
+    let (reserve_a, reserve_b) = layout.split(reserve_0, reserve_1);
+    let (reserve_in, reserve_out) = if token_in == token_a {
+        (reserve_a, reserve_b)
+    } else {
+        (reserve_b, reserve_a)
+    };
+
+    let gas_price = gas_price(client.as_ref(), config).await;
+    let eth_balance = client
+        .get_balance(owner, None)
+        .await
+        .map_err(|e| anyhow!("get balance fail: {}", e))?;
+
+    // the bought back side needs about what the selling stage returns
+    let expected_out = get_amount_out(amount, reserve_in, reserve_out);
+    let allowance_in = erc20::allowance(client.clone(), token_in, owner, router_address).await;
+    let allowance_out = erc20::allowance(client.clone(), token_out, owner, router_address).await;
+    let approvals = u64::from(allowance_in < amount) + u64::from(allowance_out < expected_out);
+
+    Ok(Preflight {
+        amount,
+        balance: erc20::balance_of(client.clone(), token_in, owner).await,
+        eth_balance,
+        gas_price,
+        // an unbounded run is sized for its first cycle
+        swaps: (config.trade.tswap_sell + config.trade.tswap_buy)
+            * config
+                .cycle
+                .as_ref()
+                .map_or(1, |cycle| cycle.max_cycles.max(1)),
+        approvals,
+        swap_gas: config
+            .trade
+            .gas_budget
+            .as_ref()
+            .map(|budget| budget.swap_gas)
+            .unwrap_or(DEFAULT_SWAP_CALL_GAS),
+        reserve_in,
+        reserve_out,
+        sell_steps: config.trade.tswap_sell,
+        buy_steps: config.trade.tswap_buy,
+    })
+}
+
+/// Print the breakdown, Err naming the failed checks when any.
+pub fn report(checks: &[Check]) -> Result<()> {
+    for check in checks {
+        println!(
+            "{:<10} {:<13} {}",
+            check.name,
+            if check.passed { "ok" } else { "INSUFFICIENT" },
+            check.detail
+        );
+    }
+    let failed: Vec<&str> = checks
+        .iter()
+        .filter(|check| !check.passed)
+        .map(|check| check.name)
+        .collect();
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!("pre-flight failed: {}", failed.join(", ")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stage_shortfall_grows_with_size() {
+        let reserve = U256::exp10(21);
+        let small = simulate_stage(U256::exp10(18), 10, reserve, reserve);
+        assert!(!small.received.is_zero());
+        // about the 0.3% fee and a 0.1% impact
+        assert!((30..=45).contains(&small.shortfall));
+        assert_eq!(small.reserve_in, reserve + U256::exp10(18));
+        assert_eq!(small.reserve_out, reserve - small.received);
+
+        let large = simulate_stage(U256::exp10(20), 10, reserve, reserve);
+        assert!(large.shortfall > 500);
+        let empty = simulate_stage(U256::one(), 1, U256::zero(), reserve);
+        assert_eq!(
+            (empty.received, empty.shortfall),
+            (U256::zero(), BASIS_POINT)
+        );
+    }
+
+    #[test]
+    fn buy_back_trades_against_what_selling_left() {
+        let reserve = U256::exp10(21);
+        let amount = U256::exp10(20);
+        let sell = simulate_stage(amount, 10, reserve, reserve);
+        let buy = simulate_stage(sell.received, 10, sell.reserve_out, sell.reserve_in);
+        // the pool is back near where it started, less the fees of both stages
+        assert!(buy.shortfall > 0 && buy.shortfall < sell.shortfall);
+        assert!(buy.received < amount);
+        assert_eq!(buy.reserve_out, reserve + amount - buy.received);
+    }
+
+    #[test]
+    fn gas_cost_counts_swaps_and_missing_approvals() {
+        let preflight = Preflight {
+            amount: U256::exp10(18),
+            balance: U256::exp10(18),
+            eth_balance: U256::exp10(16),
+            gas_price: U256::exp10(9),
+            swaps: 3,
+            approvals: 1,
+            swap_gas: 100000,
+            reserve_in: U256::exp10(21),
+            reserve_out: U256::exp10(21),
+            sell_steps: 2,
+            buy_steps: 1,
+        };
+        // (3 * 130000 + 52000) gas at 1 gwei
+        assert_eq!(preflight.gas_cost(), U256::from(442000u64) * U256::exp10(9));
+    }
+
+    #[test]
+    fn report_names_failed_checks() {
+        let check = |name, passed| Check {
+            name,
+            passed,
+            detail: String::new(),
+        };
+        assert!(report(&[check("balance", true)]).is_ok());
+        let error = report(&[check("balance", true), check("gas", false)]).unwrap_err();
+        assert_eq!(error.to_string(), "pre-flight failed: gas");
+    }
+}
@@ -0,0 +1,145 @@
+use crate::config::{RelayConfig, RelayMethod};
+use anyhow::{anyhow, Result};
+use ethers::{
+    providers::Middleware,
+    signers::{LocalWallet, Signer},
+    types::{transaction::eip2718::TypedTransaction, Address, Bytes, H256},
+    utils::{hex, keccak256},
+};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Header carrying the searcher identity expected by Flashbots-style relays.
+pub const SIGNATURE_HEADER: &str = "X-Flashbots-Signature";
+
+/// Private submission of signed transactions to a relay, bypassing the public mempool.
+#[derive(Debug)]
+pub struct Relay {
+    url: String,
+    method: RelayMethod,
+    /// Blocks to wait for inclusion before broadcasting publicly.
+    fallback_blocks: u64,
+    /// Signs request bodies, only identifies us to the relay and holds no funds.
+    auth: LocalWallet,
+    http: reqwest::Client,
+}
+
+impl Relay {
+    pub fn new(config: &RelayConfig, auth: LocalWallet) -> Self {
+        Self {
+            url: config.url.clone(),
+            method: config.method,
+            fallback_blocks: config.fallback_blocks,
+            auth,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// JSON-RPC request submitting `raw` for inclusion after `block`.
+    pub fn request(&self, raw: &Bytes, block: u64) -> Value {
+        let (method, params) = match self.method {
+            RelayMethod::Bundle => (
+                "eth_sendBundle",
+                json!([{ "txs": [raw], "blockNumber": format!("{:#x}", block + 1) }]),
+            ),
+            RelayMethod::Private => (
+                "eth_sendPrivateTransaction",
+                json!([{
+                    "tx": raw,
+                    "maxBlockNumber": format!("{:#x}", block + self.fallback_blocks),
+                }]),
+            ),
+        };
+        json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params })
+    }
+
+    /// Post the request for `raw` to the relay and return its result.
+    pub async fn submit(&self, raw: &Bytes, block: u64) -> Result<Value> {
+        let body = self.request(raw, block).to_string();
+        let digest = format!("0x{}", hex::encode(keccak256(body.as_bytes())));
+        let signature = self.auth.sign_message(digest).await?;
+
+        let response: Value = self
+            .http
+            .post(&self.url)
+            .header("Content-Type", "application/json")
+            .header(
+                SIGNATURE_HEADER,
+                format!("{:?}:0x{}", self.auth.address(), signature),
+            )
+            .body(body)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        if let Some(error) = response.get("error") {
+            return Err(anyhow!("relay error: {}", error));
+        }
+        Ok(response.get("result").cloned().unwrap_or(Value::Null))
+    }
+
+    /// Sign `tx` from `from` and hand it to the relay until it is mined, broadcasting it
+    /// publicly once `fallback_blocks` pass without inclusion or when the relay fails.
+    pub async fn send<M: Middleware + 'static>(
+        &self,
+        client: Arc<M>,
+        mut tx: TypedTransaction,
+        from: Address,
+    ) -> Result<H256> {
+        tx.set_from(from);
+        client
+            .fill_transaction(&mut tx, None)
+            .await
+            .map_err(|e| anyhow!("fill transaction fail: {}", e))?;
+        let signature = client
+            .sign_transaction(&tx, from)
+            .await
+            .map_err(|e| anyhow!("sign transaction fail: {}", e))?;
+        let raw = tx.rlp_signed(&signature);
+        let hash = H256::from(keccak256(&raw));
+
+        let start = client.get_block_number().await?.as_u64();
+        let mut submitted: Option<u64> = None;
+        loop {
+            let block = client.get_block_number().await?.as_u64();
+            if client
+                .get_transaction_receipt(hash)
+                .await
+                .map_err(|e| anyhow!("get receipt fail: {}", e))?
+                .is_some()
+            {
+                println!("{:?} included through relay", hash);
+                return Ok(hash);
+            }
+            if block >= start + self.fallback_blocks {
+                println!(
+                    "{:?} not included after {} blocks, broadcast publicly",
+                    hash, self.fallback_blocks
+                );
+                break;
+            }
+
+            // a bundle only targets the next block, a private transaction stays pending
+            let resubmit = match self.method {
+                RelayMethod::Bundle => submitted != Some(block),
+                RelayMethod::Private => submitted.is_none(),
+            };
+            if resubmit {
+                if let Err(e) = self.submit(&raw, block).await {
+                    println!("relay fail: {}, broadcast publicly", e);
+                    break;
+                }
+                submitted = Some(block);
+            }
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+
+        client
+            .send_raw_transaction(raw)
+            .await
+            .map_err(|e| anyhow!("broadcast fail: {}", e))?;
+        Ok(hash)
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{amm::get_amount_out, config::SandwichConfig, BASIS_POINT};
+use ethers::types::U256;
+
+/// Swap bounds left after `protect`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Protection {
+    /// Step size to send, zero when no size keeps the risk under the limit.
+    pub amount: U256,
+    pub amount_out_min: U256,
+}
+
+/// Our output once a front-run of `front` moved the pool.
+fn victim_out(front: U256, amount_in: U256, reserve_in: U256, reserve_out: U256) -> U256 {
+    let front_out = get_amount_out(front, reserve_in, reserve_out);
+    get_amount_out(amount_in, reserve_in + front, reserve_out - front_out)
+}
+
+/// Largest front-run that still lets our swap of `amount_in` return `amount_out_min`.
+///
+/// Without the attacker's fee the pool keeps `k = reserve_in * reserve_out`, and after a
+/// front-run leaving `y` on the input side we receive `g x k / (y (y + g x))`. Solving for
+/// that to equal `amount_out_min` gives `y = (sqrt(g^2 x^2 + 4 g x k / min) - g x) / 2`, which
+/// is then refined against the exact constant-product amounts.
+pub fn front_run(
+    amount_in: U256,
+    amount_out_min: U256,
+    reserve_in: U256,
+    reserve_out: U256,
+) -> U256 {
+    let raw = |value: U256| value.to_string().parse::<f64>().unwrap_or(0f64);
+    if amount_out_min.is_zero() || reserve_in.is_zero() || reserve_out.is_zero() {
+        return U256::zero();
+    }
+    if victim_out(U256::zero(), amount_in, reserve_in, reserve_out) < amount_out_min {
+        return U256::zero();
+    }
+
+    let g = 0.997f64;
+    let (x, min, r_in, r_out) = (
+        raw(amount_in),
+        raw(amount_out_min),
+        raw(reserve_in),
+        raw(reserve_out),
+    );
+    let gx = g * x;
+    let y = ((gx * gx + 4f64 * gx * r_in * r_out / min).sqrt() - gx) / 2f64;
+    if y <= r_in {
+        return U256::zero();
+    }
+    let estimate = U256::from_dec_str(&format!("{:.0}", (y - r_in).floor())).unwrap_or_default();
+
+    // the attacker's fee leaves a little more output in the pool than the estimate assumes
+    // and f64 rounding goes either way, settle the exact bound between a valid and a failing
+    // front-run
+    let holds =
+        |front: U256| victim_out(front, amount_in, reserve_in, reserve_out) >= amount_out_min;
+    let (mut low, mut high) = if holds(estimate) {
+        let mut high = estimate.max(U256::one()) * 2;
+        while holds(high) {
+            high *= 2;
+        }
+        (estimate, high)
+    } else {
+        (U256::zero(), estimate)
+    };
+    while high - low > U256::one() {
+        let middle = (low + high) / 2;
+        if holds(middle) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    low
+}
+
+/// Input-token profit of front-running our swap by `front_run` and selling back right after.
+pub fn extractable(
+    amount_in: U256,
+    amount_out_min: U256,
+    reserve_in: U256,
+    reserve_out: U256,
+) -> U256 {
+    if amount_out_min.is_zero() {
+        // an unbounded swap can be drained of almost everything
+        return amount_in;
+    }
+    let front = front_run(amount_in, amount_out_min, reserve_in, reserve_out);
+    if front.is_zero() {
+        return U256::zero();
+    }
+    let front_out = get_amount_out(front, reserve_in, reserve_out);
+    let (reserve_in, reserve_out) = (reserve_in + front, reserve_out - front_out);
+    let out = get_amount_out(amount_in, reserve_in, reserve_out);
+    let back = get_amount_out(front_out, reserve_out - out, reserve_in + amount_in);
+    back.saturating_sub(front)
+}
+
+/// Tighten `amount_out_min`, then halve the step, until what a sandwich could extract is at
+/// most `max_extractable` of the step.
+pub fn protect(
+    amount: U256,
+    amount_out_min: U256,
+    reserve_in: U256,
+    reserve_out: U256,
+    config: &SandwichConfig,
+) -> Protection {
+    let limit =
+        |amount: U256| amount * U256::from(config.max_extractable) / U256::from(BASIS_POINT);
+    let safe = |amount: U256, amount_out_min: U256| {
+        extractable(amount, amount_out_min, reserve_in, reserve_out) <= limit(amount)
+    };
+    let tightest = |amount: U256| {
+        get_amount_out(amount, reserve_in, reserve_out) * U256::from(config.tightest_slippage)
+            / U256::from(BASIS_POINT)
+    };
+
+    if safe(amount, amount_out_min) {
+        return Protection {
+            amount,
+            amount_out_min,
+        };
+    }
+
+    let tightest_min = tightest(amount);
+    if tightest_min > amount_out_min && safe(amount, tightest_min) {
+        // loosest bound between the configured one and the tightest one that is safe
+        let (mut low, mut high) = (amount_out_min, tightest_min);
+        while high - low > U256::one() {
+            let middle = (low + high) / 2;
+            if safe(amount, middle) {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+        return Protection {
+            amount,
+            amount_out_min: high,
+        };
+    }
+
+    let mut step = amount / 2;
+    while !step.is_zero() {
+        let step_min = tightest(step).max(amount_out_min * step / amount);
+        if safe(step, step_min) {
+            return Protection {
+                amount: step,
+                amount_out_min: step_min,
+            };
+        }
+        step /= 2;
+    }
+    Protection {
+        amount: U256::zero(),
+        amount_out_min: U256::zero(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn e18(value: u64) -> U256 {
+        U256::exp10(18) * value
+    }
+
+    #[test]
+    fn front_run_leaves_exactly_our_bound() {
+        let (reserve_in, reserve_out) = (e18(1000), e18(1000));
+        let amount = e18(10);
+        let expected = get_amount_out(amount, reserve_in, reserve_out);
+        let amount_out_min = expected * 9500 / 10000;
+
+        let front = front_run(amount, amount_out_min, reserve_in, reserve_out);
+        assert!(!front.is_zero());
+        assert!(victim_out(front, amount, reserve_in, reserve_out) >= amount_out_min);
+        // a larger front-run makes our swap revert
+        let larger = front * 1001 / 1000;
+        assert!(victim_out(larger, amount, reserve_in, reserve_out) < amount_out_min);
+
+        assert!(!extractable(amount, amount_out_min, reserve_in, reserve_out).is_zero());
+        // nothing to take when we accept no worse than the quote
+        assert!(extractable(amount, expected, reserve_in, reserve_out).is_zero());
+        assert_eq!(
+            extractable(amount, U256::zero(), reserve_in, reserve_out),
+            amount
+        );
+    }
+
+    #[test]
+    fn protect_tightens_before_shrinking() {
+        let (reserve_in, reserve_out) = (e18(1000), e18(1000));
+        let amount = e18(10);
+        let amount_out_min = get_amount_out(amount, reserve_in, reserve_out) * 9500 / 10000;
+        let config = SandwichConfig {
+            max_extractable: 10,
+            tightest_slippage: 9990,
+        };
+
+        let protection = protect(amount, amount_out_min, reserve_in, reserve_out, &config);
+        assert_eq!(protection.amount, amount);
+        assert!(protection.amount_out_min > amount_out_min);
+        assert!(
+            extractable(amount, protection.amount_out_min, reserve_in, reserve_out)
+                <= amount / 1000
+        );
+
+        // a big step in a shallow pool is only safe once smaller
+        let amount = e18(200);
+        let amount_out_min = get_amount_out(amount, reserve_in, reserve_out) * 9500 / 10000;
+        let config = SandwichConfig {
+            max_extractable: 1,
+            tightest_slippage: 9900,
+        };
+        let protection = protect(amount, amount_out_min, reserve_in, reserve_out, &config);
+        assert!(protection.amount < amount);
+        assert!(
+            extractable(
+                protection.amount,
+                protection.amount_out_min,
+                reserve_in,
+                reserve_out
+            ) <= protection.amount / 10000
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{
+    config::Config,
+    pair::{self, PairLayout},
+    utils::to_float,
+};
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use bindings_uniswapv2::{ierc20::IERC20, uniswap_v2_pair::UniswapV2Pair};
+use ethers::{
+    abi::{Token, Tokenizable},
+    contract::Multicall,
+    providers::Middleware,
+    types::{Address, Bytes, U256},
+};
+use std::fmt::Debug;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Wallet and pool state read in one `Multicall3.aggregate3` call, so every field is as of
+/// the same block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snapshot {
+    pub block_number: u64,
+    /// None on chains without EIP-1559.
+    pub base_fee: Option<U256>,
+    pub eth_balance: U256,
+    pub token_a: Address,
+    pub token_b: Address,
+    pub balance_a: U256,
+    pub balance_b: U256,
+    /// Allowances to the router.
+    pub allowance_a: U256,
+    pub allowance_b: U256,
+    pub reserve_a: U256,
+    pub reserve_b: U256,
+}
+
+impl Snapshot {
+    /// Why swapping `amount` of `token_in` through the router would revert, if it would.
+    pub fn check_swap(&self, token_in: Address, amount: U256) -> Result<()> {
+        let (balance, allowance, reserve_in, reserve_out) = if token_in == self.token_a {
+            (
+                self.balance_a,
+                self.allowance_a,
+                self.reserve_a,
+                self.reserve_b,
+            )
+        } else if token_in == self.token_b {
+            (
+                self.balance_b,
+                self.allowance_b,
+                self.reserve_b,
+                self.reserve_a,
+            )
+        } else {
+            return Err(anyhow!("{:?} is not a pool token", token_in));
+        };
+
+        if self.eth_balance.is_zero() {
+            return Err(anyhow!("no ETH left for gas"));
+        }
+        if balance < amount {
+            return Err(anyhow!("balance {} below {}", balance, amount));
+        }
+        if allowance < amount {
+            return Err(anyhow!("allowance {} below {}", allowance, amount));
+        }
+        if reserve_in.is_zero() || reserve_out.is_zero() {
+            return Err(anyhow!("pool has no liquidity"));
+        }
+        Ok(())
+    }
+
+    /// `(reserve_in, reserve_out)` of a swap selling `token_in`, None for other tokens.
+    pub fn reserves(&self, token_in: Address) -> Option<(U256, U256)> {
+        if token_in == self.token_a {
+            Some((self.reserve_a, self.reserve_b))
+        } else if token_in == self.token_b {
+            Some((self.reserve_b, self.reserve_a))
+        } else {
+            None
+        }
+    }
+
+    pub fn print(&self, config: &Config) {
+        let pool = &config.pool;
+        println!(
+            "block {}, base fee {} gwei",
+            self.block_number,
+            self.base_fee
+                .map(|base_fee| to_float(base_fee, 9).to_string())
+                .unwrap_or_else(|| "-".to_string())
+        );
+        println!("ETH: {}", to_float(self.eth_balance, 18));
+        println!(
+            "{}: {} (allowance {})",
+            pool.token_a_symbol,
+            to_float(self.balance_a, pool.token_a_decimal),
+            to_float(self.allowance_a, pool.token_a_decimal)
+        );
+        println!(
+            "{}: {} (allowance {})",
+            pool.token_b_symbol,
+            to_float(self.balance_b, pool.token_b_decimal),
+            to_float(self.allowance_b, pool.token_b_decimal)
+        );
+        println!(
+            "reserves: {} ({}) - {} ({})",
+            to_float(self.reserve_a, pool.token_a_decimal),
+            pool.token_a_symbol,
+            to_float(self.reserve_b, pool.token_b_decimal),
+            pool.token_b_symbol
+        );
+    }
+}
+
+/// Source of state snapshots checked by `Tswap` before every swap.
+#[async_trait]
+pub trait StateSource: Debug + Send + Sync {
+    async fn snapshot(&self) -> Result<Snapshot>;
+}
+
+/// Reads snapshots of the configured wallet and pool through Multicall3.
+#[derive(Debug)]
+pub struct SnapshotReader<M> {
+    client: Arc<M>,
+    /// Multicall3 deployment, the canonical one when None.
+    multicall_address: Option<Address>,
+    chain_id: u64,
+    layout: PairLayout,
+    token_a: Address,
+    token_b: Address,
+    router_address: Address,
+    owner: Address,
+}
+
+impl<M: Middleware + 'static> SnapshotReader<M> {
+    pub async fn new(client: Arc<M>, config: &Config, owner: Address) -> Result<Self> {
+        let multicall_address = match &config.network.multicall_address {
+            Some(address) => Some(Address::from_str(address)?),
+            None => None,
+        };
+        Ok(Self {
+            layout: pair::layout(client.clone(), &config.pool).await?,
+            client,
+            multicall_address,
+            chain_id: config.network.chain_id,
+            token_a: Address::from_str(&config.pool.token_a_address)?,
+            token_b: Address::from_str(&config.pool.token_b_address)?,
+            router_address: Address::from_str(&config.pool.router_address)?,
+            owner,
+        })
+    }
+
+    pub async fn read(&self) -> Result<Snapshot> {
+        let mut multicall = Multicall::new_with_chain_id(
+            self.client.clone(),
+            self.multicall_address,
+            Some(self.chain_id),
+        )?;
+        let token_a = IERC20::new(self.token_a, self.client.clone());
+        let token_b = IERC20::new(self.token_b, self.client.clone());
+        let pair = UniswapV2Pair::new(self.layout.pair_address, self.client.clone());
+        multicall
+            .add_get_block_number()
+            .add_get_basefee(true)
+            .add_get_eth_balance(self.owner, false)
+            .add_call(token_a.balance_of(self.owner), false)
+            .add_call(token_b.balance_of(self.owner), false)
+            .add_call(token_a.allowance(self.owner, self.router_address), false)
+            .add_call(token_b.allowance(self.owner, self.router_address), false)
+            .add_call(pair.get_reserves(), false);
+
+        let mut results = multicall.call_raw().await?.into_iter();
+        let mut next = || -> Result<U256> { uint(results.next()) };
+        let block_number = next()?.as_u64();
+        let base_fee = next().ok();
+        let eth_balance = next()?;
+        let balance_a = next()?;
+        let balance_b = next()?;
+        let allowance_a = next()?;
+        let allowance_b = next()?;
+        let (reserve_0, reserve_1, _) = match results.next() {
+            Some(Ok(reserves)) => <(U256, U256, U256)>::from_token(reserves)?,
+            _ => return Err(anyhow!("getReserves fail")),
+        };
+        let (reserve_a, reserve_b) = self.layout.split(reserve_0, reserve_1);
+
+        Ok(Snapshot {
+            block_number,
+            base_fee,
+            eth_balance,
+            token_a: self.token_a,
+            token_b: self.token_b,
+            balance_a,
+            balance_b,
+            allowance_a,
+            allowance_b,
+            reserve_a,
+            reserve_b,
+        })
+    }
+}
+
+fn uint(result: Option<std::result::Result<Token, Bytes>>) -> Result<U256> {
+    match result {
+        Some(Ok(token)) => Ok(U256::from_token(token)?),
+        Some(Err(data)) => Err(anyhow!("call reverted: {}", data)),
+        None => Err(anyhow!("missing multicall result")),
+    }
+}
+
+#[async_trait]
+impl<M: Middleware + 'static> StateSource for SnapshotReader<M> {
+    async fn snapshot(&self) -> Result<Snapshot> {
+        self.read().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> Snapshot {
+        Snapshot {
+            block_number: 1,
+            base_fee: None,
+            eth_balance: U256::exp10(17),
+            token_a: Address::repeat_byte(0x0a),
+            token_b: Address::repeat_byte(0x0b),
+            balance_a: U256::from(100u64),
+            balance_b: U256::from(50u64),
+            allowance_a: U256::MAX,
+            allowance_b: U256::from(10u64),
+            reserve_a: U256::exp10(18),
+            reserve_b: U256::exp10(18),
+        }
+    }
+
+    #[test]
+    fn check_swap_reads_the_sold_token_side() {
+        let state = snapshot();
+        assert!(state.check_swap(state.token_a, U256::from(100u64)).is_ok());
+        assert!(state.check_swap(state.token_a, U256::from(101u64)).is_err());
+        // enough balance but not enough allowance
+        assert!(state.check_swap(state.token_b, U256::from(10u64)).is_ok());
+        assert!(state.check_swap(state.token_b, U256::from(20u64)).is_err());
+        assert!(state
+            .check_swap(Address::repeat_byte(0x0c), U256::one())
+            .is_err());
+
+        let broke = Snapshot {
+            eth_balance: U256::zero(),
+            ..state
+        };
+        assert!(broke.check_swap(broke.token_a, U256::one()).is_err());
+    }
+}
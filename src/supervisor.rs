@@ -0,0 +1,385 @@
+use crate::{config::SupervisorConfig, BASIS_POINT};
+use anyhow::{anyhow, Result};
+use ethers::{
+    providers::Middleware,
+    types::{
+        transaction::eip2718::TypedTransaction, Address, BlockNumber, TransactionRequest, H256,
+        U256,
+    },
+};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Smallest gas price increase nodes accept to replace a pending transaction, 4 decimals.
+pub const MIN_GAS_BUMP: u64 = 1000u64;
+
+const CANCEL_GAS: u64 = 21000u64;
+
+/// Transaction sent by us and not seen mined yet.
+#[derive(Debug, Clone)]
+pub struct Tracked {
+    pub nonce: U256,
+    pub hash: H256,
+    pub tx: TypedTransaction,
+    /// Block number when the current version was sent.
+    pub sent_block: u64,
+    /// Gas-bumped resends so far.
+    pub replacements: u64,
+    /// Whether the current version is a zero-value self-transfer.
+    pub cancel: bool,
+    /// Self-transfers sent for this nonce so far, resends included.
+    pub cancels: u64,
+}
+
+/// What to do with a tracked transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Its nonce is used, by it or by a replacement.
+    Mined,
+    Wait,
+    Replace,
+    Cancel,
+}
+
+/// Gas price of a replacement, at least `MIN_GAS_BUMP` above `gas_price`.
+pub fn bump_gas_price(gas_price: U256, gas_bump: u64) -> U256 {
+    let gas_bump = gas_bump.max(MIN_GAS_BUMP);
+    let bumped = gas_price * U256::from(BASIS_POINT + gas_bump);
+    // round up so the bump never falls under the rule
+    (bumped + U256::from(BASIS_POINT - 1)) / U256::from(BASIS_POINT)
+}
+
+/// Resend up to `max_replacements` times once `stuck_blocks` pass, then cancel. A stuck
+/// cancel is resent up to `max_replacements` times as well, then left to wait.
+pub fn action(
+    tracked: &Tracked,
+    block: u64,
+    mined_nonce: U256,
+    config: &SupervisorConfig,
+) -> Action {
+    if tracked.nonce < mined_nonce {
+        Action::Mined
+    } else if block < tracked.sent_block + config.stuck_blocks {
+        Action::Wait
+    } else if tracked.cancel {
+        if tracked.cancels <= config.max_replacements {
+            Action::Replace
+        } else {
+            Action::Wait
+        }
+    } else if tracked.replacements < config.max_replacements {
+        Action::Replace
+    } else {
+        Action::Cancel
+    }
+}
+
+/// Zero-value transfer to `owner` taking the place of whatever uses `nonce`.
+pub fn cancel_tx(owner: Address, nonce: U256, gas_price: U256) -> TypedTransaction {
+    TransactionRequest::new()
+        .from(owner)
+        .to(owner)
+        .value(0u64)
+        .nonce(nonce)
+        .gas(CANCEL_GAS)
+        .gas_price(gas_price)
+        .into()
+}
+
+/// Keeps track of the transactions an account sends and unblocks its nonces when they get
+/// stuck.
+#[derive(Debug)]
+pub struct TxSupervisor<M> {
+    client: Arc<M>,
+    owner: Address,
+    /// Automatic replacement, manual `cancel` only when None.
+    config: Option<SupervisorConfig>,
+    tracked: Mutex<Vec<Tracked>>,
+}
+
+impl<M: Middleware + 'static> TxSupervisor<M> {
+    pub fn new(client: Arc<M>, owner: Address, config: Option<SupervisorConfig>) -> Self {
+        Self {
+            client,
+            owner,
+            config,
+            tracked: Mutex::new(vec![]),
+        }
+    }
+
+    fn gas_bump(&self) -> u64 {
+        self.config
+            .as_ref()
+            .map(|config| config.gas_bump)
+            .unwrap_or(MIN_GAS_BUMP)
+    }
+
+    /// Fill `tx` so its nonce is known, send it and start tracking it.
+    pub async fn send(&self, mut tx: TypedTransaction) -> Result<H256> {
+        tx.set_from(self.owner);
+        self.client
+            .fill_transaction(&mut tx, None)
+            .await
+            .map_err(|e| anyhow!("fill transaction fail: {}", e))?;
+        let hash = self.broadcast(tx.clone()).await?;
+        self.track(hash, tx).await?;
+        Ok(hash)
+    }
+
+    /// Track `tx`, already sent as `hash` with its nonce filled.
+    ///
+    /// Without automatic replacement nothing else forgets mined transactions, so they are
+    /// dropped here.
+    pub async fn track(&self, hash: H256, tx: TypedTransaction) -> Result<()> {
+        let nonce = *tx
+            .nonce()
+            .ok_or_else(|| anyhow!("untracked {:?}: no nonce", hash))?;
+        let sent_block = self.client.get_block_number().await?.as_u64();
+        let mined = match &self.config {
+            Some(_) => None,
+            None => Some(self.nonces().await?.0),
+        };
+        let mut tracked = self.tracked.lock().unwrap();
+        if let Some(mined) = mined {
+            tracked.retain(|t| t.nonce >= mined);
+        }
+        tracked.push(Tracked {
+            nonce,
+            hash,
+            tx,
+            sent_block,
+            replacements: 0,
+            cancel: false,
+            cancels: 0,
+        });
+        Ok(())
+    }
+
+    async fn broadcast(&self, tx: TypedTransaction) -> Result<H256> {
+        let pending = self
+            .client
+            .send_transaction(tx, None)
+            .await
+            .map_err(|e| anyhow!("send transaction fail: {}", e))?;
+        Ok(pending.tx_hash())
+    }
+
+    /// Nonce of the next transaction to be mined and of the next one to be sent.
+    pub async fn nonces(&self) -> Result<(U256, U256)> {
+        let mined = self
+            .client
+            .get_transaction_count(self.owner, Some(BlockNumber::Latest.into()))
+            .await
+            .map_err(|e| anyhow!("get nonce fail: {}", e))?;
+        let pending = self
+            .client
+            .get_transaction_count(self.owner, Some(BlockNumber::Pending.into()))
+            .await
+            .map_err(|e| anyhow!("get nonce fail: {}", e))?;
+        Ok((mined, pending))
+    }
+
+    /// Tracked transactions not mined yet, by nonce.
+    pub async fn pending(&self) -> Result<Vec<Tracked>> {
+        let (mined, _) = self.nonces().await?;
+        let mut tracked = self.tracked.lock().unwrap();
+        tracked.retain(|t| t.nonce >= mined);
+        let mut pending = tracked.clone();
+        pending.sort_by_key(|t| t.nonce);
+        Ok(pending)
+    }
+
+    /// Take the place of `nonce` with a self-transfer, priced above the tracked transaction
+    /// using it or above the node gas price when it is not tracked.
+    pub async fn cancel(&self, nonce: U256) -> Result<H256> {
+        let tracked = self
+            .tracked
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|t| t.nonce == nonce)
+            .cloned();
+        let gas_price = match tracked.as_ref().and_then(|t| t.tx.gas_price()) {
+            Some(gas_price) => gas_price,
+            None => self
+                .client
+                .get_gas_price()
+                .await
+                .map_err(|e| anyhow!("get gas price fail: {}", e))?,
+        };
+        let tx = cancel_tx(
+            self.owner,
+            nonce,
+            bump_gas_price(gas_price, self.gas_bump()),
+        );
+        let hash = self.broadcast(tx.clone()).await?;
+        let sent_block = self.client.get_block_number().await?.as_u64();
+
+        let mut all = self.tracked.lock().unwrap();
+        all.retain(|t| t.nonce != nonce);
+        all.push(Tracked {
+            nonce,
+            hash,
+            tx,
+            sent_block,
+            replacements: tracked.as_ref().map(|t| t.replacements).unwrap_or_default(),
+            cancel: true,
+            cancels: tracked.map(|t| t.cancels).unwrap_or_default() + 1,
+        });
+        Ok(hash)
+    }
+
+    /// Gas-bumped resend of `tracked`, a self-transfer instead when `cancel` is set.
+    async fn replace(&self, tracked: &Tracked, cancel: bool) -> Result<Tracked> {
+        let gas_price = tracked
+            .tx
+            .gas_price()
+            .ok_or_else(|| anyhow!("no gas price on {:?}", tracked.hash))?;
+        let gas_price = bump_gas_price(gas_price, self.gas_bump());
+        let tx = if cancel {
+            cancel_tx(self.owner, tracked.nonce, gas_price)
+        } else {
+            let mut tx = tracked.tx.clone();
+            tx.set_gas_price(gas_price);
+            tx
+        };
+        let hash = self.broadcast(tx.clone()).await?;
+        Ok(Tracked {
+            hash,
+            tx,
+            sent_block: self.client.get_block_number().await?.as_u64(),
+            replacements: tracked.replacements + u64::from(!cancel && !tracked.cancel),
+            cancel: cancel || tracked.cancel,
+            cancels: tracked.cancels + u64::from(cancel || tracked.cancel),
+            ..tracked.clone()
+        })
+    }
+
+    /// One pass over the tracked transactions, forgetting mined ones and unblocking stuck
+    /// ones.
+    pub async fn check(&self) -> Result<()> {
+        let config = match &self.config {
+            Some(config) => config,
+            None => return Ok(()),
+        };
+        let (mined, _) = self.nonces().await?;
+        let block = self.client.get_block_number().await?.as_u64();
+        let tracked = self.tracked.lock().unwrap().clone();
+
+        let mut kept = vec![];
+        for t in tracked {
+            let updated = match action(&t, block, mined, config) {
+                Action::Mined => continue,
+                Action::Wait => Ok(t.clone()),
+                Action::Replace => self.replace(&t, false).await,
+                Action::Cancel => self.replace(&t, true).await,
+            };
+            match updated {
+                Ok(updated) => {
+                    if updated.hash != t.hash {
+                        println!(
+                            "nonce {} stuck since block {}, {} {:?} -> {:?}",
+                            t.nonce,
+                            t.sent_block,
+                            if updated.cancel {
+                                "cancelled"
+                            } else {
+                                "resent"
+                            },
+                            t.hash,
+                            updated.hash
+                        );
+                    }
+                    kept.push(updated);
+                }
+                Err(e) => {
+                    // the original may have been mined meanwhile, retry on the next pass
+                    println!("replace nonce {} fail: {}", t.nonce, e);
+                    kept.push(t);
+                }
+            }
+        }
+
+        // keep transactions tracked while this pass ran
+        let mut all = self.tracked.lock().unwrap();
+        let known: Vec<U256> = kept.iter().map(|t| t.nonce).collect();
+        all.retain(|t| !known.contains(&t.nonce) && t.nonce >= mined);
+        all.extend(kept);
+        Ok(())
+    }
+
+    /// `check` every `interval` seconds, forever.
+    pub async fn run(&self) {
+        let interval = self
+            .config
+            .as_ref()
+            .map(|config| config.interval)
+            .unwrap_or(1);
+        loop {
+            if let Err(e) = self.check().await {
+                println!("tx supervisor fail: {}", e);
+            }
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> SupervisorConfig {
+        SupervisorConfig {
+            stuck_blocks: 3,
+            gas_bump: 1250,
+            max_replacements: 2,
+            interval: 12,
+        }
+    }
+
+    fn tracked(nonce: u64, replacements: u64, cancels: u64) -> Tracked {
+        Tracked {
+            nonce: U256::from(nonce),
+            hash: H256::zero(),
+            tx: cancel_tx(Address::zero(), U256::from(nonce), U256::from(100u64)),
+            sent_block: 10,
+            replacements,
+            cancel: cancels > 0,
+            cancels,
+        }
+    }
+
+    #[test]
+    fn bump_respects_replacement_rule() {
+        assert_eq!(bump_gas_price(U256::from(100u64), 1250), U256::from(113u64));
+        // below 10% is raised to 10%, rounded up
+        assert_eq!(bump_gas_price(U256::from(100u64), 500), U256::from(110u64));
+        assert_eq!(bump_gas_price(U256::from(7u64), 0), U256::from(8u64));
+    }
+
+    #[test]
+    fn stuck_transaction_is_resent_then_cancelled() {
+        let config = config();
+        let mined = U256::from(5u64);
+        assert_eq!(action(&tracked(4, 0, 0), 20, mined, &config), Action::Mined);
+        assert_eq!(action(&tracked(5, 0, 0), 12, mined, &config), Action::Wait);
+        assert_eq!(
+            action(&tracked(5, 1, 0), 13, mined, &config),
+            Action::Replace
+        );
+        assert_eq!(
+            action(&tracked(5, 2, 0), 13, mined, &config),
+            Action::Cancel
+        );
+        assert_eq!(
+            action(&tracked(5, 2, 1), 13, mined, &config),
+            Action::Replace
+        );
+        // a stuck cancel is resent max_replacements times, then left to wait
+        assert_eq!(
+            action(&tracked(5, 2, 2), 13, mined, &config),
+            Action::Replace
+        );
+        assert_eq!(action(&tracked(5, 2, 3), 13, mined, &config), Action::Wait);
+    }
+}
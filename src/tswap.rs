@@ -1,30 +1,257 @@
-use crate::{params::config::Config, BASIS_POINT, DEFAULT_GAS_PRICE, GAS_MULTIPLIER};
-use bindings_uniswapv2::{ierc20::IERC20, uniswapv2_router02::UniswapV2Router02};
+use crate::{
+    dex::{DexClient, SwapOrder},
+    erc20,
+    gas::{self, GasVerdict},
+    guard::PriceGuard,
+    params::config::{Config, GasAction, JitterConfig},
+    sandwich,
+    snapshot::StateSource,
+    utils::scale_gas_price,
+    BASIS_POINT,
+};
+use anyhow::{anyhow, Result};
 use chrono::Utc;
-use ethers::{abi::Address, contract::ContractError, providers::Middleware, types::U256};
-use std::sync::Arc;
-
-const DEFAULT_APPROVE_CALL_GAS: u64 = 40000u64;
-const DEFAULT_SWAP_CALL_GAS: u64 = 200000u64;
+use ethers::{abi::Address, providers::Middleware, types::U256};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 
 pub const SWAP_DEADLINE: i64 = 120000i64; // 120 seconds
 
+/// Seconds between reads while waiting on the chain, and the shortest pause of a polling
+/// loop whatever `tswap_step` is.
+pub const POLL_INTERVAL: u64 = 3u64;
+
+/// Seconds past the router deadline a swap is still waited on, so blocks stamped before the
+/// deadline but read late still count.
+pub const DEADLINE_GRACE: u64 = 30u64;
+
+/// Outcome of one `tswap` stage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StageReport {
+    pub filled_steps: u64,
+    pub failed_steps: u64,
+    /// Steps added to the next one because gas was over the budget limits.
+    pub merged_steps: u64,
+    /// Whether the gas budget stopped the stage early.
+    pub aborted: bool,
+    /// Whether the loss kill switch stopped the stage early.
+    pub halted: bool,
+    /// Amount left unswapped when the stage ended early.
+    pub unsold: U256,
+}
+
+impl StageReport {
+    pub fn add(&mut self, other: &StageReport) {
+        self.filled_steps += other.filled_steps;
+        self.failed_steps += other.failed_steps;
+        self.merged_steps += other.merged_steps;
+        self.aborted |= other.aborted;
+        self.halted |= other.halted;
+        self.unsold += other.unsold;
+    }
+}
+
+/// Balances a round trip started from, marked to market before every step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundTripMark {
+    pub token_a: Address,
+    pub token_b: Address,
+    pub start_a: U256,
+    pub start_b: U256,
+    /// Token A the round trip sells, what its loss is measured against.
+    pub amount: U256,
+}
+
+/// Token A lost going from `start_a` to `projected_a`, in basis points of the `amount` sold,
+/// 0 on a gain.
+pub fn loss_bps(start_a: U256, projected_a: U256, amount: U256) -> u64 {
+    if amount.is_zero() {
+        return 0;
+    }
+    (start_a.saturating_sub(projected_a) * U256::from(BASIS_POINT) / amount)
+        .min(U256::from(u64::MAX))
+        .as_u64()
+}
+
+/// One step of a stage: its size and the seconds waited after the previous step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlannedStep {
+    pub amount: U256,
+    pub delay: u64,
+}
+
 #[derive(Debug)]
-#[allow(dead_code)]
-pub struct Tswap<M> {
-    /// Ethers client.
-    client: Arc<M>,
-    owner: Address,
+pub struct Tswap<D> {
+    /// Chain backend used for quoting, approving and swapping.
+    dex: Arc<D>,
     config: Config,
+    /// Draws step jitter, seeded from `trade.jitter.seed` when set.
+    rng: Mutex<StdRng>,
+    /// Reference price check run before every step.
+    guard: Option<Arc<dyn PriceGuard>>,
+    /// Wallet and pool state checked before every swap.
+    state: Option<Arc<dyn StateSource>>,
+    /// Estimated gas paid by the swaps of the current run, `gas_budget.swap_gas` each.
+    gas_spent: Mutex<U256>,
+    /// Round trip in progress, checked against `trade.max_round_trip_loss`.
+    mark: Mutex<Option<RoundTripMark>>,
+    /// Why the loss kill switch stopped the round trip in progress, swaps are refused while
+    /// set.
+    halted: Mutex<Option<String>>,
 }
 
-impl<M: Middleware + 'static> Tswap<M> {
-    pub fn new(client: Arc<M>, owner: Address, config: Config) -> Self {
+impl<D: DexClient> Tswap<D> {
+    pub fn new(dex: Arc<D>, config: Config) -> Self {
+        let rng = match config.trade.jitter.as_ref().and_then(|j| j.seed) {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
         Self {
-            client,
-            owner,
+            dex,
             config,
+            rng: Mutex::new(rng),
+            guard: None,
+            state: None,
+            gas_spent: Mutex::new(U256::zero()),
+            mark: Mutex::new(None),
+            halted: Mutex::new(None),
+        }
+    }
+
+    pub fn with_guard(mut self, guard: Arc<dyn PriceGuard>) -> Self {
+        self.guard = Some(guard);
+        self
+    }
+
+    pub fn with_state(mut self, state: Arc<dyn StateSource>) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Whether the pool price is close enough to the reference to trade, true without a guard.
+    ///
+    /// A reference that cannot be read pauses trading as well.
+    pub async fn guard_allows(&self) -> bool {
+        let guard = match &self.guard {
+            Some(guard) => guard,
+            None => return true,
+        };
+        match guard.read().await {
+            Ok(reading) if reading.deviation() <= guard.max_deviation() as f64 => true,
+            Ok(reading) => {
+                println!(
+                    "pool price {} is {:.0} bps away from reference {}, paused",
+                    reading.pool_price,
+                    reading.deviation(),
+                    reading.reference_price
+                );
+                false
+            }
+            Err(e) => {
+                println!("reference price fail: {}, paused", e);
+                false
+            }
+        }
+    }
+
+    /// Node gas price scaled by `gas_price_multipler`, `DEFAULT_GAS_PRICE` when unavailable.
+    async fn gas_price(&self) -> U256 {
+        scale_gas_price(
+            self.dex.gas_price().await.ok(),
+            self.config.network.gas_price_multipler,
+        )
+    }
+
+    /// Mark every following step to market against `mark`, re-arming the kill switch.
+    pub fn start_round_trip(&self, mark: RoundTripMark) {
+        *self.mark.lock().unwrap() = Some(mark);
+        *self.halted.lock().unwrap() = None;
+    }
+
+    /// Stop marking steps to market and lift the kill switch, the reason when it fired.
+    pub fn end_round_trip(&self) -> Option<String> {
+        *self.mark.lock().unwrap() = None;
+        self.halted.lock().unwrap().take()
+    }
+
+    pub fn halted(&self) -> Option<String> {
+        self.halted.lock().unwrap().clone()
+    }
+
+    /// Token A the round trip would end with if the token B gained so far were bought back
+    /// now.
+    pub async fn projected_a(&self, mark: &RoundTripMark) -> Result<U256> {
+        let balance_a = self.dex.balance(mark.token_a).await?;
+        let gained_b = self
+            .dex
+            .balance(mark.token_b)
+            .await?
+            .saturating_sub(mark.start_b);
+        if gained_b.is_zero() {
+            return Ok(balance_a);
+        }
+        Ok(balance_a + self.dex.quote(mark.token_b, mark.token_a, gained_b).await?)
+    }
+
+    /// Halt trading once the projected loss of the round trip is over `max_round_trip_loss`,
+    /// the reason when halted.
+    ///
+    /// Balances or a quote that cannot be read leave trading on.
+    pub async fn check_loss(&self) -> Option<String> {
+        if let Some(reason) = self.halted() {
+            return Some(reason);
+        }
+        let max_loss = self.config.trade.max_round_trip_loss?;
+        let mark = (*self.mark.lock().unwrap())?;
+        let projected = match self.projected_a(&mark).await {
+            Ok(projected) => projected,
+            Err(e) => {
+                println!("mark to market fail: {}", e);
+                return None;
+            }
+        };
+        let loss = loss_bps(mark.start_a, projected, mark.amount);
+        if loss <= max_loss {
+            return None;
         }
+        let reason = format!(
+            "projected token A {} is below the starting {} by {} bps of the {} sold, over max_round_trip_loss {}",
+            projected, mark.start_a, loss, mark.amount, max_loss
+        );
+        *self.halted.lock().unwrap() = Some(reason.clone());
+        Some(reason)
+    }
+
+    /// Start counting `gas_budget.max_total` again, at the start of every run.
+    pub fn start_run(&self) {
+        *self.gas_spent.lock().unwrap() = U256::zero();
+    }
+
+    pub fn gas_spent(&self) -> U256 {
+        *self.gas_spent.lock().unwrap()
+    }
+
+    /// Whether a step selling `amount` of `token_in` now fits `trade.gas_budget`.
+    ///
+    /// The step is valued in ETH through a WETH quote, the gas share is not checked when
+    /// that quote fails.
+    pub async fn gas_verdict(&self, token_in: Address, amount: U256) -> GasVerdict {
+        let budget = match &self.config.trade.gas_budget {
+            Some(budget) => budget,
+            None => return GasVerdict::Within,
+        };
+        let gas_price = self.gas_price().await;
+        let notional = match Address::from_str(&self.config.pool.weth_address) {
+            Ok(weth) if weth == token_in => Some(amount),
+            Ok(weth) if budget.max_gas_share.is_some() => {
+                self.dex.quote(token_in, weth, amount).await.ok()
+            }
+            _ => None,
+        };
+        gas::verdict(budget, gas_price, self.gas_spent(), notional)
     }
 
     pub async fn approve_token(
@@ -32,158 +259,950 @@ impl<M: Middleware + 'static> Tswap<M> {
         token_address: Address,
         spender: Address,
         value: U256,
-    ) -> Result<(), ContractError<M>> {
-        let token = IERC20::new(token_address, self.client.clone());
-
-        let approve_token_call = token.approve(spender, value);
-
-        let gas = approve_token_call
-            .estimate_gas()
-            .await
-            .unwrap_or(U256::from(DEFAULT_APPROVE_CALL_GAS));
-        let gas = gas
-            .checked_mul(U256::from(GAS_MULTIPLIER))
-            .unwrap()
-            .checked_div(U256::from(BASIS_POINT))
-            .unwrap();
-
-        let gas_price = self
-            .client
-            .get_gas_price()
+    ) -> Result<()> {
+        let gas_price = self.gas_price().await;
+        self.dex
+            .approve(token_address, spender, value, gas_price)
             .await
-            .unwrap_or(U256::from(DEFAULT_GAS_PRICE));
-        let gas_price = gas_price
-            .checked_mul(U256::from(self.config.network.gas_price_multipler))
-            .unwrap()
-            .checked_div(U256::from(BASIS_POINT))
-            .unwrap();
+    }
 
-        approve_token_call
-            .gas(gas)
-            .gas_price(gas_price)
-            .send()
-            .await?;
+    /// Wait for `amount` of `token` to leave the wallet that held `before`.
+    ///
+    /// False once the router deadline and `DEADLINE_GRACE` passed without it, the swap then
+    /// reverted, was dropped, or can only revert if it is still mined.
+    pub async fn confirm_spent(&self, token: Address, before: U256, amount: U256) -> bool {
+        let deadline = tokio::time::Instant::now()
+            + Duration::from_millis(SWAP_DEADLINE as u64)
+            + Duration::from_secs(DEADLINE_GRACE);
+        loop {
+            match self.dex.balance(token).await {
+                Ok(balance) if balance + amount <= before => return true,
+                Ok(_) => {}
+                Err(e) => println!("read balance fail: {}", e),
+            }
+            if tokio::time::Instant::now() >= deadline {
+                return false;
+            }
+            tokio::time::sleep(Duration::from_secs(POLL_INTERVAL)).await;
+        }
+    }
 
-        Ok(())
+    /// Swap `amount` of token A for token B in one transaction, bounded by `slippage`.
+    ///
+    /// Fails without sending anything while the reference guard pauses trading or when the
+    /// swap does not fit the gas budget. Returns the amount sent, less than `amount` when
+    /// the sandwich check shrank the swap.
+    pub async fn swap(
+        &self,
+        token_a_address: Address,
+        token_b_address: Address,
+        amount: U256,
+    ) -> Result<U256> {
+        if !self.guard_allows().await {
+            return Err(anyhow!("paused by reference guard"));
+        }
+        match self.gas_verdict(token_a_address, amount).await {
+            GasVerdict::Within => {}
+            GasVerdict::Exceeded(reason) | GasVerdict::OverBudget(reason) => {
+                return Err(anyhow!(reason))
+            }
+        }
+        self.execute(token_a_address, token_b_address, amount).await
     }
 
-    async fn swap(
+    /// `swap` without the guard check, for callers that already ran it.
+    ///
+    /// Fails without sending anything once the loss kill switch halted trading, or when the
+    /// state snapshot shows the swap would revert, a snapshot that cannot be read does not
+    /// block the swap.
+    pub(crate) async fn execute(
         &self,
-        client: Arc<M>,
-        router_address: Address,
-        owner: Address,
         token_a_address: Address,
         token_b_address: Address,
         amount: U256,
-    ) -> Result<(), ContractError<M>> {
-        let route02 = UniswapV2Router02::new(router_address, client);
+    ) -> Result<U256> {
+        if let Some(reason) = self.halted() {
+            return Err(anyhow!("halted: {}", reason));
+        }
+        let mut reserves = None;
+        if let Some(state) = &self.state {
+            match state.snapshot().await {
+                Ok(snapshot) => {
+                    snapshot.check_swap(token_a_address, amount)?;
+                    reserves = snapshot.reserves(token_a_address);
+                }
+                Err(e) => println!("state snapshot fail: {}", e),
+            }
+        }
 
-        let amounts = route02
-            .get_amounts_out(amount, vec![token_a_address, token_b_address])
+        let amount_out = self
+            .dex
+            .quote(token_a_address, token_b_address, amount)
             .await
-            .unwrap_or(vec![U256::zero()]);
-        let zero = U256::zero();
-        let amount_out = amounts.last().unwrap_or(&zero);
+            .unwrap_or(U256::zero());
         let amount_out_min = amount_out
             .checked_mul(U256::from(self.config.trade.slippage))
             .unwrap()
             .checked_div(U256::from(BASIS_POINT))
             .unwrap();
+        let (amount, amount_out_min) = self
+            .protect(
+                token_a_address,
+                token_b_address,
+                amount,
+                amount_out_min,
+                reserves,
+            )
+            .await?;
 
         let valid_timestamp = get_valid_timestamp(SWAP_DEADLINE);
-        let swap_call = route02.swap_exact_tokens_for_tokens(
-            amount,
-            amount_out_min,
-            vec![token_a_address, token_b_address],
-            owner,
-            U256::from_dec_str(&valid_timestamp.to_string()).unwrap(),
-        );
+        let gas_price = self.gas_price().await;
 
-        let gas = swap_call
-            .estimate_gas()
-            .await
-            .unwrap_or(U256::from(DEFAULT_SWAP_CALL_GAS));
-        let gas = gas
-            .checked_mul(U256::from(GAS_MULTIPLIER))
-            .unwrap()
-            .checked_div(U256::from(BASIS_POINT))
-            .unwrap();
+        self.dex
+            .swap(SwapOrder {
+                token_in: token_a_address,
+                token_out: token_b_address,
+                amount_in: amount,
+                amount_out_min,
+                deadline: U256::from_dec_str(&valid_timestamp.to_string()).unwrap(),
+                gas_price,
+            })
+            .await?;
 
-        let gas_price = self
-            .client
-            .get_gas_price()
-            .await
-            .unwrap_or(U256::from(DEFAULT_GAS_PRICE));
-        let gas_price = gas_price
-            .checked_mul(U256::from(self.config.network.gas_price_multipler))
-            .unwrap()
-            .checked_div(U256::from(BASIS_POINT))
-            .unwrap();
+        if let Some(budget) = &self.config.trade.gas_budget {
+            *self.gas_spent.lock().unwrap() += gas_price * U256::from(budget.swap_gas);
+        }
+        Ok(amount)
+    }
 
-        swap_call.gas(gas).gas_price(gas_price).send().await?;
+    /// Bounds of a step after `trade.sandwich`, unchanged without it.
+    ///
+    /// Uses the `(reserve_in, reserve_out)` of the state snapshot when given, otherwise reads
+    /// them. Fails when no step size keeps what a sandwich could extract under the limit,
+    /// reserves that cannot be read leave the step as it is.
+    async fn protect(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        amount: U256,
+        amount_out_min: U256,
+        reserves: Option<(U256, U256)>,
+    ) -> Result<(U256, U256)> {
+        let config = match &self.config.trade.sandwich {
+            Some(config) => config,
+            None => return Ok((amount, amount_out_min)),
+        };
+        let reserves = match reserves {
+            Some(reserves) => Ok(reserves),
+            None => self.dex.reserves(token_in, token_out).await,
+        };
+        let (reserve_in, reserve_out) = match reserves {
+            Ok(reserves) => reserves,
+            Err(e) => {
+                println!("reserves fail: {}, sandwich risk unchecked", e);
+                return Ok((amount, amount_out_min));
+            }
+        };
 
-        Ok(())
+        let protection = sandwich::protect(amount, amount_out_min, reserve_in, reserve_out, config);
+        if protection.amount.is_zero() {
+            return Err(anyhow!("sandwich risk too high"));
+        }
+        if protection.amount != amount {
+            println!(
+                "sandwich risk, step shrunk from {} to {}",
+                amount, protection.amount
+            );
+        } else if protection.amount_out_min != amount_out_min {
+            println!(
+                "sandwich risk, amount out min tightened from {} to {}",
+                amount_out_min, protection.amount_out_min
+            );
+        }
+        Ok((protection.amount, protection.amount_out_min))
     }
 
     pub async fn tswap(
         &self,
-        router_address: Address,
         token_a_address: Address,
         token_b_address: Address,
         amount: U256,
         max_step: u64,
-    ) {
-        let step = self.config.trade.tswap_step as i64;
+    ) -> StageReport {
+        let plan = plan_steps(
+            amount,
+            max_step,
+            self.config.trade.tswap_step,
+            self.config.trade.jitter.as_ref(),
+            &mut *self.rng.lock().unwrap(),
+        );
         let mut step_count = 0u64;
+        let mut report = StageReport::default();
         let mut last_tick = Utc::now();
+        // amount of merged or shrunk steps, added to the next one
+        let mut carried = U256::zero();
+        let mut delayed_since: Option<i64> = None;
+        // send time of the previous step, for the realized schedule
+        let mut last_sent: Option<i64> = None;
         loop {
             let now = Utc::now();
-            if now.timestamp() - last_tick.timestamp() > step || step_count == 0 {
-                last_tick = now;
-                step_count += 1;
+            let step = plan[step_count as usize];
+            if now.timestamp() - last_tick.timestamp() > step.delay as i64 || step_count == 0 {
+                if !self.guard_allows().await {
+                    tokio::time::sleep(Duration::from_secs(
+                        self.config.trade.tswap_step.max(POLL_INTERVAL),
+                    ))
+                    .await;
+                    continue;
+                }
+                let amount = step.amount + carried;
 
-                let samount = if step_count == max_step {
-                    amount
-                        .checked_sub(
-                            amount
-                                .checked_div(U256::from(max_step))
-                                .unwrap()
-                                .checked_mul(U256::from(max_step - 1))
-                                .unwrap(),
-                        )
-                        .unwrap()
-                } else {
-                    amount.checked_div(U256::from(max_step)).unwrap()
+                if let Some(reason) = self.check_loss().await {
+                    report.halted = true;
+                    report.unsold = plan[step_count as usize + 1..]
+                        .iter()
+                        .fold(amount, |unsold, step| unsold + step.amount);
+                    println!(
+                        "stage halted at step {}: {}, {} filled, {} failed, {} merged, {} unsold",
+                        step_count + 1,
+                        reason,
+                        report.filled_steps,
+                        report.failed_steps,
+                        report.merged_steps,
+                        report.unsold
+                    );
+                    break;
+                }
+                let reason = match self.gas_verdict(token_a_address, amount).await {
+                    GasVerdict::Within => None,
+                    GasVerdict::OverBudget(reason) => Some((reason, GasAction::Abort)),
+                    GasVerdict::Exceeded(reason) => {
+                        let budget = self.config.trade.gas_budget.as_ref().unwrap();
+                        let waited =
+                            now.timestamp() - *delayed_since.get_or_insert(now.timestamp());
+                        let action = match budget.on_exceed {
+                            GasAction::Merge if step_count + 1 < max_step => GasAction::Merge,
+                            _ if waited >= budget.max_delay as i64 => GasAction::Abort,
+                            GasAction::Abort => GasAction::Abort,
+                            _ => GasAction::Delay,
+                        };
+                        Some((reason, action))
+                    }
                 };
+                match reason {
+                    Some((reason, GasAction::Delay)) => {
+                        println!("step {} delayed: {}", step_count + 1, reason);
+                        tokio::time::sleep(Duration::from_secs(
+                            self.config.trade.tswap_step.max(POLL_INTERVAL),
+                        ))
+                        .await;
+                        continue;
+                    }
+                    Some((reason, GasAction::Merge)) => {
+                        println!("step {} merged into the next: {}", step_count + 1, reason);
+                        last_tick = now;
+                        step_count += 1;
+                        report.merged_steps += 1;
+                        carried = amount;
+                        delayed_since = None;
+                        continue;
+                    }
+                    Some((reason, GasAction::Abort)) => {
+                        report.aborted = true;
+                        report.unsold = plan[step_count as usize + 1..]
+                            .iter()
+                            .fold(amount, |unsold, step| unsold + step.amount);
+                        println!(
+                            "stage aborted at step {}: {}, {} filled, {} failed, {} merged, {} unsold, {} gas spent",
+                            step_count + 1,
+                            reason,
+                            report.filled_steps,
+                            report.failed_steps,
+                            report.merged_steps,
+                            report.unsold,
+                            self.gas_spent()
+                        );
+                        break;
+                    }
+                    None => {}
+                }
+                last_tick = now;
+                step_count += 1;
+                carried = U256::zero();
+                delayed_since = None;
 
-                let result = self
-                    .swap(
-                        self.client.clone(),
-                        router_address,
-                        self.owner.clone(),
-                        token_a_address,
-                        token_b_address,
-                        samount,
-                    )
-                    .await;
-
-                if result.is_err() {
-                    println!("step {} fail", step_count);
-                } else {
-                    println!("step {} success", step_count);
+                let sent_at = Utc::now();
+                if self.config.trade.jitter.is_some() {
+                    println!(
+                        "step {} sent {} at {} ({}s after the previous)",
+                        step_count,
+                        amount,
+                        sent_at.to_rfc3339(),
+                        last_sent.map_or(0, |last| sent_at.timestamp() - last)
+                    );
+                }
+                last_sent = Some(sent_at.timestamp());
+                match self.execute(token_a_address, token_b_address, amount).await {
+                    Ok(sent) => {
+                        report.filled_steps += 1;
+                        println!("step {} success", step_count);
+                        // what the sandwich check held back goes with the next step
+                        let held_back = amount - sent;
+                        if !held_back.is_zero() {
+                            if step_count < max_step {
+                                carried = held_back;
+                                println!("step {} carries {} held back", step_count + 1, held_back);
+                            } else {
+                                report.unsold += held_back;
+                                println!("{} held back unsold", held_back);
+                            }
+                        }
+                    }
+                    Err(_) => {
+                        report.failed_steps += 1;
+                        println!("step {} fail", step_count);
+                    }
                 }
             }
 
             if step_count == max_step {
                 break;
             }
+            // let stages of other wallets run on this thread
+            tokio::task::yield_now().await;
         }
+
+        report
+    }
+}
+
+/// Approve `expected` to `spender` when needed and wait until the allowance shows up.
+pub async fn check_allowance<M: Middleware + 'static, D: DexClient>(
+    provider: Arc<M>,
+    tswap: &Tswap<D>,
+    token_address: Address,
+    owner: Address,
+    spender: Address,
+    expected: U256,
+) {
+    let mut check = false;
+    while erc20::allowance(provider.clone(), token_address, owner, spender).await < expected {
+        if !check {
+            tswap
+                .approve_token(token_address, spender, expected)
+                .await
+                .unwrap();
+
+            check = !check;
+        }
+        thread::sleep(Duration::from_secs(5));
+        println!("prev setup ...");
+    }
+}
+
+/// Size of step `step_count` (1-based) when splitting `amount` evenly, the last step takes the remainder.
+pub fn step_amount(amount: U256, max_step: u64, step_count: u64) -> U256 {
+    let samount = amount.checked_div(U256::from(max_step)).unwrap();
+    if step_count == max_step {
+        amount
+            .checked_sub(samount.checked_mul(U256::from(max_step - 1)).unwrap())
+            .unwrap()
+    } else {
+        samount
+    }
+}
+
+/// Sizes and intervals of the `max_step` steps of a stage.
+///
+/// Without jitter every step waits `step` seconds and sizes follow `step_amount`. With jitter
+/// each size is weighted by up to `size` around the even split, the last step takes the
+/// remainder so the stage still sums to `amount`, and each interval moves by up to `timing`.
+pub fn plan_steps<R: Rng>(
+    amount: U256,
+    max_step: u64,
+    step: u64,
+    jitter: Option<&JitterConfig>,
+    rng: &mut R,
+) -> Vec<PlannedStep> {
+    let jitter = match jitter {
+        Some(jitter) => jitter,
+        None => {
+            return (1..=max_step)
+                .map(|step_count| PlannedStep {
+                    amount: step_amount(amount, max_step, step_count),
+                    delay: if step_count == 1 { 0 } else { step },
+                })
+                .collect()
+        }
+    };
+
+    let size = jitter.size.min(BASIS_POINT - 1);
+    let weights: Vec<u64> = (0..max_step)
+        .map(|_| rng.gen_range(BASIS_POINT - size..=BASIS_POINT + size))
+        .collect();
+    let total_weight: u64 = weights.iter().sum();
+
+    let mut planned = U256::zero();
+    let mut steps = vec![];
+    for (i, weight) in weights.iter().enumerate() {
+        let step_amount = if i as u64 == max_step - 1 {
+            amount - planned
+        } else {
+            amount * U256::from(*weight) / U256::from(total_weight)
+        };
+        planned += step_amount;
+
+        let timing = jitter.timing.min(BASIS_POINT);
+        let factor = rng.gen_range(BASIS_POINT - timing..=BASIS_POINT + timing);
+        steps.push(PlannedStep {
+            amount: step_amount,
+            delay: if i == 0 {
+                0
+            } else {
+                step * factor / BASIS_POINT
+            },
+        });
     }
+    steps
 }
 
+/// Unix timestamp in seconds `future_millis` from now, the router compares it with
+/// `block.timestamp`.
 fn get_valid_timestamp(future_millis: i64) -> i64 {
     let since_epoch = Utc::now();
-    let time_millis = since_epoch.timestamp_millis().checked_add(future_millis).unwrap();
+    since_epoch
+        .timestamp()
+        .checked_add(future_millis / 1000)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{GasBudgetConfig, NetworkConfig, PoolConfig, SandwichConfig, TradeConfig};
+    use crate::dex::SimDex;
+    use crate::guard::GuardReading;
+    use crate::DEFAULT_GAS_PRICE;
+    use async_trait::async_trait;
+
+    /// Reports the pool 10% off the reference for the first `off_reads` reads.
+    #[derive(Debug, Default)]
+    struct FlakyGuard {
+        off_reads: u64,
+        reads: Mutex<u64>,
+    }
+
+    #[async_trait]
+    impl PriceGuard for FlakyGuard {
+        async fn read(&self) -> Result<GuardReading> {
+            let mut reads = self.reads.lock().unwrap();
+            *reads += 1;
+            Ok(GuardReading {
+                pool_price: if *reads <= self.off_reads {
+                    1.1f64
+                } else {
+                    1f64
+                },
+                reference_price: 1f64,
+            })
+        }
+
+        fn max_deviation(&self) -> u64 {
+            100
+        }
+    }
+
+    fn config() -> Config {
+        Config {
+            pool: PoolConfig {
+                router_address: String::new(),
+                pair_address: None,
+                factory_address: None,
+                init_code_hash: None,
+                weth_address: String::new(),
+                token_a_address: String::new(),
+                token_a_symbol: "TKA".to_string(),
+                token_a_decimal: 18,
+                token_b_address: String::new(),
+                token_b_symbol: "TKB".to_string(),
+                token_b_decimal: 18,
+            },
+            trade: TradeConfig {
+                tswap_buy: 2,
+                tswap_sell: 2,
+                tswap_step: 0,
+                slippage: 9500,
+                jitter: None,
+                vwap: None,
+                sandwich: None,
+                gas_budget: None,
+                max_round_trip_loss: None,
+            },
+            network: NetworkConfig {
+                chain_id: 1,
+                rpc: String::new(),
+                gas_price_multipler: 15000,
+                multicall_address: None,
+                relay: None,
+                supervisor: None,
+            },
+            mm: None,
+            grid: None,
+            cycle: None,
+            guard: None,
+            arb: None,
+        }
+    }
+
+    fn token_a() -> Address {
+        Address::repeat_byte(0xaa)
+    }
+
+    fn token_b() -> Address {
+        Address::repeat_byte(0xbb)
+    }
+
+    fn setup() -> (Arc<SimDex>, Tswap<SimDex>) {
+        let reserve = U256::exp10(24);
+        let dex = Arc::new(SimDex::new(
+            Address::repeat_byte(0x02),
+            token_a(),
+            reserve,
+            token_b(),
+            reserve,
+        ));
+        dex.set_balance(token_a(), U256::exp10(21));
+        dex.set_gas_price(Some(U256::from(10u64)));
+        let tswap = Tswap::new(dex.clone(), config());
+        (dex, tswap)
+    }
+
+    #[test]
+    fn step_amount_puts_remainder_on_last_step() {
+        let amount = U256::from(100u64);
+        let steps: Vec<U256> = (1..=3).map(|i| step_amount(amount, 3, i)).collect();
+        assert_eq!(steps, vec![33u64.into(), 33u64.into(), 34u64.into()]);
+        assert_eq!(steps.iter().fold(U256::zero(), |acc, s| acc + s), amount);
+    }
+
+    #[test]
+    fn plan_without_jitter_is_even_and_fixed() {
+        let amount = U256::from(100u64);
+        let plan = plan_steps(amount, 3, 60, None, &mut StdRng::seed_from_u64(1));
+        let amounts: Vec<U256> = plan.iter().map(|s| s.amount).collect();
+        let delays: Vec<u64> = plan.iter().map(|s| s.delay).collect();
+        assert_eq!(amounts, vec![33u64.into(), 33u64.into(), 34u64.into()]);
+        assert_eq!(delays, vec![0, 60, 60]);
+    }
+
+    #[test]
+    fn jittered_plan_sums_exactly_and_is_reproducible() {
+        let jitter = JitterConfig {
+            size: 3000,
+            timing: 5000,
+            seed: None,
+        };
+        let amount = U256::exp10(18) * 7 + 3;
+        let plan = plan_steps(
+            amount,
+            10,
+            60,
+            Some(&jitter),
+            &mut StdRng::seed_from_u64(42),
+        );
+
+        let total = plan.iter().fold(U256::zero(), |acc, s| acc + s.amount);
+        assert_eq!(total, amount);
+        // weights within +-30% of even, normalized by a total within +-30% as well
+        let even = amount / 10;
+        for step in plan.iter() {
+            assert!(step.amount > even * 5 / 10 && step.amount < even * 19 / 10);
+        }
+        assert_eq!(plan[0].delay, 0);
+        assert!(plan[1..].iter().all(|s| (30..=90).contains(&s.delay)));
+        assert!(plan.windows(2).any(|w| w[0].amount != w[1].amount));
+
+        let again = plan_steps(
+            amount,
+            10,
+            60,
+            Some(&jitter),
+            &mut StdRng::seed_from_u64(42),
+        );
+        assert_eq!(plan, again);
+    }
+
+    #[tokio::test]
+    async fn swap_bounds_output_by_slippage_and_scales_gas_price() {
+        let (dex, tswap) = setup();
+        let amount = U256::exp10(18);
+        tswap
+            .approve_token(token_a(), dex.router(), amount)
+            .await
+            .unwrap();
+
+        let quote = dex.quote(token_a(), token_b(), amount).await.unwrap();
+        let before = dex.balance_of(token_a());
+        tswap.swap(token_a(), token_b(), amount).await.unwrap();
+
+        let order = dex.swaps()[0];
+        // the router deadline is in seconds
+        assert!(order.deadline <= U256::from(Utc::now().timestamp() + SWAP_DEADLINE / 1000));
+        assert!(order.deadline >= U256::from(Utc::now().timestamp()));
+        assert_eq!(order.amount_out_min, quote * 9500 / 10000);
+        assert_eq!(order.gas_price, U256::from(15u64));
+        assert_eq!(dex.balance_of(token_b()), quote);
+        assert_eq!(dex.reserve(token_a()), U256::exp10(24) + amount);
+        assert_eq!(dex.reserve(token_b()), U256::exp10(24) - quote);
+        assert!(tswap.confirm_spent(token_a(), before, amount).await);
+    }
+
+    #[tokio::test]
+    async fn sandwich_limit_tightens_bound_then_shrinks_step() {
+        let (dex, _) = setup();
+        let mut config = config();
+        config.trade.sandwich = Some(SandwichConfig {
+            max_extractable: 10,
+            tightest_slippage: 9990,
+        });
+        let tswap = Tswap::new(dex.clone(), config);
+        tswap
+            .approve_token(token_a(), dex.router(), U256::MAX)
+            .await
+            .unwrap();
+
+        dex.set_balance(token_a(), U256::exp10(24));
+
+        // 1% of the pool, a tighter bound is enough
+        let amount = U256::exp10(22);
+        let quote = dex.quote(token_a(), token_b(), amount).await.unwrap();
+        tswap.swap(token_a(), token_b(), amount).await.unwrap();
+        let order = dex.swaps()[0];
+        assert_eq!(order.amount_in, amount);
+        assert!(order.amount_out_min > quote * 9500 / 10000);
+        assert!(order.amount_out_min <= quote * 9990 / 10000);
+
+        // 20% of the pool, only a smaller step is safe
+        let amount = U256::exp10(23) * 2;
+        let sent = tswap.swap(token_a(), token_b(), amount).await.unwrap();
+        assert!(sent < amount);
+        assert_eq!(dex.swaps()[1].amount_in, sent);
+    }
+
+    #[tokio::test]
+    async fn shrunk_step_is_carried_then_reported_unsold() {
+        let (dex, _) = setup();
+        let mut config = config();
+        config.trade.sandwich = Some(SandwichConfig {
+            max_extractable: 10,
+            tightest_slippage: 9990,
+        });
+        let tswap = Tswap::new(dex.clone(), config);
+        tswap
+            .approve_token(token_a(), dex.router(), U256::MAX)
+            .await
+            .unwrap();
+        dex.set_balance(token_a(), U256::exp10(24));
+
+        // two steps of 20% of the pool, both shrunk
+        let amount = U256::exp10(23) * 4;
+        let report = tswap.tswap(token_a(), token_b(), amount, 2).await;
+
+        let swaps = dex.swaps();
+        assert_eq!(swaps.len(), 2);
+        assert_eq!(report.filled_steps, 2);
+        assert!(!report.unsold.is_zero());
+        // nothing held back is dropped
+        assert_eq!(
+            swaps[0].amount_in + swaps[1].amount_in + report.unsold,
+            amount
+        );
+    }
+
+    fn gas_budget_tswap(dex: Arc<SimDex>, budget: GasBudgetConfig) -> Tswap<SimDex> {
+        let mut config = config();
+        // steps are valued in ETH as sold
+        config.pool.weth_address = format!("{:?}", token_a());
+        config.trade.gas_budget = Some(budget);
+        Tswap::new(dex, config)
+    }
 
-    time_millis
+    fn gas_budget(on_exceed: GasAction) -> GasBudgetConfig {
+        GasBudgetConfig {
+            max_total: None,
+            max_gas_price: None,
+            max_gas_share: None,
+            swap_gas: 200000,
+            on_exceed,
+            max_delay: 60,
+        }
+    }
+
+    #[tokio::test]
+    async fn gas_share_is_unchecked_when_the_step_cannot_be_valued() {
+        let (dex, _) = setup();
+        let mut config = config();
+        // steps are valued in ETH through a quote to token B
+        config.pool.weth_address = format!("{:?}", token_b());
+        config.trade.gas_budget = Some(GasBudgetConfig {
+            max_gas_share: Some(1),
+            ..gas_budget(GasAction::Abort)
+        });
+        let tswap = Tswap::new(dex.clone(), config);
+        let amount = U256::exp10(7);
+
+        assert!(matches!(
+            tswap.gas_verdict(token_a(), amount).await,
+            GasVerdict::Exceeded(_)
+        ));
+        dex.set_fail_quotes(true);
+        assert_eq!(
+            tswap.gas_verdict(token_a(), amount).await,
+            GasVerdict::Within
+        );
+    }
+
+    #[tokio::test]
+    async fn expensive_step_is_merged_into_the_next() {
+        let (dex, _) = setup();
+        // 15 wei * 200000 = 3e6, 15% of a 2e7 step and 7.5% of both
+        let tswap = gas_budget_tswap(
+            dex.clone(),
+            GasBudgetConfig {
+                max_gas_share: Some(1000),
+                ..gas_budget(GasAction::Merge)
+            },
+        );
+        let amount = U256::from(4u64) * U256::exp10(7);
+        tswap
+            .approve_token(token_a(), dex.router(), amount)
+            .await
+            .unwrap();
+
+        let report = tswap.tswap(token_a(), token_b(), amount, 2).await;
+
+        assert_eq!(
+            report,
+            StageReport {
+                filled_steps: 1,
+                merged_steps: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(dex.swaps().len(), 1);
+        assert_eq!(dex.swaps()[0].amount_in, amount);
+        assert_eq!(tswap.gas_spent(), U256::from(3000000u64));
+    }
+
+    #[tokio::test]
+    async fn stage_aborts_before_going_over_total_gas() {
+        let (dex, _) = setup();
+        // room for one swap of 3e6 wei
+        let tswap = gas_budget_tswap(
+            dex.clone(),
+            GasBudgetConfig {
+                max_total: Some(4e-12f64),
+                ..gas_budget(GasAction::Delay)
+            },
+        );
+        let amount = U256::exp10(18);
+        tswap
+            .approve_token(token_a(), dex.router(), amount)
+            .await
+            .unwrap();
+
+        let report = tswap.tswap(token_a(), token_b(), amount, 2).await;
+
+        assert!(report.aborted);
+        assert_eq!(report.filled_steps, 1);
+        assert_eq!(report.unsold, amount / 2);
+        assert_eq!(dex.swaps().len(), 1);
+        // a single swap is refused as well
+        assert!(tswap.swap(token_a(), token_b(), amount / 2).await.is_err());
+
+        // until the next run starts counting again
+        tswap.start_run();
+        assert!(tswap.swap(token_a(), token_b(), amount / 2).await.is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn step_is_delayed_until_gas_drops() {
+        let (dex, _) = setup();
+        dex.set_gas_price(Some(U256::from(100u64)));
+        // 1e-8 gwei is 10 wei, 15 wei once scaled
+        let tswap = gas_budget_tswap(
+            dex.clone(),
+            GasBudgetConfig {
+                max_gas_price: Some(2e-8f64),
+                ..gas_budget(GasAction::Delay)
+            },
+        );
+        let amount = U256::exp10(18);
+        tswap
+            .approve_token(token_a(), dex.router(), amount)
+            .await
+            .unwrap();
+
+        let cheaper = dex.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            cheaper.set_gas_price(Some(U256::from(10u64)));
+        });
+        let report = tswap.tswap(token_a(), token_b(), amount, 2).await;
+
+        assert_eq!(
+            report,
+            StageReport {
+                filled_steps: 2,
+                ..Default::default()
+            }
+        );
+        assert!(dex.swaps().iter().all(|s| s.gas_price == U256::from(15u64)));
+    }
+
+    #[test]
+    fn loss_is_measured_against_the_amount_sold() {
+        let start = U256::from(10000u64);
+        let sold = U256::from(1000u64);
+        assert_eq!(loss_bps(start, U256::from(9985u64), sold), 150);
+        assert_eq!(loss_bps(start, U256::from(10100u64), sold), 0);
+        assert_eq!(loss_bps(start, U256::from(9985u64), U256::zero()), 0);
+    }
+
+    #[tokio::test]
+    async fn kill_switch_halts_buy_back_on_projected_loss() {
+        let (dex, _) = setup();
+        let mut config = config();
+        config.trade.max_round_trip_loss = Some(100);
+        let tswap = Tswap::new(dex.clone(), config);
+        tswap
+            .approve_token(token_a(), dex.router(), U256::MAX)
+            .await
+            .unwrap();
+        tswap
+            .approve_token(token_b(), dex.router(), U256::MAX)
+            .await
+            .unwrap();
+        tswap.start_round_trip(RoundTripMark {
+            token_a: token_a(),
+            token_b: token_b(),
+            start_a: dex.balance_of(token_a()),
+            start_b: U256::zero(),
+            amount: U256::exp10(21) / 2,
+        });
+
+        // selling half the wallet costs about the 0.3% fee of it
+        let amount = U256::exp10(21) / 2;
+        tswap.swap(token_a(), token_b(), amount).await.unwrap();
+        assert!(tswap.check_loss().await.is_none());
+
+        // token B crashes by about 10% before the buy back
+        dex.external_swap(token_b(), token_a(), U256::exp10(23) / 2);
+        let received = dex.balance_of(token_b());
+        let report = tswap.tswap(token_b(), token_a(), received, 2).await;
+
+        assert!(report.halted);
+        assert!(!report.aborted);
+        assert_eq!(report.filled_steps, 0);
+        assert_eq!(report.unsold, received);
+        assert_eq!(dex.swaps().len(), 1);
+        assert!(tswap.halted().unwrap().contains("max_round_trip_loss"));
+        assert!(tswap.swap(token_b(), token_a(), received).await.is_err());
+
+        // the halt ends with the round trip, later swaps go through
+        assert!(tswap
+            .end_round_trip()
+            .unwrap()
+            .contains("max_round_trip_loss"));
+        assert!(tswap.halted().is_none());
+        assert!(tswap.swap(token_b(), token_a(), received / 2).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn gas_price_falls_back_to_default() {
+        let (dex, tswap) = setup();
+        dex.set_gas_price(None);
+        assert_eq!(
+            tswap.gas_price().await,
+            U256::from(DEFAULT_GAS_PRICE) * 15000 / 10000
+        );
+    }
+
+    #[tokio::test]
+    async fn tswap_sells_whole_amount_in_steps() {
+        let (dex, tswap) = setup();
+        let amount = U256::from(1001u64) * U256::exp10(15);
+        tswap
+            .approve_token(token_a(), dex.router(), amount)
+            .await
+            .unwrap();
+
+        let report = tswap.tswap(token_a(), token_b(), amount, 2).await;
+
+        assert_eq!(
+            report,
+            StageReport {
+                filled_steps: 2,
+                ..Default::default()
+            }
+        );
+        let swaps = dex.swaps();
+        assert_eq!(swaps[0].amount_in + swaps[1].amount_in, amount);
+        assert_eq!(dex.balance_of(token_a()), U256::exp10(21) - amount);
+    }
+
+    #[tokio::test]
+    async fn tswap_counts_reverted_steps() {
+        let (dex, tswap) = setup();
+        let amount = U256::exp10(18);
+        // no allowance, every transfer from the router fails
+        let report = tswap.tswap(token_a(), token_b(), amount, 2).await;
+
+        assert_eq!(report.failed_steps, 2);
+        assert!(dex.swaps().is_empty());
+        assert_eq!(dex.allowance(token_a(), dex.router()), U256::zero());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn guard_pauses_steps_until_price_is_back() {
+        let (dex, tswap) = setup();
+        let guard = Arc::new(FlakyGuard {
+            off_reads: 2,
+            ..Default::default()
+        });
+        let tswap = tswap.with_guard(guard.clone());
+        let amount = U256::exp10(18);
+        tswap
+            .approve_token(token_a(), dex.router(), amount)
+            .await
+            .unwrap();
+
+        let report = tswap.tswap(token_a(), token_b(), amount, 2).await;
+
+        assert_eq!(
+            report,
+            StageReport {
+                filled_steps: 2,
+                ..Default::default()
+            }
+        );
+        // two paused checks, then one per step
+        assert_eq!(*guard.reads.lock().unwrap(), 4);
+        assert_eq!(dex.swaps().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn guarded_swap_sends_nothing_while_paused() {
+        let (dex, tswap) = setup();
+        let tswap = tswap.with_guard(Arc::new(FlakyGuard {
+            off_reads: 1,
+            ..Default::default()
+        }));
+
+        assert!(tswap
+            .swap(token_a(), token_b(), U256::exp10(18))
+            .await
+            .is_err());
+        assert!(dex.swaps().is_empty());
+    }
 }
@@ -0,0 +1,226 @@
+use crate::{
+    pair::{self, CumulativePrices, PairLayout},
+    utils::to_float,
+};
+use anyhow::{anyhow, Result};
+use ethers::{
+    providers::Middleware,
+    types::{BlockNumber, U256},
+};
+use std::sync::Arc;
+
+/// Cumulative token A price in token B at the end of a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Observation {
+    pub block_number: u64,
+    pub timestamp: u64,
+    /// UQ112x112 price times seconds, wrapping like the pair's accumulator.
+    pub cumulative: U256,
+}
+
+/// Time-weighted average price between two observations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Twap {
+    pub from: Observation,
+    pub to: Observation,
+    /// Token A priced in token B, human units.
+    pub price: f64,
+}
+
+/// Cumulative price of token A in token B accumulated up to `now`, as the pair would
+/// report it had it been updated in that block.
+///
+/// Mirrors `UniswapV2OracleLibrary.currentCumulativePrices`: the reserves held since
+/// `blockTimestampLast` keep accumulating until the next swap, sync or mint records them.
+pub fn current_cumulative(prices: &CumulativePrices, token_a_is_0: bool, now: u32) -> U256 {
+    let (cumulative, reserve_a, reserve_b) = if token_a_is_0 {
+        (prices.price_0, prices.reserve_0, prices.reserve_1)
+    } else {
+        (prices.price_1, prices.reserve_1, prices.reserve_0)
+    };
+    if reserve_a.is_zero() || now == prices.timestamp {
+        return cumulative;
+    }
+    let elapsed = now.wrapping_sub(prices.timestamp);
+    let spot = (reserve_b << 112) / reserve_a;
+    cumulative.overflowing_add(spot * U256::from(elapsed)).0
+}
+
+/// Average token A price in token B between two observations, human units.
+pub fn average_price(from: &Observation, to: &Observation, decimal_a: u32, decimal_b: u32) -> f64 {
+    let elapsed = to.timestamp - from.timestamp;
+    let average = to.cumulative.overflowing_sub(from.cumulative).0 / U256::from(elapsed);
+    to_float(average, 0) / 2f64.powi(112) * 10f64.powi(decimal_a as i32 - decimal_b as i32)
+}
+
+/// Parse a window such as `30m`, `2h`, `1d` or `90s` into seconds, plain numbers are seconds.
+pub fn parse_window(input: &str) -> Result<u64> {
+    let input = input.trim();
+    let (value, unit) = match input.find(|c: char| !c.is_ascii_digit()) {
+        Some(i) => input.split_at(i),
+        None => (input, "s"),
+    };
+    let value: u64 = value
+        .parse()
+        .map_err(|_| anyhow!("invalid window: {}", input))?;
+    let unit = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86400,
+        _ => return Err(anyhow!("invalid window unit: {}", input)),
+    };
+    if value == 0 {
+        return Err(anyhow!("window must be positive"));
+    }
+    Ok(value * unit)
+}
+
+async fn block_timestamp<M: Middleware + 'static>(
+    client: Arc<M>,
+    block: BlockNumber,
+) -> Result<(u64, u64)> {
+    let block = client
+        .get_block(block)
+        .await?
+        .ok_or_else(|| anyhow!("block {:?} not found", block))?;
+    let number = block
+        .number
+        .ok_or_else(|| anyhow!("block is still pending"))?;
+    Ok((number.as_u64(), block.timestamp.as_u64()))
+}
+
+/// Latest block mined at or before `timestamp`.
+pub async fn block_at<M: Middleware + 'static>(client: Arc<M>, timestamp: u64) -> Result<u64> {
+    let (latest, latest_timestamp) = block_timestamp(client.clone(), BlockNumber::Latest).await?;
+    if latest_timestamp <= timestamp {
+        return Ok(latest);
+    }
+
+    // blocks are at least a second apart on most chains, so this usually brackets the target
+    let mut low = latest.saturating_sub(latest_timestamp - timestamp);
+    if block_timestamp(client.clone(), low.into()).await?.1 > timestamp {
+        low = 0;
+    }
+    let mut high = latest;
+    while low + 1 < high {
+        let middle = (low + high) / 2;
+        if block_timestamp(client.clone(), middle.into()).await?.1 <= timestamp {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    Ok(low)
+}
+
+/// Sample the pair's cumulative token A price at the end of `block`, latest when None.
+pub async fn observe<M: Middleware + 'static>(
+    client: Arc<M>,
+    layout: &PairLayout,
+    block: Option<u64>,
+) -> Result<Observation> {
+    let (block_number, timestamp) = block_timestamp(
+        client.clone(),
+        block.map(BlockNumber::from).unwrap_or(BlockNumber::Latest),
+    )
+    .await?;
+    let prices = pair::cumulative_prices(client, layout.pair_address, Some(block_number)).await?;
+    Ok(Observation {
+        block_number,
+        timestamp,
+        cumulative: current_cumulative(&prices, layout.token_a_is_0, timestamp as u32),
+    })
+}
+
+/// Time-weighted average price between two observations of the pair.
+pub fn between(from: Observation, to: Observation, layout: &PairLayout) -> Result<Twap> {
+    if to.timestamp <= from.timestamp {
+        return Err(anyhow!(
+            "observations at {} and {} span no time",
+            from.timestamp,
+            to.timestamp
+        ));
+    }
+    Ok(Twap {
+        from,
+        to,
+        price: average_price(&from, &to, layout.token_a_decimal, layout.token_b_decimal),
+    })
+}
+
+/// Time-weighted average price over the last `window` seconds.
+pub async fn twap<M: Middleware + 'static>(
+    client: Arc<M>,
+    layout: &PairLayout,
+    window: u64,
+) -> Result<Twap> {
+    let to = observe(client.clone(), layout, None).await?;
+    let from_block = block_at(client.clone(), to.timestamp.saturating_sub(window)).await?;
+    let from = observe(client, layout, Some(from_block)).await?;
+    between(from, to, layout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observation(timestamp: u64, cumulative: U256) -> Observation {
+        Observation {
+            block_number: 0,
+            timestamp,
+            cumulative,
+        }
+    }
+
+    #[test]
+    fn accumulates_since_last_update() {
+        // token0 has 18 decimals, token1 6, 2000 token1 per token0 since timestamp 100
+        let prices = CumulativePrices {
+            price_0: U256::zero(),
+            price_1: U256::zero(),
+            reserve_0: U256::exp10(18) * 10,
+            reserve_1: U256::exp10(6) * 20000,
+            timestamp: 100,
+        };
+        let from = observation(100, current_cumulative(&prices, true, 100));
+        let to = observation(160, current_cumulative(&prices, true, 160));
+        assert!((average_price(&from, &to, 18, 6) - 2000f64).abs() < 1e-6);
+
+        // same pool read from token1's side
+        let to = observation(160, current_cumulative(&prices, false, 160));
+        let from = observation(100, U256::zero());
+        assert!((average_price(&from, &to, 6, 18) - 0.0005f64).abs() < 1e-12);
+    }
+
+    #[test]
+    fn averages_across_accumulator_overflow() {
+        let spot = U256::from(3u64) << 112;
+        let from = observation(0, U256::MAX - spot * 5 + 1);
+        let to = observation(10, from.cumulative.overflowing_add(spot * 10).0);
+        assert!((average_price(&from, &to, 18, 18) - 3f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn timestamp_wraps_at_32_bits() {
+        let prices = CumulativePrices {
+            price_0: U256::zero(),
+            price_1: U256::zero(),
+            reserve_0: U256::from(1u64),
+            reserve_1: U256::from(2u64),
+            timestamp: u32::MAX - 9,
+        };
+        let cumulative = current_cumulative(&prices, true, 10);
+        assert_eq!(cumulative, (U256::from(2u64) << 112) * 20);
+    }
+
+    #[test]
+    fn parses_window_units() {
+        assert_eq!(parse_window("30m").unwrap(), 1800);
+        assert_eq!(parse_window("2h").unwrap(), 7200);
+        assert_eq!(parse_window("1d").unwrap(), 86400);
+        assert_eq!(parse_window("45").unwrap(), 45);
+        assert!(parse_window("10x").is_err());
+        assert!(parse_window("0m").is_err());
+    }
+}
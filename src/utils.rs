@@ -0,0 +1,70 @@
+use crate::{config::Config, BASIS_POINT, DEFAULT_GAS_PRICE, GAS_MULTIPLIER};
+use anyhow::{anyhow, Result};
+use ethers::{
+    providers::Middleware,
+    types::{TransactionReceipt, U256},
+    utils::{format_units, parse_units},
+};
+
+/// Convert a raw token amount into human units for display and analysis.
+pub fn to_float(amount: U256, decimal: u32) -> f64 {
+    format_units(amount, decimal)
+        .unwrap_or_default()
+        .parse::<f64>()
+        .unwrap_or(0f64)
+}
+
+/// Convert human units back into a raw token amount, 0 for negative or invalid input.
+pub fn from_float(amount: f64, decimal: u32) -> U256 {
+    if !amount.is_finite() || amount <= 0f64 {
+        return U256::zero();
+    }
+    parse_units(format!("{:.*}", decimal as usize, amount), decimal)
+        .map(|v| v.into())
+        .unwrap_or_default()
+}
+
+/// Price of token A quoted in token B, 0 when token A amount is empty.
+pub fn price(amount_a: f64, amount_b: f64) -> f64 {
+    if amount_a == 0f64 {
+        0f64
+    } else {
+        amount_b / amount_a
+    }
+}
+
+/// Scale a node gas price by `gas_price_multipler`, `DEFAULT_GAS_PRICE` when unknown.
+pub fn scale_gas_price(gas_price: Option<U256>, gas_price_multipler: u64) -> U256 {
+    gas_price
+        .unwrap_or(U256::from(DEFAULT_GAS_PRICE))
+        .checked_mul(U256::from(gas_price_multipler))
+        .unwrap()
+        .checked_div(U256::from(BASIS_POINT))
+        .unwrap()
+}
+
+/// Gas price to send with, the node one scaled by `gas_price_multipler`.
+pub async fn gas_price<M: Middleware + 'static>(client: &M, config: &Config) -> U256 {
+    scale_gas_price(
+        client.get_gas_price().await.ok(),
+        config.network.gas_price_multipler,
+    )
+}
+
+/// Pad an estimated gas limit by `GAS_MULTIPLIER`.
+pub fn gas_limit(estimated: U256) -> U256 {
+    estimated
+        .checked_mul(U256::from(GAS_MULTIPLIER))
+        .unwrap()
+        .checked_div(U256::from(BASIS_POINT))
+        .unwrap()
+}
+
+/// Receipt of a mined transaction, Err when `what` reverted or was dropped.
+pub fn succeeded(receipt: Option<TransactionReceipt>, what: &str) -> Result<TransactionReceipt> {
+    match receipt {
+        Some(receipt) if receipt.status == Some(1u64.into()) => Ok(receipt),
+        Some(receipt) => Err(anyhow!("{} {:?} reverted", what, receipt.transaction_hash)),
+        None => Err(anyhow!("{} dropped", what)),
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{
+    config::{Config, GasAction},
+    dex::DexClient,
+    gas::GasVerdict,
+    pair,
+    tswap::{StageReport, Tswap, POLL_INTERVAL},
+    BASIS_POINT,
+};
+use anyhow::{anyhow, Result};
+use bindings_uniswapv2::uniswap_v2_pair::SwapFilter;
+use chrono::Utc;
+use ethers::{
+    contract::{parse_log, EthEvent},
+    providers::Middleware,
+    types::{Address, BlockNumber, Filter, U256},
+};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Volume of the `token_in` side of `swaps`, both directions, leaving out swaps paying `owner`.
+pub fn swap_volume(swaps: &[SwapFilter], token_in_is_0: bool, owner: Address) -> U256 {
+    swaps
+        .iter()
+        .filter(|swap| swap.to != owner)
+        .fold(U256::zero(), |volume, swap| {
+            if token_in_is_0 {
+                volume + swap.amount_0_in + swap.amount_0_out
+            } else {
+                volume + swap.amount_1_in + swap.amount_1_out
+            }
+        })
+}
+
+/// Next step size: `participation` (4 decimals) of the observed volume, at most what is left.
+pub fn participation_step(volume: U256, participation: u64, remaining: U256) -> U256 {
+    (volume * U256::from(participation) / U256::from(BASIS_POINT)).min(remaining)
+}
+
+/// Volume traded by others in `token_in` over `[from_block, to_block]`.
+async fn pool_volume<M: Middleware + 'static>(
+    client: Arc<M>,
+    pair_address: Address,
+    token_in_is_0: bool,
+    owner: Address,
+    from_block: u64,
+    to_block: u64,
+) -> Result<U256> {
+    let filter = Filter::new()
+        .address(pair_address)
+        .topic0(SwapFilter::signature())
+        .from_block(BlockNumber::Number(from_block.into()))
+        .to_block(BlockNumber::Number(to_block.into()));
+    let swaps: Vec<SwapFilter> = client
+        .get_logs(&filter)
+        .await
+        .map_err(|e| anyhow!("get logs {}..{} fail: {}", from_block, to_block, e))?
+        .into_iter()
+        .filter_map(|log| parse_log::<SwapFilter>(log).ok())
+        .collect();
+    Ok(swap_volume(&swaps, token_in_is_0, owner))
+}
+
+/// Sell `amount` of `token_in` in steps sized by the pool's recent volume.
+///
+/// Every `tswap_step` seconds (at least `POLL_INTERVAL`), swap `participation` of what others
+/// traded in `token_in` since the previous check, then swap whatever is left once `duration`
+/// has passed. A failed swap of that remainder ends the stage with it in `unsold`.
+#[allow(clippy::too_many_arguments)]
+pub async fn vwap<M: Middleware + 'static, D: DexClient>(
+    client: Arc<M>,
+    tswap: &Tswap<D>,
+    config: &Config,
+    owner: Address,
+    token_in: Address,
+    token_out: Address,
+    amount: U256,
+) -> Result<StageReport> {
+    let vwap_config = config
+        .trade
+        .vwap
+        .clone()
+        .ok_or_else(|| anyhow!("trade.vwap is not set in config"))?;
+    let layout = pair::layout(client.clone(), &config.pool).await?;
+    let token_in_is_0 =
+        (token_in == Address::from_str(&config.pool.token_a_address)?) == layout.token_a_is_0;
+
+    let deadline = Utc::now().timestamp() + vwap_config.duration as i64;
+    let mut last_block = client.get_block_number().await?.as_u64();
+    let mut remaining = amount;
+    let mut report = StageReport::default();
+
+    while !remaining.is_zero() {
+        // each pass reads the chain, never spin on it
+        tokio::time::sleep(Duration::from_secs(
+            config.trade.tswap_step.max(POLL_INTERVAL),
+        ))
+        .await;
+
+        let step = if Utc::now().timestamp() >= deadline {
+            println!("stage deadline, swap remaining {}", remaining);
+            remaining
+        } else {
+            let block = match client.get_block_number().await {
+                Ok(block) => block.as_u64(),
+                Err(e) => {
+                    println!("get block number fail: {}", e);
+                    continue;
+                }
+            };
+            if block <= last_block {
+                continue;
+            }
+            let volume = match pool_volume(
+                client.clone(),
+                layout.pair_address,
+                token_in_is_0,
+                owner,
+                last_block + 1,
+                block,
+            )
+            .await
+            {
+                Ok(volume) => volume,
+                Err(e) => {
+                    println!("{}", e);
+                    continue;
+                }
+            };
+            last_block = block;
+            participation_step(volume, vwap_config.participation, remaining)
+        };
+        if step.is_zero() || !tswap.guard_allows().await {
+            continue;
+        }
+        if let Some(reason) = tswap.check_loss().await {
+            report.halted = true;
+            report.unsold = remaining;
+            println!("stage halted: {}, {} unsold", reason, remaining);
+            break;
+        }
+        // a skipped step stays in `remaining`, swapped once gas drops or at the deadline
+        match tswap.gas_verdict(token_in, step).await {
+            GasVerdict::Within => {}
+            GasVerdict::Exceeded(reason)
+                if config
+                    .trade
+                    .gas_budget
+                    .as_ref()
+                    .map(|budget| budget.on_exceed)
+                    != Some(GasAction::Abort) =>
+            {
+                println!("step ({}) skipped: {}", step, reason);
+                continue;
+            }
+            GasVerdict::Exceeded(reason) | GasVerdict::OverBudget(reason) => {
+                report.aborted = true;
+                report.unsold = remaining;
+                println!("stage aborted: {}, {} unsold", reason, remaining);
+                break;
+            }
+        }
+
+        let step_count = report.filled_steps + report.failed_steps + 1;
+        match tswap.execute(token_in, token_out, step).await {
+            Ok(sent) => {
+                report.filled_steps += 1;
+                remaining -= sent;
+                println!("step {} ({}) success", step_count, sent);
+            }
+            Err(_) => {
+                report.failed_steps += 1;
+                println!("step {} ({}) fail", step_count, step);
+                if Utc::now().timestamp() >= deadline {
+                    report.unsold = remaining;
+                    println!("stage deadline passed, {} unsold", remaining);
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap(amount_0_in: u64, amount_1_out: u64, to: Address) -> SwapFilter {
+        SwapFilter {
+            sender: Address::zero(),
+            amount_0_in: amount_0_in.into(),
+            amount_1_in: U256::zero(),
+            amount_0_out: U256::zero(),
+            amount_1_out: amount_1_out.into(),
+            to,
+        }
+    }
+
+    #[test]
+    fn volume_skips_our_own_swaps() {
+        let owner = Address::repeat_byte(0x01);
+        let swaps = vec![
+            swap(100, 200, Address::repeat_byte(0x02)),
+            swap(50, 90, owner),
+            swap(30, 60, Address::repeat_byte(0x03)),
+        ];
+        assert_eq!(swap_volume(&swaps, true, owner), U256::from(130u64));
+        assert_eq!(swap_volume(&swaps, false, owner), U256::from(260u64));
+    }
+
+    #[test]
+    fn participation_is_capped_by_remaining() {
+        let remaining = U256::from(1000u64);
+        assert_eq!(
+            participation_step(U256::from(4000u64), 1000, remaining),
+            U256::from(400u64)
+        );
+        assert_eq!(
+            participation_step(U256::from(40000u64), 1000, remaining),
+            remaining
+        );
+    }
+}
@@ -0,0 +1,321 @@
+use crate::{
+    config::Config,
+    dex::DexClient,
+    erc20,
+    tswap::{check_allowance, StageReport, Tswap, POLL_INTERVAL, SWAP_DEADLINE},
+    utils::{gas_price, succeeded},
+};
+use anyhow::{anyhow, Result};
+use bindings_uniswapv2::ierc20::IERC20;
+use ethers::{
+    providers::Middleware,
+    types::{Address, BlockNumber, TransactionRequest, U256},
+};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinSet;
+
+const ETH_TRANSFER_GAS: u64 = 21000u64;
+
+/// Sub-wallet trading its share of a stage.
+#[derive(Debug)]
+pub struct Wallet<M, D> {
+    pub address: Address,
+    /// Signing client with the wallet's own nonce manager.
+    pub client: Arc<M>,
+    pub tswap: Arc<Tswap<D>>,
+}
+
+/// What a stage moved, for one wallet or summed over all of them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StageFill {
+    /// Share of the stage amount assigned.
+    pub amount: U256,
+    pub report: StageReport,
+    /// Balance decrease of the sold token.
+    pub sold: U256,
+    /// Balance increase of the bought token.
+    pub received: U256,
+}
+
+impl StageFill {
+    pub fn add(&mut self, other: &StageFill) {
+        self.amount += other.amount;
+        self.report.add(&other.report);
+        self.sold += other.sold;
+        self.received += other.received;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalletStage {
+    pub address: Address,
+    pub fill: StageFill,
+}
+
+/// Split `amount` evenly over `wallets`, the remainder going to the last one.
+pub fn split_amount(amount: U256, wallets: usize) -> Vec<U256> {
+    if wallets == 0 {
+        return vec![];
+    }
+    let share = amount / U256::from(wallets);
+    let mut shares = vec![share; wallets];
+    shares[wallets - 1] = amount - share * U256::from(wallets - 1);
+    shares
+}
+
+/// Config for the `index`-th sub-wallet, with its own jitter seed so wallets do not share
+/// a schedule.
+pub fn wallet_config(config: &Config, index: usize) -> Config {
+    let mut config = config.clone();
+    if let Some(jitter) = config.trade.jitter.as_mut() {
+        jitter.seed = jitter.seed.map(|seed| seed.wrapping_add(index as u64 + 1));
+    }
+    config
+}
+
+pub fn aggregate(stages: &[WalletStage]) -> StageFill {
+    stages
+        .iter()
+        .fold(StageFill::default(), |mut total, stage| {
+            total.add(&stage.fill);
+            total
+        })
+}
+
+/// Wait until every transaction `address` sent is mined, polling its nonce until the
+/// router deadline passed. False when some are still pending then.
+async fn wait_mined<M: Middleware + 'static>(client: &M, address: Address) -> bool {
+    let deadline = tokio::time::Instant::now()
+        + Duration::from_millis(SWAP_DEADLINE as u64)
+        + Duration::from_secs(POLL_INTERVAL);
+    loop {
+        let mined = client
+            .get_transaction_count(address, Some(BlockNumber::Latest.into()))
+            .await;
+        let sent = client
+            .get_transaction_count(address, Some(BlockNumber::Pending.into()))
+            .await;
+        match (mined, sent) {
+            (Ok(mined), Ok(sent)) if mined >= sent => return true,
+            (Ok(_), Ok(_)) => {}
+            (Err(e), _) | (_, Err(e)) => println!("read nonce fail: {}", e),
+        }
+        if tokio::time::Instant::now() >= deadline {
+            return false;
+        }
+        tokio::time::sleep(Duration::from_secs(POLL_INTERVAL)).await;
+    }
+}
+
+/// Send `amount` of `token` to `to` and wait for the receipt, ETH when `token` is None.
+async fn transfer<M: Middleware + 'static>(
+    client: Arc<M>,
+    token: Option<Address>,
+    to: Address,
+    amount: U256,
+    gas_price: U256,
+) -> Result<()> {
+    let receipt = match token {
+        Some(token) => {
+            let token = IERC20::new(token, client);
+            let call = token.transfer(to, amount).gas_price(gas_price);
+            let pending = call.send().await?;
+            pending.await?
+        }
+        None => {
+            let tx = TransactionRequest::new()
+                .to(to)
+                .value(amount)
+                .gas(ETH_TRANSFER_GAS)
+                .gas_price(gas_price);
+            client
+                .send_transaction(tx, None)
+                .await
+                .map_err(|e| anyhow!("send transaction fail: {}", e))?
+                .await?
+        }
+    };
+    succeeded(receipt, "transfer")?;
+    Ok(())
+}
+
+/// Send `amount` of `token` (ETH when None) from the master wallet to every sub-wallet.
+pub async fn fund<M: Middleware + 'static>(
+    client: Arc<M>,
+    config: &Config,
+    token: Option<Address>,
+    recipients: &[Address],
+    amount: U256,
+) -> Result<()> {
+    for recipient in recipients {
+        let gas_price = gas_price(client.as_ref(), config).await;
+        transfer(client.clone(), token, *recipient, amount, gas_price).await?;
+        println!("funded {:?} with {}", recipient, amount);
+    }
+    Ok(())
+}
+
+/// Send every token balance of `wallet` back to `master`, then its ETH minus the gas of
+/// that last transfer.
+pub async fn sweep<M: Middleware + 'static, D>(
+    wallet: &Wallet<M, D>,
+    config: &Config,
+    tokens: &[Address],
+    master: Address,
+) -> Result<()> {
+    for token in tokens {
+        let balance = erc20::balance_of(wallet.client.clone(), *token, wallet.address).await;
+        if balance.is_zero() {
+            continue;
+        }
+        let gas_price = gas_price(wallet.client.as_ref(), config).await;
+        transfer(
+            wallet.client.clone(),
+            Some(*token),
+            master,
+            balance,
+            gas_price,
+        )
+        .await?;
+        println!("swept {} of {:?} from {:?}", balance, token, wallet.address);
+    }
+
+    let gas_price = gas_price(wallet.client.as_ref(), config).await;
+    let balance = wallet.client.get_balance(wallet.address, None).await?;
+    let amount = balance.saturating_sub(gas_price * U256::from(ETH_TRANSFER_GAS));
+    if !amount.is_zero() {
+        transfer(wallet.client.clone(), None, master, amount, gas_price).await?;
+        println!("swept {} ETH from {:?}", amount, wallet.address);
+    }
+    Ok(())
+}
+
+/// Split `amount` of `token_in` over `wallets` and run each share as its own `max_step`
+/// step stage, all wallets at once.
+pub async fn stage<M: Middleware + 'static, D: DexClient + 'static>(
+    wallets: &[Wallet<M, D>],
+    config: &Config,
+    token_in: Address,
+    token_out: Address,
+    amount: U256,
+    max_step: u64,
+) -> Result<Vec<WalletStage>> {
+    if wallets.is_empty() {
+        return Err(anyhow!("no sub-wallets configured"));
+    }
+    let router_address = Address::from_str(&config.pool.router_address)?;
+
+    let mut tasks = JoinSet::new();
+    for (index, (wallet, share)) in wallets
+        .iter()
+        .zip(split_amount(amount, wallets.len()))
+        .enumerate()
+    {
+        let (address, client, tswap) =
+            (wallet.address, wallet.client.clone(), wallet.tswap.clone());
+        tswap.start_run();
+        tasks.spawn(async move {
+            check_allowance(
+                client.clone(),
+                &tswap,
+                token_in,
+                address,
+                router_address,
+                share,
+            )
+            .await;
+            let before_in = erc20::balance_of(client.clone(), token_in, address).await;
+            let before_out = erc20::balance_of(client.clone(), token_out, address).await;
+
+            let report = tswap.tswap(token_in, token_out, share, max_step).await;
+            if !wait_mined(client.as_ref(), address).await {
+                println!(
+                    "{:?} still has pending swaps, balances may undercount",
+                    address
+                );
+            }
+
+            let after_in = erc20::balance_of(client.clone(), token_in, address).await;
+            let after_out = erc20::balance_of(client, token_out, address).await;
+            (
+                index,
+                WalletStage {
+                    address,
+                    fill: StageFill {
+                        amount: share,
+                        report,
+                        sold: before_in.saturating_sub(after_in),
+                        received: after_out.saturating_sub(before_out),
+                    },
+                },
+            )
+        });
+    }
+
+    let mut stages = vec![];
+    while let Some(result) = tasks.join_next().await {
+        stages.push(result?);
+    }
+    stages.sort_by_key(|(index, _)| *index);
+    Ok(stages.into_iter().map(|(_, stage)| stage).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::providers::Provider;
+
+    #[tokio::test(start_paused = true)]
+    async fn wait_mined_polls_until_nonce_catches_up() {
+        let (provider, mock) = Provider::mocked();
+        // responses pop last pushed first: pending 3 / latest 2, then pending 3 / latest 3
+        mock.push(U256::from(3u64)).unwrap();
+        mock.push(U256::from(3u64)).unwrap();
+        mock.push(U256::from(3u64)).unwrap();
+        mock.push(U256::from(2u64)).unwrap();
+        let start = tokio::time::Instant::now();
+        assert!(wait_mined(&provider, Address::zero()).await);
+        assert_eq!(start.elapsed(), Duration::from_secs(POLL_INTERVAL));
+    }
+
+    #[test]
+    fn split_puts_remainder_on_last_wallet() {
+        let shares = split_amount(U256::from(100u64), 3);
+        assert_eq!(
+            shares,
+            vec![U256::from(33u64), U256::from(33u64), U256::from(34u64)]
+        );
+        assert!(split_amount(U256::from(100u64), 0).is_empty());
+    }
+
+    #[test]
+    fn aggregate_sums_every_wallet() {
+        let stage = |sold: u64, failed_steps: u64| WalletStage {
+            address: Address::zero(),
+            fill: StageFill {
+                amount: U256::from(10u64),
+                report: StageReport {
+                    filled_steps: 2,
+                    failed_steps,
+                    ..Default::default()
+                },
+                sold: U256::from(sold),
+                received: U256::from(sold * 2),
+            },
+        };
+        let total = aggregate(&[stage(10, 0), stage(7, 1)]);
+        assert_eq!(total.amount, U256::from(20u64));
+        assert_eq!(total.sold, U256::from(17u64));
+        assert_eq!(total.received, U256::from(34u64));
+        assert_eq!(
+            total.report,
+            StageReport {
+                filled_steps: 4,
+                failed_steps: 1,
+                ..Default::default()
+            }
+        );
+    }
+}
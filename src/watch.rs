@@ -0,0 +1,224 @@
+use crate::{
+    config::Config,
+    pair::{self, PairLayout},
+    utils::{price, to_float},
+};
+use anyhow::{anyhow, Result};
+use bindings_uniswapv2::uniswap_v2_pair::{UniswapV2Pair, UniswapV2PairEvents};
+use ethers::{
+    contract::LogMeta,
+    providers::{Middleware, StreamExt},
+    types::U256,
+};
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use std::sync::Arc;
+
+const CSV_HEADER: &str = "block_number,transaction_hash,kind,side,amount_a,amount_b,price";
+
+/// One pair event expressed in token A / token B human units.
+///
+/// For `swap` the amounts are the traded size and `side` is from the trader's
+/// point of view on token A, for `sync` they are the new reserves, for `mint`
+/// and `burn` the liquidity added or removed.
+#[derive(Debug, Clone, Serialize)]
+pub struct PoolEvent {
+    pub block_number: u64,
+    pub transaction_hash: String,
+    pub kind: String,
+    pub side: String,
+    pub amount_a: f64,
+    pub amount_b: f64,
+    pub price: f64,
+}
+
+impl PoolEvent {
+    pub fn decode(event: UniswapV2PairEvents, meta: &LogMeta, layout: &PairLayout) -> Option<Self> {
+        let (kind, side, amount_a, amount_b) = match event {
+            UniswapV2PairEvents::SwapFilter(swap) => {
+                let (a_in, b_in) = layout.split(swap.amount_0_in, swap.amount_1_in);
+                let (a_out, b_out) = layout.split(swap.amount_0_out, swap.amount_1_out);
+                if a_in > U256::zero() {
+                    ("swap", "sell", a_in, b_out)
+                } else {
+                    ("swap", "buy", a_out, b_in)
+                }
+            }
+            UniswapV2PairEvents::SyncFilter(sync) => {
+                let (reserve_a, reserve_b) =
+                    layout.split(U256::from(sync.reserve_0), U256::from(sync.reserve_1));
+                ("sync", "", reserve_a, reserve_b)
+            }
+            UniswapV2PairEvents::MintFilter(mint) => {
+                let (amount_a, amount_b) = layout.split(mint.amount_0, mint.amount_1);
+                ("mint", "", amount_a, amount_b)
+            }
+            UniswapV2PairEvents::BurnFilter(burn) => {
+                let (amount_a, amount_b) = layout.split(burn.amount_0, burn.amount_1);
+                ("burn", "", amount_a, amount_b)
+            }
+            _ => return None,
+        };
+
+        let amount_a = to_float(amount_a, layout.token_a_decimal);
+        let amount_b = to_float(amount_b, layout.token_b_decimal);
+
+        Some(Self {
+            block_number: meta.block_number.as_u64(),
+            transaction_hash: format!("{:?}", meta.transaction_hash),
+            kind: kind.to_string(),
+            side: side.to_string(),
+            amount_a,
+            amount_b,
+            price: price(amount_a, amount_b),
+        })
+    }
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.block_number,
+            self.transaction_hash,
+            self.kind,
+            self.side,
+            self.amount_a,
+            self.amount_b,
+            self.price
+        )
+    }
+}
+
+/// Destination file for watched events, format picked from the extension.
+pub enum EventSink {
+    Csv(File),
+    Jsonl(File),
+}
+
+impl EventSink {
+    pub fn create(path: &str) -> Result<Self> {
+        if path.ends_with(".csv") {
+            let mut file = File::create(path)?;
+            writeln!(file, "{}", CSV_HEADER)?;
+            Ok(Self::Csv(file))
+        } else if path.ends_with(".jsonl") {
+            Ok(Self::Jsonl(File::create(path)?))
+        } else {
+            Err(anyhow!("unsupported output {}, use .csv or .jsonl", path))
+        }
+    }
+
+    pub fn write(&mut self, event: &PoolEvent) -> Result<()> {
+        match self {
+            Self::Csv(file) => writeln!(file, "{}", event.to_csv())?,
+            Self::Jsonl(file) => writeln!(file, "{}", serde_json::to_string(event)?)?,
+        }
+        Ok(())
+    }
+}
+
+/// Stream swap, sync, mint and burn events of the configured pair until Ctrl+C.
+pub async fn watch<M: Middleware + 'static>(
+    client: Arc<M>,
+    config: &Config,
+    output: Option<String>,
+) -> Result<()> {
+    let layout = pair::layout(client.clone(), &config.pool).await?;
+    let mut sink = output.as_deref().map(EventSink::create).transpose()?;
+
+    let token_a_symbol = &config.pool.token_a_symbol;
+    let token_b_symbol = &config.pool.token_b_symbol;
+
+    let (reserve_0, reserve_1) = pair::get_reserves(client.clone(), layout.pair_address).await;
+    let (reserve_a, reserve_b) = layout.split(reserve_0, reserve_1);
+    let reserve_a = to_float(reserve_a, layout.token_a_decimal);
+    let reserve_b = to_float(reserve_b, layout.token_b_decimal);
+    println!(
+        "watching pair {:?}: {} ({}) - {} ({}), price {} {}/{}",
+        layout.pair_address,
+        reserve_a,
+        token_a_symbol,
+        reserve_b,
+        token_b_symbol,
+        price(reserve_a, reserve_b),
+        token_b_symbol,
+        token_a_symbol
+    );
+    println!("press Ctrl+C to stop");
+
+    let pair = UniswapV2Pair::new(layout.pair_address, client);
+    let events = pair.events();
+    let mut stream = events.stream_with_meta().await?;
+
+    loop {
+        let next = tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            next = stream.next() => next,
+        };
+
+        let (event, meta) = match next {
+            Some(Ok(item)) => item,
+            Some(Err(e)) => {
+                println!("event decode fail: {}", e);
+                continue;
+            }
+            None => break,
+        };
+
+        let Some(event) = PoolEvent::decode(event, &meta, &layout) else {
+            continue;
+        };
+
+        match event.kind.as_str() {
+            "swap" => println!(
+                "[{}] {} {} ({}) for {} ({}) @ {}",
+                event.block_number,
+                event.side,
+                event.amount_a,
+                token_a_symbol,
+                event.amount_b,
+                token_b_symbol,
+                event.price
+            ),
+            "sync" => println!(
+                "[{}] reserves {} ({}) - {} ({}), price {}",
+                event.block_number,
+                event.amount_a,
+                token_a_symbol,
+                event.amount_b,
+                token_b_symbol,
+                event.price
+            ),
+            _ => println!(
+                "[{}] {} liquidity {} ({}) - {} ({})",
+                event.block_number,
+                event.kind,
+                event.amount_a,
+                token_a_symbol,
+                event.amount_b,
+                token_b_symbol
+            ),
+        }
+
+        if let Some(sink) = sink.as_mut() {
+            if let Err(e) = sink.write(&event) {
+                println!("write event fail: {}", e);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsupported_output_creates_no_file() {
+        let path = std::env::temp_dir().join("tswap-watch-events.txt");
+        let path = path.to_str().unwrap();
+        assert!(EventSink::create(path).is_err());
+        assert!(!std::path::Path::new(path).exists());
+    }
+}
@@ -0,0 +1,193 @@
+use bindings_uniswapv2::uniswap_v2_router_02::UniswapV2Router02;
+use ethers::{
+    abi::Abi,
+    contract::{Contract, ContractFactory},
+    core::utils::{Anvil, AnvilInstance},
+    prelude::{MiddlewareBuilder, NonceManagerMiddleware, SignerMiddleware},
+    providers::{Http, Provider},
+    signers::{LocalWallet, Signer},
+    types::{Address, Bytes, U256},
+    utils::parse_ether,
+};
+use serde_json::Value;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::Arc;
+use uniswapv2_mm::config::{Config, NetworkConfig, PoolConfig, TradeConfig};
+
+pub type Client = SignerMiddleware<NonceManagerMiddleware<Provider<Http>>, LocalWallet>;
+
+/// Liquidity seeded on each side of the pair.
+pub const POOL_LIQUIDITY: u64 = 1000u64;
+/// Supply minted to the deployer for each mock token.
+pub const TOKEN_SUPPLY: u64 = 1000000u64;
+
+/// Local anvil node with a Uniswap V2 deployment and a seeded token A / token B pair.
+pub struct Devnet {
+    /// Keeps the anvil process alive for the lifetime of the test.
+    _anvil: AnvilInstance,
+    pub client: Arc<Client>,
+    pub owner: Address,
+    pub router: Address,
+    pub weth: Address,
+    pub token_a: Address,
+    pub token_b: Address,
+    pub pair: Address,
+    pub config: Config,
+}
+
+/// Load ABI and creation bytecode from `tests/fixtures/<name>.json`.
+///
+/// Accepts both the waffle layout shipped in `@uniswap/v2-core/build` and
+/// `@uniswap/v2-periphery/build` (`bytecode` is a hex string) and the solc / foundry
+/// layout (`bytecode.object`).
+fn artifact(name: &str) -> (Abi, Bytes) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(format!("{}.json", name));
+    let raw = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("missing fixture {}: {}", path.display(), e));
+    let json: Value = serde_json::from_str(&raw).unwrap();
+
+    let abi: Abi = serde_json::from_value(json["abi"].clone()).unwrap();
+    let bytecode = match &json["bytecode"] {
+        Value::String(code) => code.clone(),
+        Value::Object(code) => code["object"].as_str().unwrap().to_string(),
+        _ => panic!("fixture {} has no bytecode", name),
+    };
+    let bytecode = bytecode.trim_start_matches("0x");
+
+    (
+        abi,
+        Bytes::from(ethers::utils::hex::decode(bytecode).unwrap()),
+    )
+}
+
+async fn deploy<T: ethers::abi::Tokenize>(
+    client: Arc<Client>,
+    name: &str,
+    constructor_args: T,
+) -> Contract<Client> {
+    let (abi, bytecode) = artifact(name);
+    ContractFactory::new(abi, bytecode, client)
+        .deploy(constructor_args)
+        .unwrap()
+        .send()
+        .await
+        .unwrap_or_else(|e| panic!("deploy {} fail: {}", name, e))
+}
+
+impl Devnet {
+    /// Deploy a fresh setup, panics when the `anvil` binary is not on `PATH`.
+    pub async fn spawn() -> Self {
+        if Command::new("anvil").arg("--version").output().is_err() {
+            panic!("devnet tests need anvil on PATH, see tests/fixtures/README.md");
+        }
+        let anvil = Anvil::new().spawn();
+        let wallet: LocalWallet =
+            LocalWallet::from(anvil.keys()[0].clone()).with_chain_id(anvil.chain_id());
+        let owner = wallet.address();
+
+        let provider = Provider::<Http>::try_from(anvil.endpoint()).unwrap();
+        let client = Arc::new(provider.nonce_manager(owner).with_signer(wallet));
+
+        let factory = deploy(client.clone(), "UniswapV2Factory", owner).await;
+        let weth = deploy(client.clone(), "WETH9", ()).await;
+        let router = deploy(
+            client.clone(),
+            "UniswapV2Router02",
+            (factory.address(), weth.address()),
+        )
+        .await;
+        let token_a = deploy(client.clone(), "ERC20", parse_ether(TOKEN_SUPPLY).unwrap()).await;
+        let token_b = deploy(client.clone(), "ERC20", parse_ether(TOKEN_SUPPLY).unwrap()).await;
+
+        // seed the pool through the router so the pair is created with the router's init code
+        let liquidity = parse_ether(POOL_LIQUIDITY).unwrap();
+        for token in [&token_a, &token_b] {
+            token
+                .method::<_, bool>("approve", (router.address(), U256::MAX))
+                .unwrap()
+                .send()
+                .await
+                .unwrap()
+                .await
+                .unwrap();
+        }
+        UniswapV2Router02::new(router.address(), client.clone())
+            .add_liquidity(
+                token_a.address(),
+                token_b.address(),
+                liquidity,
+                liquidity,
+                U256::zero(),
+                U256::zero(),
+                owner,
+                U256::MAX,
+            )
+            .send()
+            .await
+            .unwrap()
+            .await
+            .unwrap();
+
+        let pair: Address = factory
+            .method::<_, Address>("getPair", (token_a.address(), token_b.address()))
+            .unwrap()
+            .call()
+            .await
+            .unwrap();
+
+        let config = Config {
+            pool: PoolConfig {
+                router_address: format!("{:?}", router.address()),
+                pair_address: Some(format!("{:?}", pair)),
+                factory_address: None,
+                init_code_hash: None,
+                weth_address: format!("{:?}", weth.address()),
+                token_a_address: format!("{:?}", token_a.address()),
+                token_a_symbol: "TKA".to_string(),
+                token_a_decimal: 18,
+                token_b_address: format!("{:?}", token_b.address()),
+                token_b_symbol: "TKB".to_string(),
+                token_b_decimal: 18,
+            },
+            trade: TradeConfig {
+                tswap_buy: 2,
+                tswap_sell: 2,
+                tswap_step: 0,
+                slippage: 9500,
+                jitter: None,
+                vwap: None,
+                sandwich: None,
+                gas_budget: None,
+                max_round_trip_loss: None,
+            },
+            network: NetworkConfig {
+                chain_id: anvil.chain_id(),
+                rpc: anvil.endpoint(),
+                gas_price_multipler: 10000,
+                multicall_address: None,
+                relay: None,
+                supervisor: None,
+            },
+            mm: None,
+            grid: None,
+            cycle: None,
+            guard: None,
+            arb: None,
+        };
+
+        Self {
+            client,
+            owner,
+            router: router.address(),
+            weth: weth.address(),
+            token_a: token_a.address(),
+            token_b: token_b.address(),
+            pair,
+            config,
+            _anvil: anvil,
+        }
+    }
+}
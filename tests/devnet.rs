@@ -0,0 +1,263 @@
+//! End to end tests against a local anvil node.
+//!
+//! They deploy the contract build artifacts in `tests/fixtures` and need the `anvil` binary
+//! on `PATH`, so they only run on request: `cargo test --test devnet -- --ignored`.
+
+mod common;
+
+use bindings_uniswapv2::{ierc20::IERC20, uniswap_v2_pair::UniswapV2Pair, weth::WETH};
+use common::Devnet;
+use ethers::{providers::Middleware, signers::LocalWallet, types::U256, utils::parse_ether};
+use std::sync::Arc;
+use uniswapv2_mm::{
+    config::{RelayConfig, RelayMethod},
+    dex::RouterClient,
+    erc20, pair,
+    relay::Relay,
+    tswap::Tswap,
+    twap, weth,
+};
+
+fn tswap(devnet: &Devnet) -> Tswap<RouterClient<common::Client>> {
+    let dex = RouterClient::new(devnet.client.clone(), devnet.router, devnet.owner);
+    Tswap::new(Arc::new(dex), devnet.config.clone())
+}
+
+#[tokio::test]
+#[ignore = "needs anvil and the tests/fixtures artifacts"]
+async fn approve_token_sets_allowance() {
+    let devnet = Devnet::spawn().await;
+    let tswap = tswap(&devnet);
+    let value = parse_ether(42).unwrap();
+
+    tswap
+        .approve_token(devnet.token_a, devnet.router, value)
+        .await
+        .unwrap();
+
+    let allowance = erc20::allowance(
+        devnet.client.clone(),
+        devnet.token_a,
+        devnet.owner,
+        devnet.router,
+    )
+    .await;
+    assert_eq!(allowance, value);
+
+    let approvals = IERC20::new(devnet.token_a, devnet.client.clone())
+        .approval_filter()
+        .from_block(0u64)
+        .query()
+        .await
+        .unwrap();
+    let last = approvals.last().unwrap();
+    assert_eq!(last.owner, devnet.owner);
+    assert_eq!(last.spender, devnet.router);
+    assert_eq!(last.value, value);
+}
+
+#[tokio::test]
+#[ignore = "needs anvil and the tests/fixtures artifacts"]
+async fn tswap_sells_in_steps() {
+    let devnet = Devnet::spawn().await;
+    let tswap = tswap(&devnet);
+    let amount = parse_ether(10).unwrap();
+
+    let before_a = erc20::balance_of(devnet.client.clone(), devnet.token_a, devnet.owner).await;
+    let before_b = erc20::balance_of(devnet.client.clone(), devnet.token_b, devnet.owner).await;
+
+    tswap
+        .tswap(
+            devnet.token_a,
+            devnet.token_b,
+            amount,
+            devnet.config.trade.tswap_sell,
+        )
+        .await;
+
+    let after_a = erc20::balance_of(devnet.client.clone(), devnet.token_a, devnet.owner).await;
+    let after_b = erc20::balance_of(devnet.client.clone(), devnet.token_b, devnet.owner).await;
+    assert_eq!(before_a - after_a, amount);
+    assert!(after_b > before_b);
+
+    let swaps = UniswapV2Pair::new(devnet.pair, devnet.client.clone())
+        .swap_filter()
+        .from_block(0u64)
+        .query()
+        .await
+        .unwrap();
+    assert_eq!(swaps.len() as u64, devnet.config.trade.tswap_sell);
+    let sold: U256 = swaps
+        .iter()
+        .map(|s| s.amount_0_in + s.amount_1_in)
+        .fold(U256::zero(), |acc, v| acc + v);
+    assert_eq!(sold, amount);
+}
+
+#[tokio::test]
+#[ignore = "needs anvil and the tests/fixtures artifacts"]
+async fn weth_deposit_and_withdraw() {
+    let devnet = Devnet::spawn().await;
+    let amount = parse_ether(3).unwrap();
+
+    weth::deposit(
+        devnet.client.clone(),
+        devnet.weth,
+        amount,
+        devnet.config.clone(),
+    )
+    .await;
+    assert_eq!(
+        weth::balance_of(devnet.client.clone(), devnet.weth, devnet.owner).await,
+        amount
+    );
+
+    let eth_before = devnet.client.get_balance(devnet.owner, None).await.unwrap();
+    weth::withdraw(
+        devnet.client.clone(),
+        devnet.weth,
+        amount,
+        devnet.config.clone(),
+    )
+    .await;
+    assert_eq!(
+        weth::balance_of(devnet.client.clone(), devnet.weth, devnet.owner).await,
+        U256::zero()
+    );
+    let eth_after = devnet.client.get_balance(devnet.owner, None).await.unwrap();
+    // withdrawn ETH minus the gas of the withdraw call
+    assert!(eth_after > eth_before);
+
+    let contract = WETH::new(devnet.weth, devnet.client.clone());
+    let deposits = contract
+        .deposit_filter()
+        .from_block(0u64)
+        .query()
+        .await
+        .unwrap();
+    let withdrawals = contract
+        .withdrawal_filter()
+        .from_block(0u64)
+        .query()
+        .await
+        .unwrap();
+    assert_eq!(deposits.last().unwrap().wad, amount);
+    assert_eq!(withdrawals.last().unwrap().wad, amount);
+}
+
+#[tokio::test]
+#[ignore = "needs anvil and the tests/fixtures artifacts"]
+async fn sell_then_buy_cycle() {
+    let devnet = Devnet::spawn().await;
+    let client = devnet.client.clone();
+    let tswap = tswap(&devnet);
+    let amount = parse_ether(10).unwrap();
+
+    let start_a = erc20::balance_of(client.clone(), devnet.token_a, devnet.owner).await;
+    let start_b = erc20::balance_of(client.clone(), devnet.token_b, devnet.owner).await;
+
+    tswap
+        .approve_token(devnet.token_a, devnet.router, amount)
+        .await
+        .unwrap();
+    tswap
+        .tswap(
+            devnet.token_a,
+            devnet.token_b,
+            amount,
+            devnet.config.trade.tswap_sell,
+        )
+        .await;
+
+    let received_b =
+        erc20::balance_of(client.clone(), devnet.token_b, devnet.owner).await - start_b;
+    assert!(received_b > U256::zero());
+
+    tswap
+        .approve_token(devnet.token_b, devnet.router, received_b)
+        .await
+        .unwrap();
+    tswap
+        .tswap(
+            devnet.token_b,
+            devnet.token_a,
+            received_b,
+            devnet.config.trade.tswap_buy,
+        )
+        .await;
+
+    let end_a = erc20::balance_of(client.clone(), devnet.token_a, devnet.owner).await;
+    let end_b = erc20::balance_of(client.clone(), devnet.token_b, devnet.owner).await;
+
+    // all token B is spent and the round trip only loses the two 0.3% fees plus impact
+    assert_eq!(end_b, start_b);
+    assert!(end_a < start_a);
+    assert!(start_a - end_a < amount / 50);
+
+    let swaps = UniswapV2Pair::new(devnet.pair, client)
+        .swap_filter()
+        .from_block(0u64)
+        .query()
+        .await
+        .unwrap();
+    assert_eq!(
+        swaps.len() as u64,
+        devnet.config.trade.tswap_sell + devnet.config.trade.tswap_buy
+    );
+}
+
+#[tokio::test]
+#[ignore = "needs anvil and the tests/fixtures artifacts"]
+async fn twap_of_untouched_pool_is_spot_price() {
+    let devnet = Devnet::spawn().await;
+    let provider = devnet.client.inner().inner();
+    for _ in 0..5 {
+        provider
+            .request::<_, i64>("evm_increaseTime", [120u64])
+            .await
+            .unwrap();
+        provider.request::<_, String>("evm_mine", ()).await.unwrap();
+    }
+
+    let layout = pair::layout(devnet.client.clone(), &devnet.config.pool)
+        .await
+        .unwrap();
+    let twap = twap::twap(devnet.client.clone(), &layout, 300)
+        .await
+        .unwrap();
+
+    assert!(twap.from.block_number < twap.to.block_number);
+    assert!(twap.to.timestamp - twap.from.timestamp >= 300);
+    assert!((twap.price - 1f64).abs() < 1e-9);
+}
+
+#[tokio::test]
+#[ignore = "needs anvil and the tests/fixtures artifacts"]
+async fn unreachable_relay_falls_back_to_public_broadcast() {
+    let devnet = Devnet::spawn().await;
+    let relay = Relay::new(
+        &RelayConfig {
+            url: "http://127.0.0.1:9".to_string(),
+            method: RelayMethod::Bundle,
+            fallback_blocks: 2,
+        },
+        LocalWallet::new(&mut rand::thread_rng()),
+    );
+    let dex = RouterClient::new(devnet.client.clone(), devnet.router, devnet.owner)
+        .with_relay(Arc::new(relay));
+    let tswap = Tswap::new(Arc::new(dex), devnet.config.clone());
+    let amount = parse_ether(1).unwrap();
+
+    tswap
+        .approve_token(devnet.token_a, devnet.router, amount)
+        .await
+        .unwrap();
+    let before_a = erc20::balance_of(devnet.client.clone(), devnet.token_a, devnet.owner).await;
+    tswap
+        .swap(devnet.token_a, devnet.token_b, amount)
+        .await
+        .unwrap();
+
+    let after_a = erc20::balance_of(devnet.client.clone(), devnet.token_a, devnet.owner).await;
+    assert_eq!(before_a - after_a, amount);
+}
@@ -0,0 +1,270 @@
+//! Private relay submission against a local mock relay.
+
+use async_trait::async_trait;
+use ethers::{
+    providers::{Middleware, MockProvider, PendingTransaction, Provider, ProviderError},
+    signers::{LocalWallet, Signer},
+    types::{
+        transaction::eip2718::TypedTransaction, Address, BlockId, Bytes, Signature,
+        TransactionReceipt, TransactionRequest, TxHash, H256, U64,
+    },
+    utils::{hex, keccak256},
+};
+use serde_json::{json, Value};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use uniswapv2_mm::config::{RelayConfig, RelayMethod};
+use uniswapv2_mm::relay::{Relay, SIGNATURE_HEADER};
+
+/// Request received by the mock relay.
+struct Received {
+    signature_header: Option<String>,
+    body: String,
+}
+
+/// Serve every request with `response` and forward what was received.
+async fn mock_relay(response: Value) -> (String, mpsc::UnboundedReceiver<Received>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (sender, receiver) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut raw = vec![];
+            let mut buffer = [0u8; 4096];
+            // read headers, then the body announced by Content-Length
+            let (head, length) = loop {
+                let n = stream.read(&mut buffer).await.unwrap();
+                raw.extend_from_slice(&buffer[..n]);
+                let text = String::from_utf8_lossy(&raw).to_string();
+                if let Some(end) = text.find("\r\n\r\n") {
+                    let length = text[..end]
+                        .lines()
+                        .find_map(|line| {
+                            let (name, value) = line.split_once(':')?;
+                            name.eq_ignore_ascii_case("content-length")
+                                .then(|| value.trim().parse::<usize>().unwrap())
+                        })
+                        .unwrap_or(0);
+                    break (text[..end].to_string(), end + 4 + length);
+                }
+            };
+            while raw.len() < length {
+                let n = stream.read(&mut buffer).await.unwrap();
+                raw.extend_from_slice(&buffer[..n]);
+            }
+
+            let signature_header = head.lines().find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                name.eq_ignore_ascii_case(SIGNATURE_HEADER)
+                    .then(|| value.trim().to_string())
+            });
+            let body = String::from_utf8_lossy(&raw[head.len() + 4..length]).to_string();
+            sender
+                .send(Received {
+                    signature_header,
+                    body,
+                })
+                .unwrap();
+
+            let payload = response.to_string();
+            let reply = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                payload.len(),
+                payload
+            );
+            stream.write_all(reply.as_bytes()).await.unwrap();
+        }
+    });
+
+    (url, receiver)
+}
+
+/// Chain that advances one block per `get_block_number` call and never mines anything,
+/// recording what is broadcast publicly and at which block.
+#[derive(Debug)]
+struct NeverMined {
+    inner: Provider<MockProvider>,
+    signer: LocalWallet,
+    block: AtomicU64,
+    broadcast: Mutex<Vec<(Bytes, u64)>>,
+}
+
+impl NeverMined {
+    fn new() -> Self {
+        Self {
+            inner: Provider::mocked().0,
+            signer: LocalWallet::new(&mut rand::thread_rng()).with_chain_id(1u64),
+            block: AtomicU64::new(100),
+            broadcast: Mutex::new(vec![]),
+        }
+    }
+}
+
+#[async_trait]
+impl Middleware for NeverMined {
+    type Error = ProviderError;
+    type Provider = MockProvider;
+    type Inner = Provider<MockProvider>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn fill_transaction(
+        &self,
+        _tx: &mut TypedTransaction,
+        _block: Option<BlockId>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &TypedTransaction,
+        _from: Address,
+    ) -> Result<Signature, Self::Error> {
+        Ok(self.signer.sign_transaction_sync(tx).unwrap())
+    }
+
+    async fn get_block_number(&self) -> Result<U64, Self::Error> {
+        Ok(self.block.fetch_add(1, Ordering::SeqCst).into())
+    }
+
+    async fn get_transaction_receipt<T: Send + Sync + Into<TxHash>>(
+        &self,
+        _hash: T,
+    ) -> Result<Option<TransactionReceipt>, Self::Error> {
+        Ok(None)
+    }
+
+    async fn send_raw_transaction<'a>(
+        &'a self,
+        tx: Bytes,
+    ) -> Result<PendingTransaction<'a, Self::Provider>, Self::Error> {
+        let hash = H256::from(keccak256(&tx));
+        let block = self.block.load(Ordering::SeqCst);
+        self.broadcast.lock().unwrap().push((tx, block));
+        Ok(PendingTransaction::new(hash, &self.inner))
+    }
+}
+
+fn relay(url: String, method: RelayMethod) -> (Relay, LocalWallet) {
+    let auth = LocalWallet::new(&mut rand::thread_rng());
+    let config = RelayConfig {
+        url,
+        method,
+        fallback_blocks: 3,
+    };
+    (Relay::new(&config, auth.clone()), auth)
+}
+
+#[tokio::test]
+async fn bundle_is_signed_and_targets_next_block() {
+    let (url, mut received) = mock_relay(json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "result": { "bundleHash": "0x01" }
+    }))
+    .await;
+    let (relay, auth) = relay(url, RelayMethod::Bundle);
+    let raw = Bytes::from(vec![0x02, 0xf8, 0x6b]);
+
+    let result = relay.submit(&raw, 100).await.unwrap();
+    assert_eq!(result["bundleHash"], "0x01");
+
+    let request = received.recv().await.unwrap();
+    let body: Value = serde_json::from_str(&request.body).unwrap();
+    assert_eq!(body["method"], "eth_sendBundle");
+    assert_eq!(body["params"][0]["txs"][0], "0x02f86b");
+    assert_eq!(body["params"][0]["blockNumber"], "0x65");
+
+    // `<address>:<signature of the body hash>`
+    let header = request.signature_header.unwrap();
+    let (address, signature) = header.split_once(':').unwrap();
+    assert_eq!(Address::from_str(address).unwrap(), auth.address());
+    let digest = format!("0x{}", hex::encode(keccak256(request.body.as_bytes())));
+    Signature::from_str(signature)
+        .unwrap()
+        .verify(digest, auth.address())
+        .unwrap();
+}
+
+#[tokio::test]
+async fn private_transaction_is_bounded_by_fallback_blocks() {
+    let (url, mut received) =
+        mock_relay(json!({ "jsonrpc": "2.0", "id": 1, "result": "0x02" })).await;
+    let (relay, _) = relay(url, RelayMethod::Private);
+
+    relay.submit(&Bytes::from(vec![0x02]), 100).await.unwrap();
+
+    let body: Value = serde_json::from_str(&received.recv().await.unwrap().body).unwrap();
+    assert_eq!(body["method"], "eth_sendPrivateTransaction");
+    assert_eq!(body["params"][0]["tx"], "0x02");
+    assert_eq!(body["params"][0]["maxBlockNumber"], "0x67");
+}
+
+#[tokio::test]
+async fn relay_error_is_reported() {
+    let (url, _received) = mock_relay(json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "error": { "code": -32000, "message": "bundle rejected" }
+    }))
+    .await;
+    let (relay, _) = relay(url, RelayMethod::Bundle);
+
+    let error = relay
+        .submit(&Bytes::from(vec![0x02]), 100)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("bundle rejected"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn unmined_bundle_is_broadcast_after_fallback_blocks() {
+    let (url, mut received) = mock_relay(json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "result": { "bundleHash": "0x01" }
+    }))
+    .await;
+    let (relay, _) = relay(url, RelayMethod::Bundle);
+    let client = Arc::new(NeverMined::new());
+    let from = client.signer.address();
+    let tx: TypedTransaction = TransactionRequest::new()
+        .to(Address::zero())
+        .value(1u64)
+        .gas(21000u64)
+        .gas_price(1u64)
+        .nonce(0u64)
+        .chain_id(1u64)
+        .into();
+
+    let hash = relay.send(client.clone(), tx, from).await.unwrap();
+
+    // one bundle per block before the fallback, each targeting the next block
+    let mut targets = vec![];
+    while let Ok(request) = received.try_recv() {
+        let body: Value = serde_json::from_str(&request.body).unwrap();
+        targets.push(
+            body["params"][0]["blockNumber"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+    assert_eq!(targets, vec!["0x66", "0x67"]);
+
+    let broadcast = client.broadcast.lock().unwrap();
+    assert_eq!(broadcast.len(), 1);
+    let (raw, block) = &broadcast[0];
+    assert_eq!(H256::from(keccak256(raw)), hash);
+    // started at 100 with fallback_blocks 3
+    assert!(*block >= 103);
+}